@@ -26,6 +26,8 @@ pub enum ErrorCode {
     #[msg("Le certificateur n'existe pas dans la liste")]
     CertifierNotFound,
 
+    // Inutilisé depuis la suppression de la limite de certificateurs,
+    // conservé pour ne pas décaler les codes d'erreur suivants
     #[msg("La limite de 50 certificateurs agrees est atteinte")]
     MaxCertifiersReached,
 
@@ -72,5 +74,206 @@ pub enum ErrorCode {
 
     #[msg("Le nom d'affichage est trop long (max 50 caracteres)")]
     DisplayNameTooLong,
-}
 
+    // Codes d'erreur pour les cles deleguees des certificateurs
+    #[msg("Le delegue n'est pas autorise pour cette action")]
+    DelegateNotAuthorized,
+
+    #[msg("La delegation a expire")]
+    DelegateExpired,
+
+    #[msg("La limite de cles deleguees est atteinte")]
+    MaxDelegatesReached,
+
+    #[msg("La date d'expiration de la delegation est invalide")]
+    InvalidDelegateExpiry,
+
+    #[msg("L'accreditation du certificateur a expire")]
+    AccreditationExpired,
+
+    // Codes d'erreur pour le coffre des gains des certificateurs
+    #[msg("Les paiements de ce certificateur sont bloques")]
+    PayoutsHeld,
+
+    #[msg("Gains disponibles insuffisants pour ce retrait")]
+    InsufficientEarnings,
+
+    #[msg("L'adresse de paiement ne correspond pas a celle configuree")]
+    InvalidPayoutAddress,
+
+    // Codes d'erreur pour le coffre de la plateforme
+    #[msg("Solde de tresorerie insuffisant pour ce retrait")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Le coffre de tresorerie ne correspond pas a celui de l'autorite")]
+    InvalidTreasury,
+
+    // Codes d'erreur pour la gouvernance (configuration et conseil M-sur-N)
+    #[msg("La configuration du protocole est invalide")]
+    InvalidConfig,
+
+    #[msg("La composition du conseil est invalide")]
+    InvalidCouncil,
+
+    #[msg("Le conseil d'administration est deja active")]
+    CouncilAlreadyEnabled,
+
+    #[msg("Le signataire n'est pas membre du conseil")]
+    NotCouncilMember,
+
+    #[msg("Une proposition approuvee par le conseil est requise")]
+    ProposalRequired,
+
+    #[msg("La proposition n'a pas atteint le seuil d'approbations")]
+    ProposalNotApproved,
+
+    #[msg("La proposition a deja ete executee")]
+    ProposalAlreadyExecuted,
+
+    #[msg("La proposition ne correspond pas a l'operation demandee")]
+    ProposalActionMismatch,
+
+    #[msg("La composition du conseil a change depuis la proposition")]
+    ProposalStale,
+
+    #[msg("Ce membre a deja approuve la proposition")]
+    AlreadyApproved,
+
+    // Codes d'erreur pour le préavis des modifications (timelock)
+    #[msg("La modification n'est plus en file d'attente")]
+    ChangeNotQueued,
+
+    #[msg("Le delai de preavis n'est pas encore ecoule")]
+    TimelockNotElapsed,
+
+    #[msg("Le delai de preavis est ecoule : la modification ne peut plus etre annulee")]
+    TimelockElapsed,
+
+    #[msg("La modification ne correspond pas a celle mise en file d'attente")]
+    ChangeMismatch,
+
+    // Codes d'erreur pour les rôles opérateurs
+    #[msg("L'operateur ne dispose pas du role requis")]
+    MissingRole,
+
+    #[msg("Le protocole est suspendu")]
+    ProtocolPaused,
+
+    // Codes d'erreur pour les transferts en deux étapes
+    #[msg("Le destinataire doit etre different du proprietaire")]
+    InvalidTransferRecipient,
+
+    #[msg("Seul le destinataire de l'offre peut l'accepter ou la refuser")]
+    NotTransferRecipient,
+
+    // Codes d'erreur pour les ventes
+    #[msg("Le prix de vente doit etre superieur a zero")]
+    InvalidPrice,
+
+    #[msg("Cette vente est reservee a un autre acheteur")]
+    NotDesignatedBuyer,
+
+    #[msg("Le prix de vente depasse le maximum accepte par l'acheteur")]
+    PriceAboveMaximum,
+
+    // Codes d'erreur pour l'historique de provenance
+    #[msg("La page de provenance est pleine")]
+    ProvenancePageFull,
+
+    #[msg("Ce type de transfert est reserve a l'administration")]
+    InvalidTransferKind,
+
+    // Codes d'erreur pour le retrait des certificats
+    #[msg("Le certificat est retire ou en cours de retrait")]
+    CertificateRetired,
+
+    #[msg("Aucun retrait en attente de confirmation pour ce certificat")]
+    RetirementNotPending,
+
+    #[msg("Le certificat n'est pas retire")]
+    CertificateNotRetired,
+
+    // Codes d'erreur pour le carnet d'entretien
+    #[msg("La date d'entretien est invalide")]
+    InvalidServiceDate,
+
+    // Codes d'erreur pour les réévaluations
+    #[msg("La valeur estimee doit etre superieure a zero")]
+    InvalidAppraisalValue,
+
+    #[msg("Le depot ne couvre pas la difference de frais")]
+    AppraisalDepositTooLow,
+
+    #[msg("Seul le certificateur sollicite peut reevaluer ce certificat")]
+    NotRequestedAppraiser,
+
+    // Codes d'erreur pour les métadonnées
+    #[msg("Seul le certificateur emetteur peut publier les metadonnees")]
+    NotIssuingCertifier,
+
+    // Codes d'erreur pour la recertification
+    #[msg("Ce certificat n'a pas de periode de validite")]
+    ValidityNotApplicable,
+
+    // Codes d'erreur pour le jeton NFT du certificat
+    #[msg("Un jeton NFT a deja ete emis pour ce certificat")]
+    NftAlreadyMinted,
+
+    #[msg("Les comptes du jeton NFT du certificat sont requis")]
+    NftAccountsMissing,
+
+    #[msg("Compte de jeton NFT invalide pour ce certificat")]
+    InvalidNftAccount,
+
+    // Codes d'erreur pour la politique de transférabilité
+    #[msg("Ce certificat est lie a son proprietaire et ne peut pas etre transfere")]
+    CertificateSoulbound,
+
+    #[msg("Ce transfert doit etre approuve par le certificateur emetteur")]
+    TransferApprovalRequired,
+
+    #[msg("La politique de ce certificat ne prevoit pas d'approbation des transferts")]
+    TransferApprovalNotRequired,
+
+    // Codes d'erreur pour les frais en jetons SPL
+    #[msg("Ce jeton n'est pas accepte pour le paiement des frais")]
+    FeeMintNotAccepted,
+
+    #[msg("Les comptes du jeton de paiement sont requis")]
+    TokenAccountsMissing,
+
+    #[msg("Compte de jeton invalide pour ce paiement")]
+    InvalidTokenAccount,
+
+    // Codes d'erreur pour les frais en euros
+    #[msg("Le cours SOL/EUR est requis pour des frais en euros")]
+    PriceFeedRequired,
+
+    #[msg("Le cours SOL/EUR est perime ou n'a jamais ete publie")]
+    PriceFeedStale,
+
+    #[msg("Seul l'oracle autorise peut publier le cours")]
+    UnauthorizedOracle,
+
+    #[msg("Cours ou delai de peremption invalide")]
+    InvalidPriceFeed,
+
+    // Codes d'erreur pour les devis
+    #[msg("Montant ou echeance du devis invalide")]
+    InvalidQuote,
+
+    #[msg("Cette demande n'attend pas de devis")]
+    QuoteNotRequested,
+
+    #[msg("Aucun devis en attente d'acceptation pour cette demande")]
+    NoQuoteToAccept,
+
+    #[msg("Le devis a expire")]
+    QuoteExpired,
+
+    #[msg("Le devis n'a pas encore expire")]
+    QuoteNotExpired,
+
+    #[msg("Seule une demande non payee peut etre annulee")]
+    RequestNotCancellable,
+}
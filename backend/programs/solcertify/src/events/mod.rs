@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::{RetirementReason, TimelockedChange};
+
+/// Retrait de fonds du coffre de la plateforme
+#[event]
+pub struct TreasuryWithdrawal {
+    pub admin: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+    pub remaining_balance: u64,
+    pub timestamp: i64,
+}
+
+/// Changement du compte de trésorerie de l'autorité
+#[event]
+pub struct TreasuryUpdated {
+    pub admin: Pubkey,
+    pub previous_payout_address: Pubkey,
+    pub new_payout_address: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Modification du protocole mise en file d'attente (préavis)
+#[event]
+pub struct ChangeQueued {
+    pub id: u64,
+    pub change: TimelockedChange,
+    pub queued_by: Pubkey,
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
+
+/// Modification annulée pendant le préavis
+#[event]
+pub struct ChangeCancelled {
+    pub id: u64,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Modification appliquée après le préavis
+#[event]
+pub struct ChangeExecuted {
+    pub id: u64,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Vente d'un certificat exécutée via le programme (avec redevances)
+#[event]
+pub struct CertificateSold {
+    pub certificate: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub certifier_royalty: u64,
+    pub platform_royalty: u64,
+    pub timestamp: i64,
+}
+
+/// Certificat retiré définitivement (montre détruite, démontée ou exportée)
+#[event]
+pub struct CertificateRetired {
+    pub certificate: Pubkey,
+    pub owner: Pubkey,
+    pub reason: RetirementReason,
+    pub timestamp: i64,
+}
+
+/// Nouvelle version des métadonnées publiée par le certificateur émetteur
+#[event]
+pub struct MetadataUpdated {
+    pub certificate: Pubkey,
+    pub version: u32,
+    pub metadata_uri: String,
+    pub metadata_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Cours SOL/EUR publié par l'oracle
+#[event]
+pub struct PriceUpdated {
+    pub oracle: Pubkey,
+    pub sol_price_eur_cents: u64,
+    pub timestamp: i64,
+}
+
+/// Devis publié par le certificateur assigné pour une demande
+#[event]
+pub struct QuotePosted {
+    pub request: Pubkey,
+    pub certifier: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
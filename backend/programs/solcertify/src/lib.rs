@@ -3,15 +3,19 @@
 // Programme Anchor pour la certification d'authenticite de montres de luxe sur Solana.
 // Inclut le flux de demande de certification avec distribution des frais.
 
+// Les instructions Anchor prennent naturellement beaucoup d'arguments
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
 
 // Declaration des modules
 pub mod errors;
+pub mod events;
 mod processor;
 pub mod state;
 
 // Reexporter les types pour l'IDL
-pub use state::{CertificationType, RequestStatus, CertifierProfile};
+pub use state::{CertificationType, RequestStatus, CertifierProfile, CertifierDelegate, DelegatePermissions, CertifierVault, TreasuryVault, ProtocolConfig, AdminAction, AdminCouncil, AdminProposal, TimelockedChange, ChangeStatus, QueuedChange, OperatorRoles, OperatorRole, TransferKind, ProvenanceEntry, ProvenancePage, CertificateStatus, RetirementReason, ServiceRecord, AppraisalRequest, AppraisalRecord, MetadataVersion, TransferPolicy, FeeMint, FeeCurrency, PriceFeed, RejectionCode};
 
 // ID du programme
 declare_id!("FGgYzSL6kTGm2D9UZPCtoGZZykiHZKWUnAUxZiPeXEee");
@@ -27,7 +31,7 @@ pub mod solcertify {
         processor::initialize::handler(ctx)
     }
 
-    /// Ajoute un certificateur agree avec son profil
+    /// Agree un certificateur mis en file d'attente (permissionless apres le preavis)
     pub fn add_certifier(
         ctx: Context<AddCertifier>, 
         certifier: Pubkey,
@@ -37,11 +41,37 @@ pub mod solcertify {
         processor::add_certifier::handler(ctx, certifier, display_name, physical_address)
     }
 
-    /// Retire un certificateur de la liste des agrees
+    /// Ajoute ou met a jour un jeton SPL accepte pour les frais (permissionless apres le preavis)
+    pub fn set_fee_mint(
+        ctx: Context<SetFeeMint>,
+        mint: Pubkey,
+        standard_fee: u64,
+        premium_fee: u64,
+        luxury_fee: u64,
+        exceptional_fee: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        processor::set_fee_mint::handler(
+            ctx,
+            mint,
+            standard_fee,
+            premium_fee,
+            luxury_fee,
+            exceptional_fee,
+            enabled,
+        )
+    }
+
+    /// Retire l'agrement d'un certificateur (desactive son profil)
     pub fn remove_certifier(ctx: Context<RemoveCertifier>, certifier: Pubkey) -> Result<()> {
         processor::remove_certifier::handler(ctx, certifier)
     }
 
+    /// Renouvelle l'accreditation annuelle d'un certificateur (admin)
+    pub fn renew_certifier(ctx: Context<RenewCertifier>, certifier: Pubkey) -> Result<()> {
+        processor::renew_certifier::handler(ctx, certifier)
+    }
+
     /// Emet un nouveau certificat d'authenticite (mode direct - certificateur)
     pub fn issue_certificate(
         ctx: Context<IssueCertificate>,
@@ -51,6 +81,8 @@ pub mod solcertify {
         cert_type: CertificationType,
         estimated_value: u64,
         metadata_uri: String,
+        metadata_hash: [u8; 32],
+        transfer_policy: TransferPolicy,
     ) -> Result<()> {
         processor::issue_certificate::handler(
             ctx,
@@ -60,12 +92,130 @@ pub mod solcertify {
             cert_type,
             estimated_value,
             metadata_uri,
+            metadata_hash,
+            transfer_policy,
         )
     }
 
-    /// Transfère la propriete d'un certificat
-    pub fn transfer_certificate(ctx: Context<TransferCertificate>) -> Result<()> {
-        processor::transfer_certificate::handler(ctx)
+    /// Emet le jeton NFT (Token-2022) representant un certificat (proprietaire, optionnel)
+    /// Le compte de jeton reste gele : le jeton suit les transferts du programme
+    pub fn mint_certificate_nft(ctx: Context<MintCertificateNft>) -> Result<()> {
+        processor::mint_certificate_nft::handler(ctx)
+    }
+
+    /// Propose le transfert d'un certificat a un destinataire (offre)
+    /// Le signataire peut etre le proprietaire ou son delegue de transfert
+    pub fn transfer_certificate(ctx: Context<TransferCertificate>, kind: TransferKind) -> Result<()> {
+        processor::transfer_certificate::handler(ctx, kind)
+    }
+
+    /// Accepte une offre de transfert (destinataire) et devient proprietaire
+    pub fn accept_transfer(ctx: Context<AcceptTransfer>) -> Result<()> {
+        processor::accept_transfer::handler(ctx)
+    }
+
+    /// Approuve une offre de transfert (certificateur emetteur ou delegue habilite)
+    /// Requis pour les certificats soumis a la politique CertifierApproval
+    pub fn approve_transfer(ctx: Context<ApproveTransfer>) -> Result<()> {
+        processor::approve_transfer::handler(ctx)
+    }
+
+    /// Refuse une offre de transfert (destinataire)
+    pub fn decline_transfer(ctx: Context<DeclineTransfer>) -> Result<()> {
+        processor::decline_transfer::handler(ctx)
+    }
+
+    /// Annule une offre de transfert avant acceptation (proprietaire)
+    pub fn cancel_transfer(ctx: Context<CancelTransfer>) -> Result<()> {
+        processor::cancel_transfer::handler(ctx)
+    }
+
+    /// Autorise un delegue (negociant, maison de ventes) a transferer pour le proprietaire
+    pub fn approve_delegate(
+        ctx: Context<ApproveDelegate>,
+        delegate: Pubkey,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        processor::approve_delegate::handler(ctx, delegate, expires_at)
+    }
+
+    /// Revoque le delegue de transfert du certificat (proprietaire)
+    pub fn revoke_delegate(ctx: Context<RevokeDelegate>) -> Result<()> {
+        processor::revoke_delegate::handler(ctx)
+    }
+
+    /// Retire un certificat (montre detruite, demontee ou exportee) - proprietaire
+    pub fn retire_certificate(ctx: Context<RetireCertificate>, reason: RetirementReason) -> Result<()> {
+        processor::retire_certificate::handler(ctx, reason)
+    }
+
+    /// Recupere le loyer d'un certificat retire en compactant son compte (proprietaire)
+    pub fn reclaim_certificate_rent(ctx: Context<ReclaimCertificateRent>) -> Result<()> {
+        processor::reclaim_certificate_rent::handler(ctx)
+    }
+
+    /// Inscrit un entretien dans le carnet du certificat (certificateur ou delegue habilite)
+    pub fn add_service_record(
+        ctx: Context<AddServiceRecord>,
+        serviced_at: i64,
+        work_performed: String,
+        parts_replaced: String,
+        evidence_uri: String,
+    ) -> Result<()> {
+        processor::add_service_record::handler(ctx, serviced_at, work_performed, parts_replaced, evidence_uri)
+    }
+
+    /// Publie une nouvelle version des metadonnees (certificateur emetteur ou delegue habilite)
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
+    ) -> Result<()> {
+        processor::update_metadata::handler(ctx, metadata_uri, metadata_hash)
+    }
+
+    /// Demande la reevaluation d'un certificat a un certificateur (proprietaire, depot sequestre)
+    pub fn request_appraisal(
+        ctx: Context<RequestAppraisal>,
+        certifier: Pubkey,
+        max_fee_difference: u64,
+    ) -> Result<()> {
+        processor::request_appraisal::handler(ctx, certifier, max_fee_difference)
+    }
+
+    /// Signe la nouvelle valeur et le nouveau type d'un certificat (certificateur sollicite)
+    pub fn complete_appraisal(
+        ctx: Context<CompleteAppraisal>,
+        new_value: u64,
+        new_type: CertificationType,
+    ) -> Result<()> {
+        processor::complete_appraisal::handler(ctx, new_value, new_type)
+    }
+
+    /// Annule une demande de reevaluation et restitue le depot (proprietaire)
+    pub fn cancel_appraisal(ctx: Context<CancelAppraisal>) -> Result<()> {
+        processor::cancel_appraisal::handler(ctx)
+    }
+
+    /// Renouvelle la validite d'un certificat apres nouvelle authentification (proprietaire paie les frais)
+    pub fn recertify(ctx: Context<Recertify>) -> Result<()> {
+        processor::recertify::handler(ctx)
+    }
+
+    /// Met un certificat en vente a prix fixe (acheteur designe ou ouvert)
+    pub fn list_certificate(ctx: Context<ListCertificate>, price: u64, buyer: Option<Pubkey>) -> Result<()> {
+        processor::list_certificate::handler(ctx, price, buyer)
+    }
+
+    /// Retire un certificat de la vente (vendeur)
+    pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+        processor::cancel_listing::handler(ctx)
+    }
+
+    /// Achete un certificat en vente : paiement sequestre et echange atomique
+    /// Les redevances de revente vont au certificateur emetteur et a la plateforme
+    pub fn purchase_certificate(ctx: Context<PurchaseCertificate>, max_price: u64) -> Result<()> {
+        processor::purchase_certificate::handler(ctx, max_price)
     }
 
     /// Verifie l'authenticite d'un certificat
@@ -85,6 +235,8 @@ pub mod solcertify {
         cert_type: CertificationType,
         estimated_value: u64,
         metadata_uri: String,
+        metadata_hash: [u8; 32],
+        transfer_policy: TransferPolicy,
         target_certifier: Pubkey, // NOUVEAU: Certificateur choisi
     ) -> Result<()> {
         processor::request_certification::handler(
@@ -95,6 +247,8 @@ pub mod solcertify {
             cert_type,
             estimated_value,
             metadata_uri,
+            metadata_hash,
+            transfer_policy,
             target_certifier,
         )
     }
@@ -105,17 +259,196 @@ pub mod solcertify {
         processor::approve_certification::handler(ctx)
     }
 
+    /// Ouvre une demande de certification sans paiement, en attente d'un devis
+    pub fn request_quote(
+        ctx: Context<RequestQuote>,
+        serial_number: String,
+        brand: String,
+        model: String,
+        cert_type: CertificationType,
+        estimated_value: u64,
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
+        transfer_policy: TransferPolicy,
+        target_certifier: Pubkey,
+    ) -> Result<()> {
+        processor::request_quote::handler(
+            ctx,
+            serial_number,
+            brand,
+            model,
+            cert_type,
+            estimated_value,
+            metadata_uri,
+            metadata_hash,
+            transfer_policy,
+            target_certifier,
+        )
+    }
+
+    /// Publie ou revise le devis d'une demande (certificateur assigne ou delegue)
+    pub fn post_quote(ctx: Context<PostQuote>, amount: u64, expires_at: i64) -> Result<()> {
+        processor::post_quote::handler(ctx, amount, expires_at)
+    }
+
+    /// Accepte le devis en sequestrant son montant (demandeur)
+    pub fn accept_quote(ctx: Context<AcceptQuote>) -> Result<()> {
+        processor::accept_quote::handler(ctx)
+    }
+
+    /// Annule une demande sur devis non payee (demandeur)
+    pub fn cancel_request(ctx: Context<CancelRequest>) -> Result<()> {
+        processor::cancel_request::handler(ctx)
+    }
+
+    /// Cloture une demande dont le devis a expire (permissionless)
+    pub fn expire_quote(ctx: Context<ExpireQuote>) -> Result<()> {
+        processor::expire_quote::handler(ctx)
+    }
+
     /// Rejette une demande de certification (certificateur)
-    /// Rembourse les frais au demandeur
-    pub fn reject_certification(ctx: Context<RejectCertification>, reason: String) -> Result<()> {
-        processor::reject_certification::handler(ctx, reason)
+    /// Rembourse les frais au demandeur, moins les frais d'inspection selon le motif
+    pub fn reject_certification(
+        ctx: Context<RejectCertification>,
+        rejection_code: RejectionCode,
+        reason: String,
+    ) -> Result<()> {
+        processor::reject_certification::handler(ctx, rejection_code, reason)
+    }
+
+    /// Enregistre une cle deleguee (employe) pour le certificateur signataire
+    pub fn add_delegate(
+        ctx: Context<AddDelegate>,
+        delegate: Pubkey,
+        permissions: DelegatePermissions,
+        expires_at: i64,
+    ) -> Result<()> {
+        processor::add_delegate::handler(ctx, delegate, permissions, expires_at)
+    }
+
+    /// Revoque une cle deleguee du certificateur signataire
+    pub fn remove_delegate(ctx: Context<RemoveDelegate>) -> Result<()> {
+        processor::remove_delegate::handler(ctx)
+    }
+
+    /// Retire les gains du coffre du certificateur vers son adresse de paiement
+    pub fn withdraw_earnings(ctx: Context<WithdrawEarnings>, amount: u64) -> Result<()> {
+        processor::withdraw_earnings::handler(ctx, amount)
+    }
+
+    /// Configure l'adresse de paiement des gains du certificateur
+    pub fn set_payout_address(ctx: Context<SetPayoutAddress>, payout_address: Pubkey) -> Result<()> {
+        processor::set_payout_address::handler(ctx, payout_address)
+    }
+
+    /// Bloque ou debloque les paiements d'un certificateur (admin, litiges)
+    pub fn set_payouts_held(ctx: Context<SetPayoutsHeld>, certifier: Pubkey, held: bool) -> Result<()> {
+        processor::set_payouts_held::handler(ctx, certifier, held)
+    }
+
+    /// Retire les gains en jetons du coffre du certificateur vers son adresse de paiement
+    pub fn withdraw_token_earnings(ctx: Context<WithdrawTokenEarnings>, amount: u64) -> Result<()> {
+        processor::withdraw_token_earnings::handler(ctx, amount)
+    }
+
+    /// Retire des jetons de la tresorerie (admin)
+    pub fn withdraw_treasury_tokens(ctx: Context<WithdrawTreasuryTokens>, amount: u64) -> Result<()> {
+        processor::withdraw_treasury_tokens::handler(ctx, amount)
+    }
+
+    /// Retire des fonds du coffre de la plateforme (admin)
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        processor::withdraw_treasury::handler(ctx, amount)
+    }
+
+    /// Designe l'adresse de paiement des retraits de tresorerie (admin ou conseil)
+    pub fn set_treasury(ctx: Context<SetTreasury>, payout_address: Pubkey) -> Result<()> {
+        processor::set_treasury::handler(ctx, payout_address)
+    }
+
+    /// Designe l'oracle autorise a publier le cours SOL/EUR (admin)
+    pub fn set_price_oracle(
+        ctx: Context<SetPriceOracle>,
+        oracle: Pubkey,
+        max_staleness: i64,
+    ) -> Result<()> {
+        processor::set_price_oracle::handler(ctx, oracle, max_staleness)
+    }
+
+    /// Publie le cours SOL/EUR (oracle autorise)
+    pub fn update_price(ctx: Context<UpdatePrice>, sol_price_eur_cents: u64) -> Result<()> {
+        processor::update_price::handler(ctx, sol_price_eur_cents)
+    }
+
+    /// Applique une configuration mise en file d'attente (permissionless apres le preavis)
+    pub fn update_config(ctx: Context<UpdateConfig>, config: ProtocolConfig) -> Result<()> {
+        processor::update_config::handler(ctx, config)
+    }
+
+    /// Met en file d'attente une modification soumise au preavis (admin ou conseil)
+    pub fn queue_change(ctx: Context<QueueChange>, change: TimelockedChange) -> Result<()> {
+        processor::queue_change::handler(ctx, change)
+    }
+
+    /// Annule une modification pendant son preavis (admin ou conseil)
+    pub fn cancel_change(ctx: Context<CancelChange>, id: u64) -> Result<()> {
+        processor::cancel_change::handler(ctx, id)
+    }
+
+    /// Attribue des roles operationnels a un operateur (admin ou conseil)
+    pub fn set_operator_roles(
+        ctx: Context<SetOperatorRoles>,
+        operator: Pubkey,
+        roles: OperatorRoles,
+    ) -> Result<()> {
+        processor::set_operator_roles::handler(ctx, operator, roles)
+    }
+
+    /// Suspend ou reprend le protocole (admin, conseil ou role "pauser")
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        processor::set_paused::handler(ctx, paused)
+    }
+
+    /// Corrige le proprietaire d'un certificat (admin, conseil ou role "dispute resolver")
+    pub fn correct_owner(ctx: Context<CorrectOwner>, new_owner: Pubkey) -> Result<()> {
+        processor::correct_owner::handler(ctx, new_owner)
+    }
+
+    /// Confirme ou refuse le retrait d'un certificat Luxury/Exceptional (admin ou conseil)
+    pub fn confirm_retirement(ctx: Context<ConfirmRetirement>, approved: bool) -> Result<()> {
+        processor::confirm_retirement::handler(ctx, approved)
+    }
+
+    // ==================== GOUVERNANCE (CONSEIL M-SUR-N) ====================
+
+    /// Active le conseil d'administration M-sur-N (admin, une seule fois)
+    pub fn create_council(ctx: Context<CreateCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        processor::create_council::handler(ctx, members, threshold)
+    }
+
+    /// Propose une operation sensible au conseil (membre)
+    pub fn propose_admin_action(ctx: Context<ProposeAdminAction>, action: AdminAction) -> Result<()> {
+        processor::propose_admin_action::handler(ctx, action)
+    }
+
+    /// Approuve une proposition en attente (membre)
+    pub fn approve_admin_action(ctx: Context<ApproveAdminAction>) -> Result<()> {
+        processor::approve_admin_action::handler(ctx)
+    }
+
+    /// Modifie les membres et le seuil du conseil (proposition approuvee)
+    pub fn update_council(ctx: Context<UpdateCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        processor::update_council::handler(ctx, members, threshold)
     }
 }
 
 // ==================== ACCOUNTS STRUCTS ====================
 
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::errors::ErrorCode;
-use crate::state::{Certificate, CertificationAuthority, CertificationRequest, UserActivity, MAX_CONCURRENT_REQUESTS};
+use crate::state::{Certificate, CertificationAuthority, CertificationRequest, Listing, PendingTransfer, UserActivity, MAX_CONCURRENT_REQUESTS};
 
 /// Structure retournee lors de la verification d'un certificat
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -127,12 +460,28 @@ pub struct CertificateInfo {
     pub estimated_value: u64,
     pub owner: Pubkey,
     pub certifier: Pubkey,
+    pub acting_delegate: Option<Pubkey>,
     pub metadata_uri: String,
+    pub metadata_hash: [u8; 32],
+    pub metadata_version: u32,
     pub created_at: i64,
     pub last_transfer_at: i64,
     pub locked_until: i64,
     pub is_locked: bool,
-    pub previous_owners_count: u8,
+    pub transfer_count: u32,
+    pub last_sale_price: u64,
+    pub total_royalties_paid: u64,
+    pub status: CertificateStatus,
+    pub retirement_reason: Option<RetirementReason>,
+    pub retired_at: i64,
+    pub service_count: u32,
+    pub last_serviced_at: i64,
+    pub appraisal_count: u32,
+    pub transfer_policy: TransferPolicy,
+    pub valid_until: i64,
+    pub is_expired: bool,
+    pub last_certified_at: i64,
+    pub last_certified_by: Pubkey,
     pub total_certificates_issued: u64,
 }
 
@@ -151,8 +500,54 @@ pub struct Initialize<'info> {
     )]
     pub authority: Account<'info, CertificationAuthority>,
 
-    /// CHECK: Ce compte est seulement utilise pour recevoir des SOL
-    pub treasury: AccountInfo<'info>,
+    /// Coffre de la plateforme (PDA detenu par le programme)
+    #[account(
+        init,
+        payer = admin,
+        space = TreasuryVault::SPACE,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, TreasuryVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === SetFeeMint ===
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct SetFeeMint<'info> {
+    /// N'importe quel signataire une fois le préavis écoulé (paie le compte créé)
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    /// Mint du jeton (vérifie qu'il s'agit bien d'un mint SPL)
+    #[account(constraint = payment_mint.key() == mint @ ErrorCode::InvalidTokenAccount)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    /// Jeton de la liste blanche - créé lors du premier ajout, mis à jour ensuite
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = FeeMint::SPACE,
+        seeds = [b"fee_mint", mint.as_ref()],
+        bump
+    )]
+    pub fee_mint: Account<'info, FeeMint>,
+
+    /// Modification mise en file d'attente à appliquer
+    #[account(
+        mut,
+        seeds = [b"queued_change".as_ref(), &queued_change.id.to_le_bytes()],
+        bump = queued_change.bump
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
 
     pub system_program: Program<'info, System>,
 }
@@ -161,11 +556,9 @@ pub struct Initialize<'info> {
 #[derive(Accounts)]
 #[instruction(certifier: Pubkey)]
 pub struct AddCertifier<'info> {
-    #[account(
-        mut,
-        constraint = admin.key() == authority.admin @ ErrorCode::UnauthorizedCertifier
-    )]
-    pub admin: Signer<'info>,
+    /// N'importe quel signataire une fois le préavis écoulé (paie les comptes créés)
+    #[account(mut)]
+    pub executor: Signer<'info>,
 
     #[account(
         mut,
@@ -174,42 +567,123 @@ pub struct AddCertifier<'info> {
     )]
     pub authority: Account<'info, CertificationAuthority>,
 
-    /// Profil du nouveau certificateur - créé lors de l'ajout
+    /// Profil du certificateur - créé lors du premier agrément, réactivé ensuite
     #[account(
-        init,
-        payer = admin,
+        init_if_needed,
+        payer = executor,
         space = CertifierProfile::SPACE,
         seeds = [b"certifier_profile", certifier.as_ref()],
         bump
     )]
     pub certifier_profile: Account<'info, CertifierProfile>,
 
+    /// Coffre des gains du certificateur - créé lors du premier agrément
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = CertifierVault::SPACE,
+        seeds = [b"certifier_vault", certifier.as_ref()],
+        bump
+    )]
+    pub certifier_vault: Account<'info, CertifierVault>,
+
     pub system_program: Program<'info, System>,
+
+    /// Modification mise en file d'attente à appliquer
+    #[account(
+        mut,
+        seeds = [b"queued_change".as_ref(), &queued_change.id.to_le_bytes()],
+        bump = queued_change.bump
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
 }
 
 // === RemoveCertifier ===
 #[derive(Accounts)]
+#[instruction(certifier: Pubkey)]
 pub struct RemoveCertifier<'info> {
+    /// Admin (ou payeur exécutant une proposition approuvée du conseil)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
     #[account(
         mut,
-        constraint = admin.key() == authority.admin @ ErrorCode::UnauthorizedCertifier
+        seeds = [b"auth_v5"],
+        bump = authority.bump
     )]
-    pub admin: Signer<'info>,
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"certifier_profile", certifier.as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
 
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
     #[account(
         mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    /// Rôle opérateur du signataire (absent si l'admin ou le conseil agit)
+    #[account(
+        seeds = [b"operator_role", admin.key().as_ref()],
+        bump = operator_role.bump,
+        constraint = operator_role.roles.certifier_manager @ ErrorCode::MissingRole
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+}
+
+// === RenewCertifier ===
+#[derive(Accounts)]
+#[instruction(certifier: Pubkey)]
+pub struct RenewCertifier<'info> {
+    /// Admin (ou signataire exécutant une proposition approuvée du conseil)
+    pub admin: Signer<'info>,
+
+    #[account(
         seeds = [b"auth_v5"],
         bump = authority.bump
     )]
     pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"certifier_profile", certifier.as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    /// Rôle opérateur du signataire (absent si l'admin ou le conseil agit)
+    #[account(
+        seeds = [b"operator_role", admin.key().as_ref()],
+        bump = operator_role.bump,
+        constraint = operator_role.roles.certifier_manager @ ErrorCode::MissingRole
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
 }
 
 // === IssueCertificate (mode direct) ===
 #[derive(Accounts)]
 #[instruction(serial_number: String)]
 pub struct IssueCertificate<'info> {
+    /// Certificateur ou délégué qui signe et paie les frais
     #[account(mut)]
-    pub certifier: Signer<'info>,
+    pub operator: Signer<'info>,
+
+    /// CHECK: Certificateur pour le compte duquel le certificat est émis
+    pub certifier: AccountInfo<'info>,
 
     /// CHECK: Ce compte est seulement utilise comme reference pour le proprietaire
     pub owner: AccountInfo<'info>,
@@ -217,13 +691,14 @@ pub struct IssueCertificate<'info> {
     #[account(
         mut,
         seeds = [b"auth_v5"],
-        bump = authority.bump
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
     )]
     pub authority: Account<'info, CertificationAuthority>,
 
     #[account(
         init,
-        payer = certifier,
+        payer = operator,
         space = Certificate::SPACE,
         seeds = [b"certificate", serial_number.as_bytes()],
         bump
@@ -232,123 +707,1863 @@ pub struct IssueCertificate<'info> {
 
     #[account(
         init_if_needed,
-        payer = certifier,
+        payer = operator,
         space = UserActivity::SPACE,
         seeds = [b"user_activity", owner.key().as_ref()],
         bump
     )]
     pub owner_activity: Account<'info, UserActivity>,
 
-    /// CHECK: Verifie par la contrainte sur authority.treasury
+    /// Profil du certificateur pour comptabiliser les émissions directes
     #[account(
         mut,
-        constraint = treasury.key() == authority.treasury @ ErrorCode::UnauthorizedCertifier
+        seeds = [b"certifier_profile", certifier.key().as_ref()],
+        bump = certifier_profile.bump
     )]
-    pub treasury: AccountInfo<'info>,
-
-    pub system_program: Program<'info, System>,
-}
-
-// === TransferCertificate ===
-#[derive(Accounts)]
-pub struct TransferCertificate<'info> {
-    #[account(mut)]
-    pub from: Signer<'info>,
-
-    /// CHECK: Ce compte est seulement utilise comme reference
-    pub to: AccountInfo<'info>,
+    pub certifier_profile: Account<'info, CertifierProfile>,
 
+    /// Délégation de l'opérateur (absente si le certificateur signe lui-même)
     #[account(
-        mut,
-        seeds = [b"certificate", certificate.serial_number.as_bytes()],
-        bump = certificate.bump
+        seeds = [b"certifier_delegate", certifier.key().as_ref(), operator.key().as_ref()],
+        bump = delegate.bump
     )]
-    pub certificate: Account<'info, Certificate>,
+    pub delegate: Option<Account<'info, CertifierDelegate>>,
 
+    /// Coffre de la plateforme qui reçoit les frais
     #[account(
         mut,
-        seeds = [b"user_activity", from.key().as_ref()],
-        bump = from_activity.bump
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = treasury.key() == authority.treasury @ ErrorCode::InvalidTreasury
     )]
-    pub from_activity: Account<'info, UserActivity>,
+    pub treasury: Account<'info, TreasuryVault>,
 
+    /// Jeton de paiement de la liste blanche (absent pour un paiement en SOL)
     #[account(
-        init_if_needed,
-        payer = from,
-        space = UserActivity::SPACE,
-        seeds = [b"user_activity", to.key().as_ref()],
-        bump
+        seeds = [b"fee_mint", fee_mint.mint.as_ref()],
+        bump = fee_mint.bump
     )]
-    pub to_activity: Account<'info, UserActivity>,
+    pub fee_mint: Option<Account<'info, FeeMint>>,
 
-    pub system_program: Program<'info, System>,
-}
+    /// Mint du jeton de paiement (requis si les frais sont payés en jetons)
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
 
-// === VerifyCertificate ===
-#[derive(Accounts)]
-pub struct VerifyCertificate<'info> {
+    /// Compte de jeton de l'opérateur qui paie les frais
+    #[account(mut)]
+    pub payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Compte de jeton détenu par la trésorerie
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Cours SOL/EUR de l'oracle (requis si les frais sont exprimés en euros)
     #[account(
-        seeds = [b"certificate", certificate.serial_number.as_bytes()],
-        bump = certificate.bump
+        seeds = [b"price_feed"],
+        bump = price_feed.bump
     )]
-    pub certificate: Account<'info, Certificate>,
+    pub price_feed: Option<Account<'info, PriceFeed>>,
 
+    /// Mint NFT du certificat (absent si aucun jeton n'est émis à la création)
     #[account(
-        seeds = [b"auth_v5"],
+        init,
+        payer = operator,
+        seeds = [b"certificate_mint", certificate.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = authority,
+        mint::freeze_authority = authority,
+        mint::token_program = nft_token_program,
+        extensions::metadata_pointer::authority = authority,
+        extensions::metadata_pointer::metadata_address = nft_mint,
+        extensions::permanent_delegate::delegate = authority
+    )]
+    pub nft_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// CHECK: Compte de jeton associé du propriétaire (créé via le programme de comptes associés)
+    #[account(mut)]
+    pub owner_nft_account: Option<AccountInfo<'info>>,
+
+    pub nft_token_program: Option<Program<'info, Token2022>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === MintCertificateNft ===
+#[derive(Accounts)]
+pub struct MintCertificateNft<'info> {
+    /// Propriétaire du certificat (paie le mint et son compte de jeton)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Autorité du programme : autorité de mint, de gel et délégué permanent
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump,
+        constraint = certificate.owner == owner.key() @ ErrorCode::NotOwner
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"certificate_mint", certificate.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = authority,
+        mint::freeze_authority = authority,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = authority,
+        extensions::metadata_pointer::metadata_address = mint,
+        extensions::permanent_delegate::delegate = authority
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub owner_nft_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+// === TransferCertificate (offre) ===
+#[derive(Accounts)]
+pub struct TransferCertificate<'info> {
+    /// Propriétaire ou délégué de transfert approuvé (paie le compte d'offre)
+    #[account(mut)]
+    pub from: Signer<'info>,
+
+    /// CHECK: Destinataire de l'offre (doit l'accepter pour devenir propriétaire)
+    pub to: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    /// Offre en attente (une seule à la fois par certificat)
+    #[account(
+        init,
+        payer = from,
+        space = PendingTransfer::SPACE,
+        seeds = [b"pending_transfer", certificate.key().as_ref()],
+        bump
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === AcceptTransfer ===
+#[derive(Accounts)]
+pub struct AcceptTransfer<'info> {
+    /// Destinataire de l'offre (paie son compte d'activité si nécessaire)
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// CHECK: Propriétaire au moment de l'offre (ancien propriétaire)
+    #[account(
+        constraint = from.key() == pending_transfer.from @ ErrorCode::NotOwner
+    )]
+    pub from: AccountInfo<'info>,
+
+    /// CHECK: Initiateur de l'offre (propriétaire ou délégué), récupère le loyer de l'offre
+    #[account(
+        mut,
+        constraint = initiator.key() == pending_transfer.initiator @ ErrorCode::NotOwner
+    )]
+    pub initiator: AccountInfo<'info>,
+
+    /// Autorité (paramètres du protocole : délais de verrouillage et cooldown)
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        mut,
+        close = initiator,
+        seeds = [b"pending_transfer", certificate.key().as_ref()],
+        bump = pending_transfer.bump,
+        constraint = pending_transfer.to == recipient.key() @ ErrorCode::NotTransferRecipient
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+
+    #[account(
+        mut,
+        seeds = [b"user_activity", from.key().as_ref()],
+        bump = from_activity.bump
+    )]
+    pub from_activity: Account<'info, UserActivity>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        space = UserActivity::SPACE,
+        seeds = [b"user_activity", recipient.key().as_ref()],
+        bump
+    )]
+    pub to_activity: Account<'info, UserActivity>,
+
+    /// Page de provenance courante (créée au premier transfert de chaque page)
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        space = ProvenancePage::SPACE,
+        seeds = [
+            b"provenance".as_ref(),
+            certificate.key().as_ref(),
+            &certificate.current_provenance_page().to_le_bytes()
+        ],
+        bump
+    )]
+    pub provenance_page: Account<'info, ProvenancePage>,
+
+    /// Mint NFT du certificat (requis si un jeton a été émis)
+    pub nft_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Compte de jeton NFT du propriétaire actuel
+    #[account(mut)]
+    pub from_nft_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Compte de jeton NFT du nouveau propriétaire (créé au préalable par le client)
+    #[account(mut)]
+    pub to_nft_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token2022>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === ApproveTransfer ===
+#[derive(Accounts)]
+pub struct ApproveTransfer<'info> {
+    /// Certificateur émetteur ou délégué habilité à approuver
+    pub operator: Signer<'info>,
+
+    /// CHECK: Certificateur émetteur du certificat
+    #[account(
+        constraint = certifier.key() == certificate.certifier @ ErrorCode::NotIssuingCertifier
+    )]
+    pub certifier: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        seeds = [b"certifier_profile", certifier.key().as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    /// Délégation de l'opérateur (absente si le certificateur signe lui-même)
+    #[account(
+        seeds = [b"certifier_delegate", certifier.key().as_ref(), operator.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, CertifierDelegate>>,
+
+    #[account(
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_transfer", certificate.key().as_ref()],
+        bump = pending_transfer.bump
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+}
+
+// === DeclineTransfer ===
+#[derive(Accounts)]
+pub struct DeclineTransfer<'info> {
+    pub recipient: Signer<'info>,
+
+    /// CHECK: Initiateur de l'offre (propriétaire ou délégué), récupère le loyer de l'offre
+    #[account(
+        mut,
+        constraint = initiator.key() == pending_transfer.initiator @ ErrorCode::NotOwner
+    )]
+    pub initiator: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        mut,
+        close = initiator,
+        seeds = [b"pending_transfer", certificate.key().as_ref()],
+        bump = pending_transfer.bump,
+        constraint = pending_transfer.to == recipient.key() @ ErrorCode::NotTransferRecipient
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+}
+
+// === CancelTransfer ===
+#[derive(Accounts)]
+pub struct CancelTransfer<'info> {
+    /// Propriétaire au moment de l'offre, initiateur de l'offre, ou propriétaire actuel
+    /// (une offre devenue caduque après une vente, une correction ou un retrait
+    /// bloquerait sinon toute nouvelle offre du nouveau propriétaire)
+    #[account(
+        constraint = from.key() == pending_transfer.from
+            || from.key() == pending_transfer.initiator
+            || from.key() == certificate.owner @ ErrorCode::NotOwner
+    )]
+    pub from: Signer<'info>,
+
+    /// CHECK: Initiateur de l'offre (propriétaire ou délégué), récupère le loyer de l'offre
+    #[account(
+        mut,
+        constraint = initiator.key() == pending_transfer.initiator @ ErrorCode::NotOwner
+    )]
+    pub initiator: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        mut,
+        close = initiator,
+        seeds = [b"pending_transfer", certificate.key().as_ref()],
+        bump = pending_transfer.bump
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+}
+
+// === ApproveDelegate ===
+#[derive(Accounts)]
+pub struct ApproveDelegate<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump,
+        constraint = certificate.owner == owner.key() @ ErrorCode::NotOwner
+    )]
+    pub certificate: Account<'info, Certificate>,
+}
+
+// === RetireCertificate ===
+#[derive(Accounts)]
+pub struct RetireCertificate<'info> {
+    pub owner: Signer<'info>,
+
+    /// Autorité du programme : délégué permanent qui brûle le jeton NFT
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump,
+        constraint = certificate.owner == owner.key() @ ErrorCode::NotOwner
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        mut,
+        seeds = [b"user_activity", owner.key().as_ref()],
+        bump = owner_activity.bump
+    )]
+    pub owner_activity: Account<'info, UserActivity>,
+
+    /// Mint NFT du certificat (requis si un jeton a été émis)
+    #[account(mut)]
+    pub nft_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Compte de jeton NFT du propriétaire (jeton brûlé au retrait)
+    #[account(mut)]
+    pub owner_nft_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token2022>>,
+}
+
+// === ReclaimCertificateRent ===
+#[derive(Accounts)]
+pub struct ReclaimCertificateRent<'info> {
+    /// Propriétaire au moment du retrait (reçoit le loyer récupéré)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump,
+        constraint = certificate.owner == owner.key() @ ErrorCode::NotOwner
+    )]
+    pub certificate: Account<'info, Certificate>,
+}
+
+// === AddServiceRecord ===
+#[derive(Accounts)]
+pub struct AddServiceRecord<'info> {
+    /// Certificateur ou délégué qui signe et paie le compte d'entretien
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Certificateur pour le compte duquel l'entretien est enregistré
+    pub certifier: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        seeds = [b"certifier_profile", certifier.key().as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    /// Délégation de l'opérateur (absente si le certificateur signe lui-même)
+    #[account(
+        seeds = [b"certifier_delegate", certifier.key().as_ref(), operator.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, CertifierDelegate>>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = ServiceRecord::SPACE,
+        seeds = [
+            b"service_record".as_ref(),
+            certificate.key().as_ref(),
+            &certificate.service_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub service_record: Account<'info, ServiceRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === UpdateMetadata ===
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    /// Certificateur ou délégué qui signe (paie l'archive de la version précédente)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Certificateur émetteur du certificat
+    #[account(
+        constraint = certifier.key() == certificate.certifier @ ErrorCode::NotIssuingCertifier
+    )]
+    pub certifier: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        seeds = [b"certifier_profile", certifier.key().as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    /// Délégation de l'opérateur (absente si le certificateur signe lui-même)
+    #[account(
+        seeds = [b"certifier_delegate", certifier.key().as_ref(), operator.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, CertifierDelegate>>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    /// Archive de la version courante (index = version remplacée)
+    #[account(
+        init,
+        payer = operator,
+        space = MetadataVersion::SPACE,
+        seeds = [
+            b"metadata_version".as_ref(),
+            certificate.key().as_ref(),
+            &certificate.metadata_version.to_le_bytes()
+        ],
+        bump
+    )]
+    pub metadata_version: Account<'info, MetadataVersion>,
+
+    /// Mint NFT du certificat (requis si un jeton a été émis : URI synchronisée)
+    #[account(mut)]
+    pub nft_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Program<'info, Token2022>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === RequestAppraisal ===
+#[derive(Accounts)]
+#[instruction(certifier: Pubkey)]
+pub struct RequestAppraisal<'info> {
+    /// Propriétaire (paie le compte de demande et le dépôt)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump,
+        constraint = certificate.owner == owner.key() @ ErrorCode::NotOwner
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    /// Profil du certificateur sollicité
+    #[account(
+        seeds = [b"certifier_profile", certifier.as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = AppraisalRequest::SPACE,
+        seeds = [b"appraisal_request", certificate.key().as_ref()],
+        bump
+    )]
+    pub appraisal_request: Account<'info, AppraisalRequest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === CompleteAppraisal ===
+#[derive(Accounts)]
+pub struct CompleteAppraisal<'info> {
+    /// Certificateur ou délégué qui signe (paie l'entrée d'historique)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Certificateur sollicité par le propriétaire
+    #[account(
+        constraint = certifier.key() == appraisal_request.certifier @ ErrorCode::NotRequestedAppraiser
+    )]
+    pub certifier: AccountInfo<'info>,
+
+    /// CHECK: Demandeur, récupère le reliquat du dépôt et le loyer
+    #[account(
+        mut,
+        constraint = owner.key() == appraisal_request.owner @ ErrorCode::NotOwner
+    )]
+    pub owner: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"certifier_profile", certifier.key().as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    /// Délégation de l'opérateur (absente si le certificateur signe lui-même)
+    #[account(
+        seeds = [b"certifier_delegate", certifier.key().as_ref(), operator.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, CertifierDelegate>>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"appraisal_request", certificate.key().as_ref()],
+        bump = appraisal_request.bump
+    )]
+    pub appraisal_request: Account<'info, AppraisalRequest>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = AppraisalRecord::SPACE,
+        seeds = [
+            b"appraisal".as_ref(),
+            certificate.key().as_ref(),
+            &certificate.appraisal_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub appraisal_record: Account<'info, AppraisalRecord>,
+
+    /// Coffre des gains du certificateur
+    #[account(
+        mut,
+        seeds = [b"certifier_vault", certifier.key().as_ref()],
+        bump = certifier_vault.bump
+    )]
+    pub certifier_vault: Account<'info, CertifierVault>,
+
+    /// Coffre de la plateforme qui reçoit sa part des frais
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = treasury.key() == authority.treasury @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: Account<'info, TreasuryVault>,
+
+    /// Cours SOL/EUR de l'oracle (requis si les frais sont exprimés en euros)
+    #[account(
+        seeds = [b"price_feed"],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === CancelAppraisal ===
+#[derive(Accounts)]
+pub struct CancelAppraisal<'info> {
+    /// Demandeur (récupère le dépôt et le loyer)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"appraisal_request", certificate.key().as_ref()],
+        bump = appraisal_request.bump,
+        constraint = appraisal_request.owner == owner.key() @ ErrorCode::NotOwner
+    )]
+    pub appraisal_request: Account<'info, AppraisalRequest>,
+}
+
+// === Recertify ===
+#[derive(Accounts)]
+pub struct Recertify<'info> {
+    /// Propriétaire qui présente la montre et paie les frais de recertification
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Certificateur ou délégué qui signe la nouvelle authentification
+    pub operator: Signer<'info>,
+
+    /// CHECK: Certificateur pour le compte duquel la recertification est signée
+    pub certifier: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        seeds = [b"certifier_profile", certifier.key().as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    /// Délégation de l'opérateur (absente si le certificateur signe lui-même)
+    #[account(
+        seeds = [b"certifier_delegate", certifier.key().as_ref(), operator.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, CertifierDelegate>>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump,
+        constraint = certificate.owner == owner.key() @ ErrorCode::NotOwner
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    /// Coffre des gains du certificateur
+    #[account(
+        mut,
+        seeds = [b"certifier_vault", certifier.key().as_ref()],
+        bump = certifier_vault.bump
+    )]
+    pub certifier_vault: Account<'info, CertifierVault>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = treasury.key() == authority.treasury @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: Account<'info, TreasuryVault>,
+
+    /// Cours SOL/EUR de l'oracle (requis si les frais sont exprimés en euros)
+    #[account(
+        seeds = [b"price_feed"],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === RevokeDelegate ===
+#[derive(Accounts)]
+pub struct RevokeDelegate<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump,
+        constraint = certificate.owner == owner.key() @ ErrorCode::NotOwner
+    )]
+    pub certificate: Account<'info, Certificate>,
+}
+
+// === ListCertificate ===
+#[derive(Accounts)]
+pub struct ListCertificate<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    /// Mise en vente (une seule à la fois par certificat)
+    #[account(
+        init,
+        payer = seller,
+        space = Listing::SPACE,
+        seeds = [b"listing", certificate.key().as_ref()],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === CancelListing ===
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    /// Vendeur ou propriétaire actuel (une vente devenue caduque après un
+    /// transfert, une correction ou un retrait bloquerait sinon toute remise en vente)
+    #[account(
+        constraint = closer.key() == listing.seller
+            || closer.key() == certificate.owner @ ErrorCode::NotOwner
+    )]
+    pub closer: Signer<'info>,
+
+    /// CHECK: Vendeur ayant créé la mise en vente, récupère le loyer du compte
+    #[account(
+        mut,
+        constraint = seller.key() == listing.seller @ ErrorCode::NotOwner
+    )]
+    pub seller: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"listing", certificate.key().as_ref()],
+        bump = listing.bump
+    )]
+    pub listing: Account<'info, Listing>,
+}
+
+// === PurchaseCertificate ===
+#[derive(Accounts)]
+pub struct PurchaseCertificate<'info> {
+    /// Acheteur (paie le prix et son compte d'activité si nécessaire)
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Vendeur, vérifié contre la mise en vente ; reçoit le prix et le loyer
+    #[account(
+        mut,
+        constraint = seller.key() == listing.seller @ ErrorCode::NotOwner
+    )]
+    pub seller: AccountInfo<'info>,
+
+    /// Autorité (paramètres du protocole : délais de verrouillage et cooldown)
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    /// Mise en vente, sert de séquestre pendant l'échange puis est fermée
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"listing", certificate.key().as_ref()],
+        bump = listing.bump
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(
+        mut,
+        seeds = [b"user_activity", seller.key().as_ref()],
+        bump = seller_activity.bump
+    )]
+    pub seller_activity: Account<'info, UserActivity>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = UserActivity::SPACE,
+        seeds = [b"user_activity", buyer.key().as_ref()],
+        bump
+    )]
+    pub buyer_activity: Account<'info, UserActivity>,
+
+    /// Coffre des gains du certificateur émetteur (redevance de revente)
+    #[account(
+        mut,
+        seeds = [b"certifier_vault", certificate.certifier.as_ref()],
+        bump = certifier_vault.bump
+    )]
+    pub certifier_vault: Account<'info, CertifierVault>,
+
+    /// Coffre de la plateforme (redevance de revente)
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = treasury.key() == authority.treasury @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: Account<'info, TreasuryVault>,
+
+    /// Page de provenance courante (créée au premier transfert de chaque page)
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = ProvenancePage::SPACE,
+        seeds = [
+            b"provenance".as_ref(),
+            certificate.key().as_ref(),
+            &certificate.current_provenance_page().to_le_bytes()
+        ],
+        bump
+    )]
+    pub provenance_page: Account<'info, ProvenancePage>,
+
+    /// Mint NFT du certificat (requis si un jeton a été émis)
+    pub nft_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Compte de jeton NFT du propriétaire actuel
+    #[account(mut)]
+    pub from_nft_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Compte de jeton NFT du nouveau propriétaire (créé au préalable par le client)
+    #[account(mut)]
+    pub to_nft_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token2022>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === VerifyCertificate ===
+#[derive(Accounts)]
+pub struct VerifyCertificate<'info> {
+    #[account(
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+}
+
+// ==================== NOUVELLES STRUCTS ACCOUNTS ====================
+
+// === RequestCertification ===
+#[derive(Accounts)]
+#[instruction(serial_number: String, _brand: String, _model: String, _cert_type: CertificationType, _estimated_value: u64, _metadata_uri: String, _metadata_hash: [u8; 32], _transfer_policy: TransferPolicy, target_certifier: Pubkey)]
+pub struct RequestCertification<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        init,
+        payer = requester,
+        space = CertificationRequest::SPACE,
+        seeds = [b"request", serial_number.as_bytes()],
+        bump
+    )]
+    pub request: Account<'info, CertificationRequest>,
+
+    /// Profil du certificateur cible - vérifie qu'il peut accepter des demandes
+    #[account(
+        mut,
+        seeds = [b"certifier_profile", target_certifier.as_ref()],
+        bump = certifier_profile.bump,
+        constraint = certifier_profile.is_active @ ErrorCode::CertifierNotActive,
+        constraint = certifier_profile.current_load < MAX_CONCURRENT_REQUESTS @ ErrorCode::CertifierAtCapacity
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    /// Jeton de paiement de la liste blanche (absent pour un paiement en SOL)
+    #[account(
+        seeds = [b"fee_mint", fee_mint.mint.as_ref()],
+        bump = fee_mint.bump
+    )]
+    pub fee_mint: Option<Account<'info, FeeMint>>,
+
+    /// Mint du jeton de paiement (requis si les frais sont payés en jetons)
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Compte de jeton du demandeur
+    #[account(mut)]
+    pub payer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Séquestre : compte de jeton détenu par la demande (créé au préalable par le client)
+    #[account(mut)]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Cours SOL/EUR de l'oracle (requis si les frais sont exprimés en euros)
+    #[account(
+        seeds = [b"price_feed"],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === ApproveCertification ===
+#[derive(Accounts)]
+pub struct ApproveCertification<'info> {
+    /// Certificateur ou délégué qui signe et paie le loyer du certificat
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Certificateur assigné à la demande
+    #[account(
+        constraint = certifier.key() == certifier_profile.certifier @ ErrorCode::NotAssignedCertifier
+    )]
+    pub certifier: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"request", request.serial_number.as_bytes()],
+        bump = request.bump
+    )]
+    pub request: Account<'info, CertificationRequest>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = Certificate::SPACE,
+        seeds = [b"certificate", request.serial_number.as_bytes()],
+        bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = UserActivity::SPACE,
+        seeds = [b"user_activity", request.requester.as_ref()],
+        bump
+    )]
+    pub owner_activity: Account<'info, UserActivity>,
+
+    /// Profil du certificateur pour mettre à jour ses stats
+    #[account(
+        mut,
+        seeds = [b"certifier_profile", certifier.key().as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    /// Délégation de l'opérateur (absente si le certificateur signe lui-même)
+    #[account(
+        seeds = [b"certifier_delegate", certifier.key().as_ref(), operator.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, CertifierDelegate>>,
+
+    /// Coffre des gains du certificateur qui reçoit sa part des frais
+    #[account(
+        mut,
+        seeds = [b"certifier_vault", certifier.key().as_ref()],
+        bump = certifier_vault.bump
+    )]
+    pub certifier_vault: Account<'info, CertifierVault>,
+
+    /// Coffre de la plateforme qui reçoit sa part des frais
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = treasury.key() == authority.treasury @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: Account<'info, TreasuryVault>,
+
+    /// CHECK: Demandeur, récupère le loyer du séquestre (frais en jetons)
+    #[account(
+        mut,
+        constraint = requester.key() == request.requester @ ErrorCode::NotOwner
+    )]
+    pub requester: Option<AccountInfo<'info>>,
+
+    /// Mint des frais séquestrés (requis si la demande a été payée en jetons)
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Séquestre : compte de jeton détenu par la demande
+    #[account(mut)]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Compte de jeton détenu par le coffre du certificateur
+    #[account(mut)]
+    pub certifier_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Compte de jeton détenu par la trésorerie
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Mint NFT du certificat (absent si aucun jeton n'est émis à l'approbation)
+    #[account(
+        init,
+        payer = operator,
+        seeds = [b"certificate_mint", certificate.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = authority,
+        mint::freeze_authority = authority,
+        mint::token_program = nft_token_program,
+        extensions::metadata_pointer::authority = authority,
+        extensions::metadata_pointer::metadata_address = nft_mint,
+        extensions::permanent_delegate::delegate = authority
+    )]
+    pub nft_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// CHECK: Compte de jeton associé du demandeur (créé via le programme de comptes associés)
+    #[account(mut)]
+    pub owner_nft_account: Option<AccountInfo<'info>>,
+
+    pub nft_token_program: Option<Program<'info, Token2022>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === RequestQuote ===
+#[derive(Accounts)]
+#[instruction(serial_number: String, _brand: String, _model: String, _cert_type: CertificationType, _estimated_value: u64, _metadata_uri: String, _metadata_hash: [u8; 32], _transfer_policy: TransferPolicy, target_certifier: Pubkey)]
+pub struct RequestQuote<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        init,
+        payer = requester,
+        space = CertificationRequest::SPACE,
+        seeds = [b"request", serial_number.as_bytes()],
+        bump
+    )]
+    pub request: Account<'info, CertificationRequest>,
+
+    /// Profil du certificateur sollicité - vérifie qu'il peut accepter des demandes
+    #[account(
+        mut,
+        seeds = [b"certifier_profile", target_certifier.as_ref()],
+        bump = certifier_profile.bump,
+        constraint = certifier_profile.is_active @ ErrorCode::CertifierNotActive,
+        constraint = certifier_profile.current_load < MAX_CONCURRENT_REQUESTS @ ErrorCode::CertifierAtCapacity
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === PostQuote ===
+#[derive(Accounts)]
+pub struct PostQuote<'info> {
+    /// Certificateur ou délégué qui signe
+    pub operator: Signer<'info>,
+
+    /// CHECK: Certificateur assigné à la demande
+    #[account(
+        constraint = certifier.key() == certifier_profile.certifier @ ErrorCode::NotAssignedCertifier
+    )]
+    pub certifier: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"request", request.serial_number.as_bytes()],
+        bump = request.bump
+    )]
+    pub request: Account<'info, CertificationRequest>,
+
+    #[account(
+        seeds = [b"certifier_profile", certifier.key().as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    /// Délégation de l'opérateur (absente si le certificateur signe lui-même)
+    #[account(
+        seeds = [b"certifier_delegate", certifier.key().as_ref(), operator.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, CertifierDelegate>>,
+}
+
+// === AcceptQuote ===
+#[derive(Accounts)]
+pub struct AcceptQuote<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump,
+        constraint = !authority.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"request", request.serial_number.as_bytes()],
+        bump = request.bump,
+        constraint = request.requester == requester.key() @ ErrorCode::NotOwner
+    )]
+    pub request: Account<'info, CertificationRequest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === CancelRequest ===
+#[derive(Accounts)]
+pub struct CancelRequest<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    /// Demande fermée (loyer rendu au demandeur, le numéro de série est libéré)
+    #[account(
+        mut,
+        close = requester,
+        seeds = [b"request", request.serial_number.as_bytes()],
+        bump = request.bump,
+        constraint = request.requester == requester.key() @ ErrorCode::NotOwner
+    )]
+    pub request: Account<'info, CertificationRequest>,
+
+    /// Profil du certificateur assigné (libération du créneau)
+    #[account(
+        mut,
+        seeds = [b"certifier_profile", certifier_profile.certifier.as_ref()],
+        bump = certifier_profile.bump,
+        constraint = request.assigned_certifier == Some(certifier_profile.certifier) @ ErrorCode::NotAssignedCertifier
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+}
+
+// === ExpireQuote ===
+#[derive(Accounts)]
+pub struct ExpireQuote<'info> {
+    /// N'importe quel signataire une fois le devis expiré
+    pub caller: Signer<'info>,
+
+    /// CHECK: Demandeur, récupère le loyer de la demande fermée
+    #[account(
+        mut,
+        constraint = requester.key() == request.requester @ ErrorCode::NotOwner
+    )]
+    pub requester: AccountInfo<'info>,
+
+    /// Demande fermée (le numéro de série est libéré)
+    #[account(
+        mut,
+        close = requester,
+        seeds = [b"request", request.serial_number.as_bytes()],
+        bump = request.bump
+    )]
+    pub request: Account<'info, CertificationRequest>,
+
+    /// Profil du certificateur assigné (libération du créneau)
+    #[account(
+        mut,
+        seeds = [b"certifier_profile", certifier_profile.certifier.as_ref()],
+        bump = certifier_profile.bump,
+        constraint = request.assigned_certifier == Some(certifier_profile.certifier) @ ErrorCode::NotAssignedCertifier
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+}
+
+// === RejectCertification ===
+#[derive(Accounts)]
+pub struct RejectCertification<'info> {
+    /// Certificateur ou délégué qui signe
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Certificateur assigné à la demande
+    #[account(
+        constraint = certifier.key() == certifier_profile.certifier @ ErrorCode::NotAssignedCertifier
+    )]
+    pub certifier: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"request", request.serial_number.as_bytes()],
+        bump = request.bump
+    )]
+    pub request: Account<'info, CertificationRequest>,
+
+    /// Profil du certificateur pour mettre à jour ses stats
+    #[account(
+        mut,
+        seeds = [b"certifier_profile", certifier.key().as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    /// Délégation de l'opérateur (absente si le certificateur signe lui-même)
+    #[account(
+        seeds = [b"certifier_delegate", certifier.key().as_ref(), operator.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, CertifierDelegate>>,
+
+    /// CHECK: Requester pour recevoir le remboursement
+    #[account(
+        mut,
+        constraint = requester.key() == request.requester @ ErrorCode::NotOwner
+    )]
+    pub requester: AccountInfo<'info>,
+
+    /// Coffre des gains du certificateur qui reçoit sa part des frais d'inspection
+    #[account(
+        mut,
+        seeds = [b"certifier_vault", certifier.key().as_ref()],
+        bump = certifier_vault.bump
+    )]
+    pub certifier_vault: Account<'info, CertifierVault>,
+
+    /// Coffre de la plateforme qui reçoit sa part des frais d'inspection
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = treasury.key() == authority.treasury @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: Account<'info, TreasuryVault>,
+
+    /// Mint des frais séquestrés (requis si la demande a été payée en jetons)
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Séquestre : compte de jeton détenu par la demande
+    #[account(mut)]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Compte de jeton du demandeur (remboursement)
+    #[account(mut)]
+    pub requester_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Compte de jeton du coffre du certificateur (frais d'inspection en jetons)
+    #[account(mut)]
+    pub certifier_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Compte de jeton de la trésorerie (frais d'inspection en jetons)
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+// === AddDelegate ===
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct AddDelegate<'info> {
+    #[account(mut)]
+    pub certifier: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"certifier_profile", certifier.key().as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    #[account(
+        init,
+        payer = certifier,
+        space = CertifierDelegate::SPACE,
+        seeds = [b"certifier_delegate", certifier.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub delegate_record: Account<'info, CertifierDelegate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === RemoveDelegate ===
+#[derive(Accounts)]
+pub struct RemoveDelegate<'info> {
+    #[account(mut)]
+    pub certifier: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"certifier_profile", certifier.key().as_ref()],
+        bump = certifier_profile.bump
+    )]
+    pub certifier_profile: Account<'info, CertifierProfile>,
+
+    #[account(
+        mut,
+        close = certifier,
+        seeds = [b"certifier_delegate", certifier.key().as_ref(), delegate_record.delegate.as_ref()],
+        bump = delegate_record.bump
+    )]
+    pub delegate_record: Account<'info, CertifierDelegate>,
+}
+
+// === WithdrawEarnings ===
+#[derive(Accounts)]
+pub struct WithdrawEarnings<'info> {
+    pub certifier: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"certifier_vault", certifier.key().as_ref()],
+        bump = certifier_vault.bump
+    )]
+    pub certifier_vault: Account<'info, CertifierVault>,
+
+    /// CHECK: Adresse de paiement verifiee par la contrainte sur le coffre
+    #[account(
+        mut,
+        constraint = payout.key() == certifier_vault.payout_address @ ErrorCode::InvalidPayoutAddress
+    )]
+    pub payout: AccountInfo<'info>,
+}
+
+// === WithdrawTokenEarnings ===
+#[derive(Accounts)]
+pub struct WithdrawTokenEarnings<'info> {
+    pub certifier: Signer<'info>,
+
+    #[account(
+        seeds = [b"certifier_vault", certifier.key().as_ref()],
+        bump = certifier_vault.bump
+    )]
+    pub certifier_vault: Account<'info, CertifierVault>,
+
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    /// Compte de jeton détenu par le coffre du certificateur
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = certifier_vault,
+        token::token_program = token_program
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Compte de jeton de l'adresse de paiement du certificateur
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = certifier_vault.payout_address,
+        token::token_program = token_program
+    )]
+    pub payout_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// === SetPayoutAddress ===
+#[derive(Accounts)]
+pub struct SetPayoutAddress<'info> {
+    pub certifier: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"certifier_vault", certifier.key().as_ref()],
+        bump = certifier_vault.bump
+    )]
+    pub certifier_vault: Account<'info, CertifierVault>,
+}
+
+// === SetPayoutsHeld ===
+#[derive(Accounts)]
+#[instruction(certifier: Pubkey)]
+pub struct SetPayoutsHeld<'info> {
+    /// Admin (ou signataire exécutant une proposition approuvée du conseil)
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"certifier_vault", certifier.as_ref()],
+        bump = certifier_vault.bump
+    )]
+    pub certifier_vault: Account<'info, CertifierVault>,
+
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    /// Rôle opérateur du signataire (absent si l'admin ou le conseil agit)
+    #[account(
+        seeds = [b"operator_role", admin.key().as_ref()],
+        bump = operator_role.bump,
+        constraint = operator_role.roles.dispute_resolver @ ErrorCode::MissingRole
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+}
+
+// === WithdrawTreasury ===
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    /// Admin (ou signataire exécutant une proposition approuvée du conseil)
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = treasury.key() == authority.treasury @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: Account<'info, TreasuryVault>,
+
+    /// CHECK: Adresse de paiement verifiee par la contrainte sur le coffre
+    #[account(
+        mut,
+        constraint = destination.key() == treasury.payout_address @ ErrorCode::InvalidPayoutAddress
+    )]
+    pub destination: AccountInfo<'info>,
+
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+}
+
+// === WithdrawTreasuryTokens ===
+#[derive(Accounts)]
+pub struct WithdrawTreasuryTokens<'info> {
+    /// Admin (ou signataire exécutant une proposition approuvée du conseil)
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = treasury.key() == authority.treasury @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: Account<'info, TreasuryVault>,
+
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    /// Compte de jeton détenu par la trésorerie
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = treasury,
+        token::token_program = token_program
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Compte de jeton de destination, détenu par l'adresse de paiement de la trésorerie
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::token_program = token_program,
+        constraint = destination.owner == treasury.payout_address @ ErrorCode::InvalidPayoutAddress
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// === SetTreasury ===
+#[derive(Accounts)]
+pub struct SetTreasury<'info> {
+    /// Admin (ou signataire exécutant une proposition approuvée du conseil)
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = treasury.key() == authority.treasury @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: Account<'info, TreasuryVault>,
+
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+}
+
+// === SetPriceOracle ===
+#[derive(Accounts)]
+pub struct SetPriceOracle<'info> {
+    /// Admin (ou payeur exécutant une proposition approuvée du conseil)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
         bump = authority.bump
     )]
     pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PriceFeed::SPACE,
+        seeds = [b"price_feed"],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, AdminProposal>>,
 }
 
-// ==================== NOUVELLES STRUCTS ACCOUNTS ====================
+// === UpdatePrice ===
+#[derive(Accounts)]
+pub struct UpdatePrice<'info> {
+    pub oracle: Signer<'info>,
 
-// === RequestCertification ===
+    #[account(
+        mut,
+        seeds = [b"price_feed"],
+        bump = price_feed.bump,
+        constraint = price_feed.oracle == oracle.key() @ ErrorCode::UnauthorizedOracle
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+}
+
+// === UpdateConfig ===
 #[derive(Accounts)]
-#[instruction(serial_number: String, _brand: String, _model: String, _cert_type: CertificationType, _estimated_value: u64, _metadata_uri: String, target_certifier: Pubkey)]
-pub struct RequestCertification<'info> {
+pub struct UpdateConfig<'info> {
+    /// N'importe quel signataire une fois le préavis écoulé
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    /// Modification mise en file d'attente à appliquer
+    #[account(
+        mut,
+        seeds = [b"queued_change".as_ref(), &queued_change.id.to_le_bytes()],
+        bump = queued_change.bump
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
+}
+
+// === QueueChange ===
+#[derive(Accounts)]
+#[instruction(change: TimelockedChange)]
+pub struct QueueChange<'info> {
+    /// Admin (ou payeur exécutant une proposition approuvée du conseil)
     #[account(mut)]
-    pub requester: Signer<'info>,
+    pub admin: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"auth_v5"],
         bump = authority.bump
     )]
     pub authority: Account<'info, CertificationAuthority>,
 
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    /// Rôle opérateur du signataire (absent si l'admin ou le conseil agit)
+    #[account(
+        seeds = [b"operator_role", admin.key().as_ref()],
+        bump = operator_role.bump,
+        constraint = operator_role.roles.allows_change(&change) @ ErrorCode::MissingRole
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+
     #[account(
         init,
-        payer = requester,
-        space = CertificationRequest::SPACE,
-        seeds = [b"request", serial_number.as_bytes()],
+        payer = admin,
+        space = QueuedChange::SPACE,
+        seeds = [b"queued_change".as_ref(), &authority.queued_change_count.to_le_bytes()],
         bump
     )]
-    pub request: Account<'info, CertificationRequest>,
+    pub queued_change: Account<'info, QueuedChange>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === CancelChange ===
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CancelChange<'info> {
+    /// Admin (ou signataire exécutant une proposition approuvée du conseil)
+    pub admin: Signer<'info>,
 
-    /// Profil du certificateur cible - vérifie qu'il peut accepter des demandes
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
     #[account(
         mut,
-        seeds = [b"certifier_profile", target_certifier.as_ref()],
-        bump = certifier_profile.bump,
-        constraint = certifier_profile.is_active @ ErrorCode::CertifierNotActive,
-        constraint = certifier_profile.current_load < MAX_CONCURRENT_REQUESTS @ ErrorCode::CertifierAtCapacity
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
     )]
-    pub certifier_profile: Account<'info, CertifierProfile>,
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    #[account(
+        mut,
+        seeds = [b"queued_change".as_ref(), &id.to_le_bytes()],
+        bump = queued_change.bump
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
+}
+
+// === SetOperatorRoles ===
+#[derive(Accounts)]
+#[instruction(operator: Pubkey)]
+pub struct SetOperatorRoles<'info> {
+    /// Admin (ou payeur exécutant une proposition approuvée du conseil)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
 
-    /// CHECK: Treasury pour recevoir les frais
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
     #[account(
         mut,
-        constraint = treasury.key() == authority.treasury @ ErrorCode::UnauthorizedCertifier
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = OperatorRole::SPACE,
+        seeds = [b"operator_role", operator.as_ref()],
+        bump
     )]
-    pub treasury: AccountInfo<'info>,
+    pub operator_role: Account<'info, OperatorRole>,
 
     pub system_program: Program<'info, System>,
 }
 
-// === ApproveCertification ===
+// === SetPaused ===
 #[derive(Accounts)]
-pub struct ApproveCertification<'info> {
-    #[account(mut)]
-    pub certifier: Signer<'info>,
+pub struct SetPaused<'info> {
+    /// Admin, opérateur "pauser" ou signataire exécutant une proposition du conseil
+    pub admin: Signer<'info>,
 
     #[account(
         mut,
@@ -357,54 +2572,188 @@ pub struct ApproveCertification<'info> {
     )]
     pub authority: Account<'info, CertificationAuthority>,
 
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
     #[account(
         mut,
-        seeds = [b"request", request.serial_number.as_bytes()],
-        bump = request.bump
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
     )]
-    pub request: Account<'info, CertificationRequest>,
+    pub proposal: Option<Account<'info, AdminProposal>>,
 
+    /// Rôle opérateur du signataire (absent si l'admin ou le conseil agit)
     #[account(
-        init,
-        payer = certifier,
-        space = Certificate::SPACE,
-        seeds = [b"certificate", request.serial_number.as_bytes()],
-        bump
+        seeds = [b"operator_role", admin.key().as_ref()],
+        bump = operator_role.bump,
+        constraint = operator_role.roles.pauser @ ErrorCode::MissingRole
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+}
+
+// === CorrectOwner ===
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct CorrectOwner<'info> {
+    /// Admin, opérateur "dispute resolver" ou signataire exécutant une proposition du conseil
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
     )]
     pub certificate: Account<'info, Certificate>,
 
+    #[account(
+        mut,
+        seeds = [b"user_activity", certificate.owner.as_ref()],
+        bump = from_activity.bump
+    )]
+    pub from_activity: Account<'info, UserActivity>,
+
     #[account(
         init_if_needed,
-        payer = certifier,
+        payer = admin,
         space = UserActivity::SPACE,
-        seeds = [b"user_activity", request.requester.as_ref()],
+        seeds = [b"user_activity", new_owner.as_ref()],
+        bump
+    )]
+    pub to_activity: Account<'info, UserActivity>,
+
+    /// Page de provenance courante (créée au premier transfert de chaque page)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ProvenancePage::SPACE,
+        seeds = [
+            b"provenance".as_ref(),
+            certificate.key().as_ref(),
+            &certificate.current_provenance_page().to_le_bytes()
+        ],
         bump
     )]
+    pub provenance_page: Account<'info, ProvenancePage>,
+
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    /// Rôle opérateur du signataire (absent si l'admin ou le conseil agit)
+    #[account(
+        seeds = [b"operator_role", admin.key().as_ref()],
+        bump = operator_role.bump,
+        constraint = operator_role.roles.dispute_resolver @ ErrorCode::MissingRole
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+
+    /// Mint NFT du certificat (requis si un jeton a été émis)
+    pub nft_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Compte de jeton NFT du propriétaire actuel
+    #[account(mut)]
+    pub from_nft_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Compte de jeton NFT du nouveau propriétaire (créé au préalable par le client)
+    #[account(mut)]
+    pub to_nft_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token2022>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === ConfirmRetirement ===
+#[derive(Accounts)]
+pub struct ConfirmRetirement<'info> {
+    /// Admin (ou signataire exécutant une proposition approuvée du conseil)
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate", certificate.serial_number.as_bytes()],
+        bump = certificate.bump
+    )]
+    pub certificate: Account<'info, Certificate>,
+
+    /// Activité du propriétaire (emplacement libéré à la confirmation)
+    #[account(
+        mut,
+        seeds = [b"user_activity", certificate.owner.as_ref()],
+        bump = owner_activity.bump
+    )]
     pub owner_activity: Account<'info, UserActivity>,
 
-    /// Profil du certificateur pour mettre à jour ses stats
+    /// Proposition approuvée du conseil (requise si le conseil est actif)
     #[account(
         mut,
-        seeds = [b"certifier_profile", certifier.key().as_ref()],
-        bump = certifier_profile.bump
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
     )]
-    pub certifier_profile: Account<'info, CertifierProfile>,
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    /// Mint NFT du certificat (requis si un jeton a été émis)
+    #[account(mut)]
+    pub nft_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Compte de jeton NFT du propriétaire (jeton brûlé à la confirmation)
+    #[account(mut)]
+    pub owner_nft_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token2022>>,
+}
+
+// ==================== GOUVERNANCE (CONSEIL M-SUR-N) ====================
+
+// === CreateCouncil ===
+#[derive(Accounts)]
+pub struct CreateCouncil<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == authority.admin @ ErrorCode::UnauthorizedCertifier
+    )]
+    pub admin: Signer<'info>,
 
-    /// CHECK: Treasury qui detient les frais
     #[account(
         mut,
-        constraint = treasury.key() == authority.treasury @ ErrorCode::UnauthorizedCertifier
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminCouncil::SPACE,
+        seeds = [b"admin_council"],
+        bump
     )]
-    pub treasury: AccountInfo<'info>,
+    pub council: Account<'info, AdminCouncil>,
 
     pub system_program: Program<'info, System>,
 }
 
-// === RejectCertification ===
+// === ProposeAdminAction ===
 #[derive(Accounts)]
-pub struct RejectCertification<'info> {
+pub struct ProposeAdminAction<'info> {
+    /// Membre du conseil qui propose (et paie le compte de proposition)
     #[account(mut)]
-    pub certifier: Signer<'info>,
+    pub proposer: Signer<'info>,
 
     #[account(
         seeds = [b"auth_v5"],
@@ -414,30 +2763,73 @@ pub struct RejectCertification<'info> {
 
     #[account(
         mut,
-        seeds = [b"request", request.serial_number.as_bytes()],
-        bump = request.bump
+        seeds = [b"admin_council"],
+        bump = council.bump
     )]
-    pub request: Account<'info, CertificationRequest>,
+    pub council: Account<'info, AdminCouncil>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = AdminProposal::SPACE,
+        seeds = [b"admin_proposal".as_ref(), &council.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// === ApproveAdminAction ===
+#[derive(Accounts)]
+pub struct ApproveAdminAction<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [b"auth_v5"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        seeds = [b"admin_council"],
+        bump = council.bump
+    )]
+    pub council: Account<'info, AdminCouncil>,
 
-    /// Profil du certificateur pour mettre à jour ses stats
     #[account(
         mut,
-        seeds = [b"certifier_profile", certifier.key().as_ref()],
-        bump = certifier_profile.bump
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
     )]
-    pub certifier_profile: Account<'info, CertifierProfile>,
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+// === UpdateCouncil ===
+#[derive(Accounts)]
+pub struct UpdateCouncil<'info> {
+    /// Signataire exécutant la proposition approuvée
+    pub admin: Signer<'info>,
 
-    /// CHECK: Requester pour recevoir le remboursement
     #[account(
         mut,
-        constraint = requester.key() == request.requester @ ErrorCode::NotOwner
+        seeds = [b"auth_v5"],
+        bump = authority.bump
     )]
-    pub requester: AccountInfo<'info>,
+    pub authority: Account<'info, CertificationAuthority>,
+
+    #[account(
+        mut,
+        seeds = [b"admin_council"],
+        bump = council.bump
+    )]
+    pub council: Account<'info, AdminCouncil>,
 
-    /// CHECK: Treasury qui detient les frais
+    /// Proposition approuvée du conseil
     #[account(
         mut,
-        constraint = treasury.key() == authority.treasury @ ErrorCode::UnauthorizedCertifier
+        seeds = [b"admin_proposal".as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
     )]
-    pub treasury: AccountInfo<'info>,
+    pub proposal: Option<Account<'info, AdminProposal>>,
 }
@@ -0,0 +1,48 @@
+// Processor: Accept Quote
+//
+// Le demandeur accepte le devis en séquestrant son montant dans la demande.
+// La demande passe alors en attente d'examen et suit le flux normal :
+// approve_certification (répartition) ou reject_certification (remboursement).
+
+use crate::errors::ErrorCode;
+use crate::state::RequestStatus;
+use crate::AcceptQuote;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Handler pour accepter un devis
+pub fn handler(ctx: Context<AcceptQuote>) -> Result<()> {
+    let clock = Clock::get()?;
+    let request = &ctx.accounts.request;
+
+    require!(
+        request.status == RequestStatus::Quoted,
+        ErrorCode::NoQuoteToAccept
+    );
+    require!(
+        clock.unix_timestamp <= request.quote_expires_at,
+        ErrorCode::QuoteExpired
+    );
+
+    // Séquestrer le montant du devis dans la demande
+    let amount = request.quote_amount;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.requester.to_account_info(),
+                to: ctx.accounts.request.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let request = &mut ctx.accounts.request;
+    request.fee_paid = amount;
+    request.status = RequestStatus::Pending;
+
+    msg!("Devis accepte pour: {}", request.serial_number);
+    msg!("Fee paid: {} lamports", amount);
+
+    Ok(())
+}
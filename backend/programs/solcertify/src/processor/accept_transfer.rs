@@ -0,0 +1,98 @@
+// Processor: Accept Transfer
+//
+// Seconde étape d'un transfert : le destinataire accepte l'offre et devient
+// propriétaire. Les contrôles de verrouillage, cooldown et limite de possession
+// sont appliqués au moment de l'acceptation.
+
+use crate::errors::ErrorCode;
+use crate::processor::certificate_nft::move_certificate_nft;
+use crate::state::constants::MAX_CERTIFICATES;
+use crate::AcceptTransfer;
+use anchor_lang::prelude::*;
+
+/// Handler pour accepter une offre de transfert
+pub fn handler(ctx: Context<AcceptTransfer>) -> Result<()> {
+    let certificate = &mut ctx.accounts.certificate;
+    let from_activity = &mut ctx.accounts.from_activity;
+    let to_activity = &mut ctx.accounts.to_activity;
+    let config = &ctx.accounts.authority.config;
+    let clock = Clock::get()?;
+
+    // Un certificat retiré (ou en cours de retrait) ne circule plus
+    certificate.require_active()?;
+
+    // Le propriétaire ne doit pas avoir changé depuis l'offre
+    require!(
+        certificate.is_owner(&ctx.accounts.from.key()),
+        ErrorCode::NotOwner
+    );
+
+    // Selon la politique du certificat, l'offre doit avoir été approuvée
+    certificate.require_transferable()?;
+    require!(
+        !certificate.requires_transfer_approval() || ctx.accounts.pending_transfer.certifier_approved,
+        ErrorCode::TransferApprovalRequired
+    );
+
+    // Une offre faite par un délégué n'est valable que si la délégation l'est encore
+    certificate.authorize_transfer(&ctx.accounts.pending_transfer.initiator, clock.unix_timestamp)?;
+
+    // Vérifier que le certificat n'est pas verrouillé
+    require!(
+        !certificate.is_locked(clock.unix_timestamp),
+        ErrorCode::CertificateLocked
+    );
+
+    // Vérifier le cooldown du vendeur
+    from_activity.require_cooldown_elapsed(clock.unix_timestamp, config.cooldown_period)?;
+
+    // Initialiser UserActivity du nouveau propriétaire si nécessaire
+    to_activity.init_if_new(ctx.accounts.recipient.key(), ctx.bumps.to_activity);
+
+    // Vérifier le cooldown et la limite de possession du nouveau propriétaire
+    to_activity.require_cooldown_elapsed(clock.unix_timestamp, config.cooldown_period)?;
+    require!(
+        to_activity.can_receive_certificate(MAX_CERTIFICATES),
+        ErrorCode::MaxCertificatesReached
+    );
+
+    // Le jeton NFT du certificat (s'il existe) suit le changement de propriétaire
+    move_certificate_nft(
+        certificate,
+        &ctx.accounts.authority,
+        ctx.accounts.nft_mint.as_ref(),
+        ctx.accounts.from_nft_account.as_ref(),
+        ctx.accounts.to_nft_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.recipient.key(),
+    )?;
+
+    // Effectuer le transfert (inscrit dans la page de provenance courante)
+    let provenance_page = &mut ctx.accounts.provenance_page;
+    provenance_page.init_if_new(
+        certificate.key(),
+        certificate.current_provenance_page(),
+        ctx.bumps.provenance_page,
+    );
+    let old_owner = certificate.transfer_ownership(
+        ctx.accounts.recipient.key(),
+        ctx.accounts.pending_transfer.kind,
+        provenance_page,
+        clock.unix_timestamp,
+        config.lock_period,
+    )?;
+
+    // Mettre à jour les compteurs
+    from_activity.decrement_certificate_count()?;
+    from_activity.update_last_action(clock.unix_timestamp);
+
+    to_activity.increment_certificate_count()?;
+    to_activity.update_last_action(clock.unix_timestamp);
+
+    msg!("Certificat transfere: {}", certificate.serial_number);
+    msg!("De: {}", old_owner);
+    msg!("Vers: {}", ctx.accounts.recipient.key());
+    msg!("Verrouille jusqu'a: {}", certificate.locked_until);
+
+    Ok(())
+}
@@ -1,9 +1,14 @@
 // Processor: Add Certifier
 //
-// Ajoute un certificateur agréé à la liste et crée son profil avec statistiques.
+// Agrée un certificateur en créant son profil avec statistiques.
+// L'agrément est soumis au préavis : il applique une modification mise en file
+// d'attente via queue_change, par n'importe quel signataire une fois le délai écoulé.
+// Le profil (existence + is_active) fait foi pour l'agrément : un certificateur
+// retiré puis ré-agréé conserve ses statistiques.
 
 use crate::errors::ErrorCode;
-use crate::state::{CertificationAuthority, CertifierProfile};
+use crate::events::ChangeExecuted;
+use crate::state::{TimelockedChange, ACCREDITATION_PERIOD};
 use crate::AddCertifier;
 use anchor_lang::prelude::*;
 
@@ -15,52 +20,74 @@ pub fn handler(
     physical_address: String,
 ) -> Result<()> {
     let clock = Clock::get()?;
+
+    // Consommer la modification mise en file d'attente (préavis écoulé)
+    let queued_change = &mut ctx.accounts.queued_change;
+    queued_change.execute(
+        &TimelockedChange::AddCertifier {
+            certifier,
+            display_name: display_name.clone(),
+            physical_address: physical_address.clone(),
+        },
+        clock.unix_timestamp,
+    )?;
+
+    emit!(ChangeExecuted {
+        id: queued_change.id,
+        executor: ctx.accounts.executor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
     let authority = &mut ctx.accounts.authority;
 
-    // Valider les longueurs des champs
-    require!(
-        display_name.len() <= CertifierProfile::MAX_NAME_LENGTH,
-        ErrorCode::DisplayNameTooLong
-    );
-    require!(
-        physical_address.len() <= CertifierProfile::MAX_ADDRESS_LENGTH,
-        ErrorCode::PhysicalAddressTooLong
-    );
+    let profile = &mut ctx.accounts.certifier_profile;
+    let is_new = profile.certifier == Pubkey::default();
 
-    // Vérifier que le certificateur n'est pas déjà dans la liste
-    require!(
-        !authority.approved_certifiers.contains(&certifier),
-        ErrorCode::CertifierAlreadyExists
-    );
+    // Vérifier que le certificateur n'est pas déjà agréé
+    require!(is_new || !profile.is_active, ErrorCode::CertifierAlreadyExists);
 
-    // Vérifier la limite maximale de certificateurs
-    require!(
-        authority.approved_certifiers.len() < CertificationAuthority::MAX_CERTIFIERS,
-        ErrorCode::MaxCertifiersReached
-    );
+    if is_new {
+        // Initialiser le profil du certificateur
+        profile.certifier = certifier;
+        profile.current_load = 0;
+        profile.total_processed = 0;
+        profile.total_processing_time = 0;
+        profile.total_approved = 0;
+        profile.total_rejected = 0;
+        profile.total_cancelled = 0;
+        profile.total_expired = 0;
+        profile.total_direct_issued = 0;
+        profile.total_appraisals = 0;
+        profile.total_lamports_earned = 0;
+        profile.delegate_count = 0;
+        profile.registered_at = clock.unix_timestamp;
+        profile.bump = ctx.bumps.certifier_profile;
+    }
 
-    // Ajouter le certificateur à la liste d'autorité
-    authority.approved_certifiers.push(certifier);
+    // Initialiser le coffre des gains (paiement vers le certificateur par défaut)
+    let vault = &mut ctx.accounts.certifier_vault;
+    if vault.certifier == Pubkey::default() {
+        vault.certifier = certifier;
+        vault.payout_address = certifier;
+        vault.lifetime_earnings = 0;
+        vault.total_withdrawn = 0;
+        vault.payouts_held = false;
+        vault.bump = ctx.bumps.certifier_vault;
+    }
 
-    // Initialiser le profil du certificateur
-    let profile = &mut ctx.accounts.certifier_profile;
-    profile.certifier = certifier;
+    // (Ré)activer le profil avec une nouvelle période d'accréditation
     profile.display_name = display_name.clone();
     profile.physical_address = physical_address.clone();
-    profile.current_load = 0;
-    profile.total_processed = 0;
-    profile.total_processing_time = 0;
     profile.is_active = true;
-    profile.registered_at = clock.unix_timestamp;
-    profile.bump = ctx.bumps.certifier_profile;
+    profile.accredited_until = clock.unix_timestamp + ACCREDITATION_PERIOD;
+
+    authority.add_certifier()?;
 
     msg!("Certificateur ajouté: {}", certifier);
     msg!("Nom: {}", display_name);
     msg!("Adresse: {}", physical_address);
-    msg!(
-        "Total certificateurs: {}",
-        authority.approved_certifiers.len()
-    );
+    msg!("Accredite jusqu'au: {}", profile.accredited_until);
+    msg!("Total certificateurs: {}", authority.certifier_count);
 
     Ok(())
 }
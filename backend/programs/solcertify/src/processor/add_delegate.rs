@@ -0,0 +1,55 @@
+// Processor: Add Delegate
+//
+// Enregistre une clé déléguée (employé) pour un certificateur agréé.
+
+use crate::errors::ErrorCode;
+use crate::state::{DelegatePermissions, MAX_DELEGATES};
+use crate::AddDelegate;
+use anchor_lang::prelude::*;
+
+/// Handler pour ajouter une clé déléguée
+pub fn handler(
+    ctx: Context<AddDelegate>,
+    delegate: Pubkey,
+    permissions: DelegatePermissions,
+    expires_at: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let certifier = ctx.accounts.certifier.key();
+
+    // Vérifier que le certificateur est toujours agréé
+    require!(
+        ctx.accounts.certifier_profile.is_approved(),
+        ErrorCode::UnauthorizedCertifier
+    );
+
+    // Le délégué doit être une clé distincte avec une expiration future
+    require!(delegate != certifier, ErrorCode::DelegateNotAuthorized);
+    require!(
+        expires_at > clock.unix_timestamp,
+        ErrorCode::InvalidDelegateExpiry
+    );
+
+    // Respecter la limite de délégués par certificateur
+    ctx.accounts.certifier_profile.add_delegate(MAX_DELEGATES)?;
+
+    let record = &mut ctx.accounts.delegate_record;
+    record.certifier = certifier;
+    record.delegate = delegate;
+    record.permissions = permissions;
+    record.expires_at = expires_at;
+    record.created_at = clock.unix_timestamp;
+    record.bump = ctx.bumps.delegate_record;
+
+    msg!("Delegue ajoute: {} pour {}", delegate, certifier);
+    msg!(
+        "Permissions - approuver: {}, rejeter: {}, emettre: {}, entretenir: {}",
+        permissions.approve,
+        permissions.reject,
+        permissions.issue,
+        permissions.service
+    );
+    msg!("Expire le: {}", expires_at);
+
+    Ok(())
+}
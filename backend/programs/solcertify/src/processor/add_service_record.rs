@@ -0,0 +1,83 @@
+// Processor: Add Service Record
+//
+// Un certificateur agréé (ou son délégué habilité) inscrit un entretien dans le
+// carnet du certificat : date, travaux effectués, pièces remplacées et
+// justificatifs IPFS. Chaque entretien est un compte distinct, lisible par tous.
+
+use crate::errors::ErrorCode;
+use crate::state::{CertifierDelegate, DelegateAction, ServiceRecord};
+use crate::AddServiceRecord;
+use anchor_lang::prelude::*;
+
+/// Handler pour enregistrer un entretien
+pub fn handler(
+    ctx: Context<AddServiceRecord>,
+    serviced_at: i64,
+    work_performed: String,
+    parts_replaced: String,
+    evidence_uri: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let certifier_key = ctx.accounts.certifier.key();
+
+    // Vérifier que le certificateur est agréé et accrédité
+    require!(
+        ctx.accounts.certifier_profile.is_approved(),
+        ErrorCode::UnauthorizedCertifier
+    );
+    require!(
+        ctx.accounts.certifier_profile.is_accredited(clock.unix_timestamp),
+        ErrorCode::AccreditationExpired
+    );
+
+    // Le signataire doit être le certificateur ou un délégué habilité
+    let acting_delegate = CertifierDelegate::authorize(
+        &ctx.accounts.operator.key(),
+        &certifier_key,
+        ctx.accounts.delegate.as_deref(),
+        DelegateAction::Service,
+        clock.unix_timestamp,
+    )?;
+
+    // Vérifier les paramètres
+    require!(
+        serviced_at > 0 && serviced_at <= clock.unix_timestamp,
+        ErrorCode::InvalidServiceDate
+    );
+    require!(
+        work_performed.len() <= ServiceRecord::MAX_WORK_LEN,
+        ErrorCode::StringTooLong
+    );
+    require!(
+        parts_replaced.len() <= ServiceRecord::MAX_PARTS_LEN,
+        ErrorCode::StringTooLong
+    );
+    require!(
+        evidence_uri.len() <= ServiceRecord::MAX_EVIDENCE_URI_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    let certificate = &mut ctx.accounts.certificate;
+    certificate.require_active()?;
+
+    let record = &mut ctx.accounts.service_record;
+    record.certificate = certificate.key();
+    record.index = certificate.service_count;
+    record.certifier = certifier_key;
+    record.acting_delegate = acting_delegate;
+    record.serviced_at = serviced_at;
+    record.work_performed = work_performed;
+    record.parts_replaced = parts_replaced;
+    record.evidence_uri = evidence_uri;
+    record.recorded_at = clock.unix_timestamp;
+    record.bump = ctx.bumps.service_record;
+
+    certificate.record_service(serviced_at)?;
+
+    msg!("Entretien enregistre: {}", certificate.serial_number);
+    msg!("Index: {}", record.index);
+    msg!("Par: {}", certifier_key);
+    msg!("Total entretiens: {}", certificate.service_count);
+
+    Ok(())
+}
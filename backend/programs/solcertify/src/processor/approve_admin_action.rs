@@ -0,0 +1,29 @@
+// Processor: Approve Admin Action
+//
+// Un membre du conseil approuve une proposition en attente.
+
+use crate::errors::ErrorCode;
+use crate::ApproveAdminAction;
+use anchor_lang::prelude::*;
+
+/// Handler pour approuver une proposition du conseil
+pub fn handler(ctx: Context<ApproveAdminAction>) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    let council = &ctx.accounts.council;
+
+    require!(council.is_member(&member), ErrorCode::NotCouncilMember);
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.require_current(ctx.accounts.authority.council_epoch)?;
+    proposal.approve(member, council.threshold)?;
+
+    msg!("Proposition #{} approuvee par {}", proposal.id, member);
+    msg!(
+        "Approbations: {}/{} - seuil atteint: {}",
+        proposal.approvals.len(),
+        council.threshold,
+        proposal.approved
+    );
+
+    Ok(())
+}
@@ -1,14 +1,14 @@
 use anchor_lang::prelude::*;
-use crate::state::{RequestStatus, MAX_CERTIFICATES, LOCK_PERIOD};
+use crate::state::{
+    CertificateStatus, CertifierDelegate, DelegateAction, RequestStatus, MAX_CERTIFICATES,
+};
 use crate::errors::ErrorCode;
+use crate::processor::{certificate_nft, token_fees};
 use crate::ApproveCertification;
 
-/// Pourcentage des frais pour le certificateur (60%)
-const CERTIFIER_SHARE_PERCENT: u64 = 60;
-
 /// Handler pour approuver une demande de certification
-/// SEUL le certificateur assigné peut approuver cette demande
-/// Les frais sont distribués : 60% certificateur, 40% plateforme
+/// SEUL le certificateur assigné (ou un délégué habilité) peut approuver cette demande
+/// Les frais sont distribués selon la configuration (par défaut 60% coffre du certificateur, 40% plateforme)
 pub fn handler(ctx: Context<ApproveCertification>) -> Result<()> {
     let clock = Clock::get()?;
 
@@ -21,10 +21,13 @@ pub fn handler(ctx: Context<ApproveCertification>) -> Result<()> {
     let estimated_value = ctx.accounts.request.estimated_value;
     let requester = ctx.accounts.request.requester;
     let metadata_uri = ctx.accounts.request.metadata_uri.clone();
+    let metadata_hash = ctx.accounts.request.metadata_hash;
+    let transfer_policy = ctx.accounts.request.transfer_policy;
     let request_status = ctx.accounts.request.status.clone();
     let owner_cert_count = ctx.accounts.owner_activity.certificate_count;
     let assigned_certifier = ctx.accounts.request.assigned_certifier;
     let created_at = ctx.accounts.request.created_at;
+    let certifier_key = ctx.accounts.certifier.key();
 
     // Verifier que la demande est en attente
     require!(
@@ -34,16 +37,31 @@ pub fn handler(ctx: Context<ApproveCertification>) -> Result<()> {
 
     // NOUVELLE VERIFICATION: Seul le certificateur ASSIGNÉ peut approuver
     require!(
-        assigned_certifier == Some(ctx.accounts.certifier.key()),
+        assigned_certifier == Some(certifier_key),
         ErrorCode::NotAssignedCertifier
     );
 
+    // Le signataire doit être le certificateur ou un délégué habilité
+    let acting_delegate = CertifierDelegate::authorize(
+        &ctx.accounts.operator.key(),
+        &certifier_key,
+        ctx.accounts.delegate.as_deref(),
+        DelegateAction::Approve,
+        clock.unix_timestamp,
+    )?;
+
     // Verifier que le certificateur est toujours agrée (sécurité)
     require!(
-        ctx.accounts.authority.approved_certifiers.contains(&ctx.accounts.certifier.key()),
+        ctx.accounts.certifier_profile.is_approved(),
         ErrorCode::UnauthorizedCertifier
     );
 
+    // Verifier que l'accreditation n'a pas expire
+    require!(
+        ctx.accounts.certifier_profile.is_accredited(clock.unix_timestamp),
+        ErrorCode::AccreditationExpired
+    );
+
     // Verifier la limite de certificats du proprietaire
     require!(
         owner_cert_count < MAX_CERTIFICATES,
@@ -51,17 +69,79 @@ pub fn handler(ctx: Context<ApproveCertification>) -> Result<()> {
     );
 
     // Calculer la distribution des frais
-    let certifier_share = fee_paid * CERTIFIER_SHARE_PERCENT / 100;
+    let config = ctx.accounts.authority.config.clone();
+    let certifier_share = config.certifier_share(fee_paid)?;
     let treasury_share = fee_paid - certifier_share;
 
-    // Transferer depuis le compte request PDA (le programme en est proprietaire)
-    // Part du certificateur
-    **ctx.accounts.request.to_account_info().try_borrow_mut_lamports()? -= certifier_share;
-    **ctx.accounts.certifier.try_borrow_mut_lamports()? += certifier_share;
-    
-    // Part de la plateforme (treasury)
-    **ctx.accounts.request.to_account_info().try_borrow_mut_lamports()? -= treasury_share;
-    **ctx.accounts.treasury.try_borrow_mut_lamports()? += treasury_share;
+    if let Some(fee_mint) = ctx.accounts.request.fee_mint {
+        // Frais en jetons : répartis depuis le séquestre de la demande, puis séquestre fermé
+        let (
+            Some(requester_info),
+            Some(mint),
+            Some(escrow),
+            Some(certifier_tokens),
+            Some(treasury_tokens),
+            Some(token_program),
+        ) = (
+            ctx.accounts.requester.as_ref(),
+            ctx.accounts.payment_mint.as_ref(),
+            ctx.accounts.escrow_token_account.as_ref(),
+            ctx.accounts.certifier_token_account.as_ref(),
+            ctx.accounts.treasury_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::TokenAccountsMissing);
+        };
+        let request_key = ctx.accounts.request.key();
+        require!(mint.key() == fee_mint, ErrorCode::InvalidTokenAccount);
+        token_fees::require_token_account(escrow, &fee_mint, &request_key)?;
+        token_fees::require_token_account(
+            certifier_tokens,
+            &fee_mint,
+            &ctx.accounts.certifier_vault.key(),
+        )?;
+        token_fees::require_token_account(treasury_tokens, &fee_mint, &ctx.accounts.treasury.key())?;
+
+        let bump = [ctx.accounts.request.bump];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"request", serial_number.as_bytes(), &bump]];
+        let request_info = ctx.accounts.request.to_account_info();
+        token_fees::transfer_tokens(
+            token_program,
+            mint,
+            escrow,
+            certifier_tokens,
+            request_info.clone(),
+            signer_seeds,
+            certifier_share,
+        )?;
+        token_fees::transfer_tokens(
+            token_program,
+            mint,
+            escrow,
+            treasury_tokens,
+            request_info.clone(),
+            signer_seeds,
+            treasury_share,
+        )?;
+        token_fees::close_escrow(
+            token_program,
+            escrow,
+            requester_info.to_account_info(),
+            request_info,
+            signer_seeds,
+        )?;
+    } else {
+        // Transferer depuis le compte request PDA (le programme en est proprietaire)
+        // Part du certificateur : accumulee dans son coffre de gains
+        **ctx.accounts.request.to_account_info().try_borrow_mut_lamports()? -= certifier_share;
+        **ctx.accounts.certifier_vault.to_account_info().try_borrow_mut_lamports()? += certifier_share;
+        ctx.accounts.certifier_vault.credit(certifier_share)?;
+
+        // Part de la plateforme (treasury)
+        **ctx.accounts.request.to_account_info().try_borrow_mut_lamports()? -= treasury_share;
+        **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += treasury_share;
+        ctx.accounts.treasury.record_deposit(treasury_share)?;
+    }
 
     // Creer le certificat
     let certificate = &mut ctx.accounts.certificate;
@@ -71,37 +151,77 @@ pub fn handler(ctx: Context<ApproveCertification>) -> Result<()> {
     certificate.cert_type = cert_type.clone();
     certificate.estimated_value = estimated_value;
     certificate.owner = requester;
-    certificate.certifier = ctx.accounts.certifier.key();
+    certificate.certifier = certifier_key;
+    certificate.acting_delegate = acting_delegate;
+    certificate.transfer_delegate = None;
+    certificate.transfer_delegate_expires_at = 0;
     certificate.metadata_uri = metadata_uri;
+    certificate.metadata_hash = metadata_hash;
+    certificate.metadata_version = 0;
+    certificate.metadata_updated_at = clock.unix_timestamp;
     certificate.created_at = clock.unix_timestamp;
     certificate.last_transfer_at = clock.unix_timestamp;
-    certificate.locked_until = clock.unix_timestamp + LOCK_PERIOD;
-    certificate.previous_owners = Vec::new();
+    certificate.locked_until = clock.unix_timestamp + config.lock_period;
+    certificate.valid_until = config.validity_until(&cert_type, clock.unix_timestamp)?;
+    certificate.last_certified_at = clock.unix_timestamp;
+    certificate.last_certified_by = certifier_key;
+    certificate.transfer_count = 0;
+    certificate.last_sale_price = 0;
+    certificate.total_royalties_paid = 0;
+    certificate.status = CertificateStatus::Active;
+    certificate.retirement_reason = None;
+    certificate.retired_at = 0;
+    certificate.service_count = 0;
+    certificate.last_serviced_at = 0;
+    certificate.appraisal_count = 0;
+    certificate.nft_mint = None;
+    certificate.transfer_policy = transfer_policy;
     certificate.bump = ctx.bumps.certificate;
 
     // Mettre a jour le UserActivity du proprietaire
     let owner_activity = &mut ctx.accounts.owner_activity;
-    if owner_activity.user == Pubkey::default() {
-        owner_activity.user = requester;
-        owner_activity.bump = ctx.bumps.owner_activity;
-    }
+    owner_activity.init_if_new(requester, ctx.bumps.owner_activity);
     owner_activity.certificate_count += 1;
     owner_activity.last_action_at = clock.unix_timestamp;
 
     // Mettre a jour les stats de l'autorite
     let authority = &mut ctx.accounts.authority;
-    authority.total_issued += 1;
-    match cert_type {
-        crate::state::CertificationType::Standard => authority.standard_count += 1,
-        crate::state::CertificationType::Premium => authority.premium_count += 1,
-        crate::state::CertificationType::Luxury => authority.luxury_count += 1,
-        crate::state::CertificationType::Exceptional => authority.exceptional_count += 1,
-    }
+    authority.increment_counter(&cert_type)?;
 
     // Mettre à jour les stats du profil certificateur
     let certifier_profile = &mut ctx.accounts.certifier_profile;
     let processing_time = (clock.unix_timestamp - created_at) as u64;
-    certifier_profile.resolve_request(processing_time)?;
+    // Les gains cumulés du profil sont suivis en lamports (hors frais en jetons)
+    let lamports_earned = if ctx.accounts.request.fee_mint.is_some() { 0 } else { certifier_share };
+    certifier_profile.record_approval(processing_time, lamports_earned)?;
+
+    // Emettre le jeton NFT des la creation si le mint est fourni
+    if let Some(mint) = ctx.accounts.nft_mint.as_deref() {
+        let (
+            Some(requester_info),
+            Some(owner_nft_account),
+            Some(token_program),
+            Some(associated_token_program),
+        ) = (
+            ctx.accounts.requester.as_ref(),
+            ctx.accounts.owner_nft_account.as_ref(),
+            ctx.accounts.nft_token_program.as_ref(),
+            ctx.accounts.associated_token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::NftAccountsMissing);
+        };
+        certificate_nft::mint_certificate_nft(
+            &mut ctx.accounts.certificate,
+            &ctx.accounts.authority,
+            mint,
+            requester_info,
+            owner_nft_account,
+            &ctx.accounts.operator.to_account_info(),
+            token_program,
+            associated_token_program,
+            &ctx.accounts.system_program,
+        )?;
+    }
 
     // Marquer la demande comme approuvee
     let request = &mut ctx.accounts.request;
@@ -109,7 +229,7 @@ pub fn handler(ctx: Context<ApproveCertification>) -> Result<()> {
     request.resolved_at = clock.unix_timestamp;
 
     msg!("Certification approved for: {}", serial_number);
-    msg!("Certifier received: {} lamports", certifier_share);
+    msg!("Certifier vault credited: {}", certifier_share);
     msg!("Processing time: {} seconds", processing_time);
 
     Ok(())
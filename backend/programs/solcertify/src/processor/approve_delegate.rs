@@ -0,0 +1,38 @@
+// Processor: Approve Delegate
+//
+// Le propriétaire autorise un délégué unique (négociant, maison de ventes) à
+// proposer le transfert du certificat pour son compte. Une nouvelle approbation
+// remplace la précédente.
+
+use crate::errors::ErrorCode;
+use crate::ApproveDelegate;
+use anchor_lang::prelude::*;
+
+/// Handler pour approuver un délégué de transfert
+pub fn handler(ctx: Context<ApproveDelegate>, delegate: Pubkey, expires_at: Option<i64>) -> Result<()> {
+    let clock = Clock::get()?;
+    let certificate = &mut ctx.accounts.certificate;
+
+    // Un certificat retiré (ou en cours de retrait) ne circule plus
+    certificate.require_active()?;
+
+    require!(delegate != certificate.owner, ErrorCode::DelegateNotAuthorized);
+    if let Some(expires_at) = expires_at {
+        require!(
+            expires_at > clock.unix_timestamp,
+            ErrorCode::InvalidDelegateExpiry
+        );
+    }
+
+    certificate.transfer_delegate = Some(delegate);
+    certificate.transfer_delegate_expires_at = expires_at.unwrap_or(0);
+
+    msg!("Delegue de transfert approuve: {}", delegate);
+    msg!("Certificat: {}", certificate.serial_number);
+    match expires_at {
+        Some(expires_at) => msg!("Expire le: {}", expires_at),
+        None => msg!("Sans expiration"),
+    }
+
+    Ok(())
+}
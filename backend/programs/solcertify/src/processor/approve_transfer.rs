@@ -0,0 +1,45 @@
+// Processor: Approve Transfer
+//
+// Pour un certificat soumis à la politique CertifierApproval, le certificateur
+// émetteur (ou un délégué habilité à approuver) valide l'offre en attente
+// avant que le destinataire puisse l'accepter.
+
+use crate::errors::ErrorCode;
+use crate::state::{CertifierDelegate, DelegateAction};
+use crate::ApproveTransfer;
+use anchor_lang::prelude::*;
+
+/// Handler pour approuver une offre de transfert
+pub fn handler(ctx: Context<ApproveTransfer>) -> Result<()> {
+    let certificate = &ctx.accounts.certificate;
+    let clock = Clock::get()?;
+
+    certificate.require_active()?;
+    require!(
+        certificate.requires_transfer_approval(),
+        ErrorCode::TransferApprovalNotRequired
+    );
+
+    // Le certificateur émetteur doit toujours être agréé
+    require!(
+        ctx.accounts.certifier_profile.is_approved(),
+        ErrorCode::UnauthorizedCertifier
+    );
+
+    // Le signataire doit être le certificateur ou un délégué habilité
+    CertifierDelegate::authorize(
+        &ctx.accounts.operator.key(),
+        &ctx.accounts.certifier.key(),
+        ctx.accounts.delegate.as_deref(),
+        DelegateAction::Approve,
+        clock.unix_timestamp,
+    )?;
+
+    let pending_transfer = &mut ctx.accounts.pending_transfer;
+    pending_transfer.certifier_approved = true;
+
+    msg!("Transfert approuve: {}", certificate.serial_number);
+    msg!("De: {} - Vers: {}", pending_transfer.from, pending_transfer.to);
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+// Processor: Cancel Appraisal
+//
+// Le propriétaire annule sa demande de réévaluation. Le compte de demande est
+// fermé et le dépôt lui est restitué avec le loyer.
+
+use crate::CancelAppraisal;
+use anchor_lang::prelude::*;
+
+/// Handler pour annuler une demande de réévaluation
+pub fn handler(ctx: Context<CancelAppraisal>) -> Result<()> {
+    msg!("Reevaluation annulee: {}", ctx.accounts.certificate.serial_number);
+    msg!("Depot restitue: {} lamports", ctx.accounts.appraisal_request.max_fee_difference);
+
+    Ok(())
+}
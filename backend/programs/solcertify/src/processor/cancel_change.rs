@@ -0,0 +1,35 @@
+// Processor: Cancel Change
+//
+// Annule une modification du protocole pendant son délai de préavis.
+// Passé ce délai, la modification ne peut plus être annulée.
+
+use crate::events::ChangeCancelled;
+use crate::state::AdminAction;
+use crate::CancelChange;
+use anchor_lang::prelude::*;
+
+/// Handler pour annuler une modification en file d'attente
+pub fn handler(ctx: Context<CancelChange>, id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Autoriser l'opération (admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_admin(
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::CancelChange { id },
+        clock.unix_timestamp,
+    )?;
+
+    let queued_change = &mut ctx.accounts.queued_change;
+    queued_change.cancel(clock.unix_timestamp)?;
+
+    emit!(ChangeCancelled {
+        id,
+        cancelled_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Modification #{} annulee", id);
+
+    Ok(())
+}
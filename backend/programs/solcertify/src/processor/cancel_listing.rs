@@ -0,0 +1,18 @@
+// Processor: Cancel Listing
+//
+// Le vendeur retire son certificat de la vente (le compte de vente est fermé).
+// Le propriétaire actuel peut aussi fermer une vente caduque laissée par un
+// ancien propriétaire (transfert, correction, retrait) ; le loyer revient au vendeur.
+
+use crate::CancelListing;
+use anchor_lang::prelude::*;
+
+/// Handler pour retirer un certificat de la vente
+pub fn handler(ctx: Context<CancelListing>) -> Result<()> {
+    msg!("Vente annulee: {}", ctx.accounts.certificate.serial_number);
+    if ctx.accounts.listing.seller != ctx.accounts.certificate.owner {
+        msg!("Vente caduque de l'ancien proprietaire: {}", ctx.accounts.listing.seller);
+    }
+
+    Ok(())
+}
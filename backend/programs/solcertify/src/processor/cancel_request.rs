@@ -0,0 +1,23 @@
+// Processor: Cancel Request
+//
+// Le demandeur retire une demande sur devis avant tout paiement (devis non
+// encore publié ou refusé). Le créneau du certificateur est libéré et le compte
+// de demande est fermé : le numéro de série peut faire l'objet d'une nouvelle demande.
+
+use crate::errors::ErrorCode;
+use crate::CancelRequest;
+use anchor_lang::prelude::*;
+
+/// Handler pour annuler une demande non payée
+pub fn handler(ctx: Context<CancelRequest>) -> Result<()> {
+    require!(
+        ctx.accounts.request.is_unfunded(),
+        ErrorCode::RequestNotCancellable
+    );
+
+    ctx.accounts.certifier_profile.record_cancellation()?;
+
+    msg!("Demande annulee: {}", ctx.accounts.request.serial_number);
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+// Processor: Cancel Transfer
+//
+// Le propriétaire (ou le délégué ayant fait l'offre) annule une offre de
+// transfert avant son acceptation. Le propriétaire actuel peut aussi annuler une
+// offre caduque laissée par un ancien propriétaire (vente, correction, retrait).
+
+use crate::CancelTransfer;
+use anchor_lang::prelude::*;
+
+/// Handler pour annuler une offre de transfert
+pub fn handler(ctx: Context<CancelTransfer>) -> Result<()> {
+    msg!("Offre de transfert annulee: {}", ctx.accounts.certificate.serial_number);
+    msg!("Destinataire: {}", ctx.accounts.pending_transfer.to);
+    if ctx.accounts.pending_transfer.from != ctx.accounts.certificate.owner {
+        msg!("Offre caduque de l'ancien proprietaire: {}", ctx.accounts.pending_transfer.from);
+    }
+
+    Ok(())
+}
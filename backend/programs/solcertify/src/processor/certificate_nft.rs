@@ -0,0 +1,362 @@
+// Utilitaires: jeton NFT des certificats
+//
+// Le mint Token-2022 d'un certificat a l'autorité du programme comme autorité
+// de mint, de gel et délégué permanent. Le compte de jeton du propriétaire
+// reste gelé : le jeton ne circule qu'au travers des instructions du
+// programme, qui le déplacent en même temps que `certificate.owner`, et il
+// est brûlé au retrait du certificat. L'URI de ses métadonnées suit celle du
+// certificat.
+
+use crate::errors::ErrorCode;
+use crate::state::constants::CERTIFICATE_NFT_SYMBOL;
+use crate::state::{Certificate, CertificationAuthority};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, AssociatedToken, Create};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022::{
+    burn, freeze_account, mint_to, thaw_account, transfer_checked, Burn, FreezeAccount, MintTo,
+    ThawAccount, Token2022, TransferChecked,
+};
+use anchor_spl::token_2022_extensions::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::{
+    Field, TokenMetadata,
+};
+use anchor_spl::token_interface::{
+    token_metadata_initialize, token_metadata_update_field, Mint, TokenAccount,
+    TokenMetadataInitialize, TokenMetadataUpdateField,
+};
+
+/// Émet le jeton NFT d'un certificat dans son mint tout juste créé : les
+/// métadonnées (nom, symbole, URI) sont inscrites dans le mint, le compte de
+/// jeton associé du propriétaire est créé au besoin, puis l'unique jeton y est
+/// émis et gelé. Le payeur avance le loyer des métadonnées et du compte de jeton.
+pub fn mint_certificate_nft<'info>(
+    certificate: &mut Account<'info, Certificate>,
+    authority: &Account<'info, CertificationAuthority>,
+    mint: &InterfaceAccount<'info, Mint>,
+    owner: &AccountInfo<'info>,
+    owner_nft_account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    token_program: &Program<'info, Token2022>,
+    associated_token_program: &Program<'info, AssociatedToken>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    require!(owner.key() == certificate.owner, ErrorCode::InvalidNftAccount);
+
+    let authority_key = authority.key();
+    let mint_key = mint.key();
+    let name = format!("{} {}", certificate.brand, certificate.model);
+    let symbol = CERTIFICATE_NFT_SYMBOL.to_string();
+    let uri = certificate.metadata_uri.clone();
+
+    // Le programme de jetons agrandit le mint pour les métadonnées :
+    // le payeur avance le loyer correspondant
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(authority_key))?,
+        mint: mint_key,
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: vec![],
+    };
+    let mint_info = mint.to_account_info();
+    let new_len = mint_info.data_len() + metadata.tlv_size_of()?;
+    let top_up = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(mint_info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: mint_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    let bump = [authority.bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"auth_v5", &bump]];
+    let program = token_program.to_account_info();
+    let authority_info = authority.to_account_info();
+
+    // Inscrire les métadonnées dans le mint
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            program.clone(),
+            TokenMetadataInitialize {
+                token_program_id: program.clone(),
+                metadata: mint_info.clone(),
+                update_authority: authority_info.clone(),
+                mint_authority: authority_info.clone(),
+                mint: mint_info.clone(),
+            },
+            signer_seeds,
+        ),
+        name,
+        symbol,
+        uri,
+    )?;
+
+    // Créer le compte de jeton associé du propriétaire s'il n'existe pas encore
+    // (le programme de comptes associés en vérifie l'adresse)
+    if owner_nft_account.data_is_empty() {
+        associated_token::create(CpiContext::new(
+            associated_token_program.to_account_info(),
+            Create {
+                payer: payer.clone(),
+                associated_token: owner_nft_account.clone(),
+                authority: owner.clone(),
+                mint: mint_info.clone(),
+                system_program: system_program.to_account_info(),
+                token_program: program.clone(),
+            },
+        ))?;
+    }
+
+    // Émettre l'unique jeton puis geler le compte du propriétaire
+    mint_to(
+        CpiContext::new_with_signer(
+            program.clone(),
+            MintTo {
+                mint: mint_info.clone(),
+                to: owner_nft_account.clone(),
+                authority: authority_info.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    freeze_account(CpiContext::new_with_signer(
+        program,
+        FreezeAccount {
+            account: owner_nft_account.clone(),
+            mint: mint_info,
+            authority: authority_info,
+        },
+        signer_seeds,
+    ))?;
+
+    certificate.nft_mint = Some(mint_key);
+
+    msg!("Jeton NFT emis pour: {}", certificate.serial_number);
+    msg!("Mint: {}", mint_key);
+
+    Ok(())
+}
+
+/// Reporte l'URI courante du certificat dans les métadonnées Token-2022 de son
+/// mint, pour que portefeuilles et places de marché affichent la dernière version.
+/// Sans jeton émis pour ce certificat, les comptes optionnels sont ignorés.
+pub fn update_certificate_nft_uri<'info>(
+    certificate: &Certificate,
+    authority: &Account<'info, CertificationAuthority>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    token_program: Option<&Program<'info, Token2022>>,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let Some(nft_mint) = certificate.nft_mint else {
+        return Ok(());
+    };
+    let (Some(mint), Some(token_program)) = (mint, token_program) else {
+        return err!(ErrorCode::NftAccountsMissing);
+    };
+    require!(mint.key() == nft_mint, ErrorCode::InvalidNftAccount);
+
+    // Une URI plus longue agrandit le mint : le payeur avance le loyer correspondant
+    let mint_info = mint.to_account_info();
+    let (current_size, new_size) = {
+        let data = mint_info.try_borrow_data()?;
+        let state = StateWithExtensions::<MintState>::unpack(&data)?;
+        let mut metadata = state.get_variable_len_extension::<TokenMetadata>()?;
+        let current_size = metadata.tlv_size_of()?;
+        metadata.uri = certificate.metadata_uri.clone();
+        (current_size, metadata.tlv_size_of()?)
+    };
+    let new_len = mint_info.data_len() + new_size.saturating_sub(current_size);
+    let top_up = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(mint_info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: mint_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    let bump = [authority.bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"auth_v5", &bump]];
+    token_metadata_update_field(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TokenMetadataUpdateField {
+                token_program_id: token_program.to_account_info(),
+                metadata: mint_info,
+                update_authority: authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Field::Uri,
+        certificate.metadata_uri.clone(),
+    )?;
+
+    msg!("URI du jeton NFT mise a jour: {}", nft_mint);
+
+    Ok(())
+}
+
+/// Brûle le jeton NFT d'un certificat retiré via le délégué permanent : le jeton
+/// disparaît des portefeuilles et le propriétaire peut fermer son compte de jeton vide.
+/// Sans jeton émis pour ce certificat, les comptes optionnels sont ignorés.
+pub fn burn_certificate_nft<'info>(
+    certificate: &Certificate,
+    authority: &Account<'info, CertificationAuthority>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    owner_nft_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Program<'info, Token2022>>,
+) -> Result<()> {
+    let Some(nft_mint) = certificate.nft_mint else {
+        return Ok(());
+    };
+    let (Some(mint), Some(account), Some(token_program)) = (mint, owner_nft_account, token_program)
+    else {
+        return err!(ErrorCode::NftAccountsMissing);
+    };
+
+    require!(mint.key() == nft_mint, ErrorCode::InvalidNftAccount);
+    require!(
+        account.mint == nft_mint && account.owner == certificate.owner && account.amount == 1,
+        ErrorCode::InvalidNftAccount
+    );
+
+    let bump = [authority.bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"auth_v5", &bump]];
+    let program = token_program.to_account_info();
+    let authority_info = authority.to_account_info();
+    let mint_info = mint.to_account_info();
+
+    // Un compte gelé ne peut pas être débité : le dégeler avant de brûler
+    if account.is_frozen() {
+        thaw_account(CpiContext::new_with_signer(
+            program.clone(),
+            ThawAccount {
+                account: account.to_account_info(),
+                mint: mint_info.clone(),
+                authority: authority_info.clone(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    burn(
+        CpiContext::new_with_signer(
+            program,
+            Burn {
+                mint: mint_info,
+                from: account.to_account_info(),
+                authority: authority_info,
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    msg!("Jeton NFT brule: {}", nft_mint);
+
+    Ok(())
+}
+
+/// Déplace le jeton NFT du certificat vers le compte de jeton du nouveau propriétaire.
+/// Sans jeton émis pour ce certificat, les comptes optionnels sont ignorés.
+pub fn move_certificate_nft<'info>(
+    certificate: &Certificate,
+    authority: &Account<'info, CertificationAuthority>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    from: Option<&InterfaceAccount<'info, TokenAccount>>,
+    to: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Program<'info, Token2022>>,
+    new_owner: &Pubkey,
+) -> Result<()> {
+    let Some(nft_mint) = certificate.nft_mint else {
+        return Ok(());
+    };
+    let (Some(mint), Some(from), Some(to), Some(token_program)) = (mint, from, to, token_program)
+    else {
+        return err!(ErrorCode::NftAccountsMissing);
+    };
+
+    // Le jeton doit être détenu par le propriétaire actuel et arriver chez le nouveau
+    require!(mint.key() == nft_mint, ErrorCode::InvalidNftAccount);
+    require!(
+        from.mint == nft_mint && from.owner == certificate.owner && from.amount == 1,
+        ErrorCode::InvalidNftAccount
+    );
+    require!(
+        to.mint == nft_mint && to.owner == *new_owner && from.key() != to.key(),
+        ErrorCode::InvalidNftAccount
+    );
+
+    let bump = [authority.bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"auth_v5", &bump]];
+    let program = token_program.to_account_info();
+    let authority_info = authority.to_account_info();
+    let mint_info = mint.to_account_info();
+
+    // Dégeler, déplacer via le délégué permanent, puis regeler les deux comptes
+    for account in [from, to] {
+        if account.is_frozen() {
+            thaw_account(CpiContext::new_with_signer(
+                program.clone(),
+                ThawAccount {
+                    account: account.to_account_info(),
+                    mint: mint_info.clone(),
+                    authority: authority_info.clone(),
+                },
+                signer_seeds,
+            ))?;
+        }
+    }
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            program.clone(),
+            TransferChecked {
+                from: from.to_account_info(),
+                mint: mint_info.clone(),
+                to: to.to_account_info(),
+                authority: authority_info.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+        0,
+    )?;
+
+    for account in [from, to] {
+        freeze_account(CpiContext::new_with_signer(
+            program.clone(),
+            FreezeAccount {
+                account: account.to_account_info(),
+                mint: mint_info.clone(),
+                authority: authority_info.clone(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    Ok(())
+}
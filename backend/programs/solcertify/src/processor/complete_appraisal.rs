@@ -0,0 +1,113 @@
+// Processor: Complete Appraisal
+//
+// Le certificateur sollicité (ou un délégué habilité à approuver) signe la
+// nouvelle valeur et le nouveau type du certificat. Si le type monte en gamme,
+// la différence de frais est prélevée sur le dépôt et répartie comme les frais
+// de certification ; le reliquat revient au propriétaire à la fermeture de la
+// demande. Les compteurs par type de l'autorité sont ajustés et la
+// réévaluation est inscrite dans l'historique.
+
+use crate::errors::ErrorCode;
+use crate::state::{CertificationType, CertifierDelegate, DelegateAction};
+use crate::CompleteAppraisal;
+use anchor_lang::prelude::*;
+
+/// Handler pour signer une réévaluation
+pub fn handler(
+    ctx: Context<CompleteAppraisal>,
+    new_value: u64,
+    new_type: CertificationType,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let certifier_key = ctx.accounts.certifier.key();
+
+    require!(new_value > 0, ErrorCode::InvalidAppraisalValue);
+    ctx.accounts.certificate.require_active()?;
+
+    // Le demandeur doit toujours être propriétaire
+    require!(
+        ctx.accounts.certificate.is_owner(&ctx.accounts.appraisal_request.owner),
+        ErrorCode::NotOwner
+    );
+
+    // Le certificateur doit toujours être agréé et accrédité
+    require!(
+        ctx.accounts.certifier_profile.is_approved(),
+        ErrorCode::UnauthorizedCertifier
+    );
+    require!(
+        ctx.accounts.certifier_profile.is_accredited(clock.unix_timestamp),
+        ErrorCode::AccreditationExpired
+    );
+
+    // Le signataire doit être le certificateur ou un délégué habilité
+    let acting_delegate = CertifierDelegate::authorize(
+        &ctx.accounts.operator.key(),
+        &certifier_key,
+        ctx.accounts.delegate.as_deref(),
+        DelegateAction::Approve,
+        clock.unix_timestamp,
+    )?;
+
+    // Différence de frais due en cas de montée en gamme
+    let config = ctx.accounts.authority.config.clone();
+    let previous_type = ctx.accounts.certificate.cert_type.clone();
+    let previous_value = ctx.accounts.certificate.estimated_value;
+    let fee_difference = config.to_lamports(
+        config
+            .fee_for(&new_type)
+            .saturating_sub(config.fee_for(&previous_type)),
+        ctx.accounts.price_feed.as_deref(),
+        clock.unix_timestamp,
+    )?;
+    require!(
+        fee_difference <= ctx.accounts.appraisal_request.max_fee_difference,
+        ErrorCode::AppraisalDepositTooLow
+    );
+
+    // Répartir la différence depuis le dépôt séquestré
+    let certifier_share = config.certifier_share(fee_difference)?;
+    let treasury_share = fee_difference - certifier_share;
+    let request_info = ctx.accounts.appraisal_request.to_account_info();
+
+    **request_info.try_borrow_mut_lamports()? -= certifier_share;
+    **ctx.accounts.certifier_vault.to_account_info().try_borrow_mut_lamports()? += certifier_share;
+    ctx.accounts.certifier_vault.credit(certifier_share)?;
+
+    **request_info.try_borrow_mut_lamports()? -= treasury_share;
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += treasury_share;
+    ctx.accounts.treasury.record_deposit(treasury_share)?;
+
+    // Ajuster les compteurs par type
+    ctx.accounts.authority.reclassify(&previous_type, &new_type)?;
+    ctx.accounts.certifier_profile.record_appraisal(certifier_share)?;
+
+    // Inscrire la réévaluation dans l'historique
+    let certificate = &mut ctx.accounts.certificate;
+    let record = &mut ctx.accounts.appraisal_record;
+    record.certificate = certificate.key();
+    record.index = certificate.appraisal_count;
+    record.certifier = certifier_key;
+    record.acting_delegate = acting_delegate;
+    record.previous_value = previous_value;
+    record.new_value = new_value;
+    record.previous_type = previous_type;
+    record.new_type = new_type.clone();
+    record.fee_paid = fee_difference;
+    record.appraised_at = clock.unix_timestamp;
+    record.bump = ctx.bumps.appraisal_record;
+
+    // Mettre à jour le certificat
+    certificate.estimated_value = new_value;
+    certificate.cert_type = new_type;
+    certificate.appraisal_count = certificate
+        .appraisal_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    msg!("Certificat reevalue: {}", certificate.serial_number);
+    msg!("Valeur: {} -> {} EUR", previous_value, new_value);
+    msg!("Difference de frais: {} lamports", fee_difference);
+
+    Ok(())
+}
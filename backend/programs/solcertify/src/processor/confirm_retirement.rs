@@ -0,0 +1,65 @@
+// Processor: Confirm Retirement
+//
+// L'admin (ou le conseil) confirme ou refuse le retrait demandé pour un certificat
+// Luxury ou Exceptional. Un refus remet le certificat en circulation ; une
+// confirmation brûle le jeton NFT éventuel.
+
+use crate::errors::ErrorCode;
+use crate::events::CertificateRetired;
+use crate::processor::certificate_nft;
+use crate::state::{AdminAction, CertificateStatus};
+use crate::ConfirmRetirement;
+use anchor_lang::prelude::*;
+
+/// Handler pour confirmer ou refuser un retrait
+pub fn handler(ctx: Context<ConfirmRetirement>, approved: bool) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Autoriser l'opération (admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_admin(
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::ConfirmRetirement {
+            certificate: ctx.accounts.certificate.key(),
+            approved,
+        },
+        clock.unix_timestamp,
+    )?;
+
+    let certificate = &mut ctx.accounts.certificate;
+    require!(
+        certificate.status == CertificateStatus::RetirementPending,
+        ErrorCode::RetirementNotPending
+    );
+
+    if !approved {
+        certificate.status = CertificateStatus::Active;
+        certificate.retirement_reason = None;
+        msg!("Retrait refuse: {}", certificate.serial_number);
+        return Ok(());
+    }
+
+    // Retrait définitif : l'emplacement du propriétaire est libéré
+    certificate.retire(clock.unix_timestamp);
+    ctx.accounts.owner_activity.decrement_certificate_count()?;
+    certificate_nft::burn_certificate_nft(
+        certificate,
+        &ctx.accounts.authority,
+        ctx.accounts.nft_mint.as_ref(),
+        ctx.accounts.owner_nft_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+
+    emit!(CertificateRetired {
+        certificate: certificate.key(),
+        owner: certificate.owner,
+        reason: certificate
+            .retirement_reason
+            .ok_or(ErrorCode::RetirementNotPending)?,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Retrait confirme: {}", certificate.serial_number);
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+// Processor: Correct Owner
+//
+// Correction administrative du propriétaire d'un certificat (erreur de saisie,
+// décision de justice). Le changement est inscrit dans l'historique de provenance
+// comme correction administrative ; aucun verrouillage ni cooldown n'est appliqué.
+
+use crate::errors::ErrorCode;
+use crate::processor::certificate_nft::move_certificate_nft;
+use crate::state::constants::MAX_CERTIFICATES;
+use crate::state::{AdminAction, TransferKind};
+use crate::CorrectOwner;
+use anchor_lang::prelude::*;
+
+/// Handler pour corriger le propriétaire d'un certificat
+pub fn handler(ctx: Context<CorrectOwner>, new_owner: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let certificate_key = ctx.accounts.certificate.key();
+
+    // Autoriser l'opération (opérateur habilité, admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_operator_or_admin(
+        ctx.accounts.operator_role.is_some(),
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::CorrectOwner {
+            certificate: certificate_key,
+            new_owner,
+        },
+        clock.unix_timestamp,
+    )?;
+
+    let certificate = &mut ctx.accounts.certificate;
+    // Un certificat retiré (ou en cours de retrait) ne circule plus
+    certificate.require_active()?;
+
+    require!(
+        new_owner != certificate.owner,
+        ErrorCode::InvalidTransferRecipient
+    );
+
+    // Le nouveau propriétaire reste soumis à la limite de possession
+    let to_activity = &mut ctx.accounts.to_activity;
+    to_activity.init_if_new(new_owner, ctx.bumps.to_activity);
+    require!(
+        to_activity.can_receive_certificate(MAX_CERTIFICATES),
+        ErrorCode::MaxCertificatesReached
+    );
+
+    // Le jeton NFT du certificat (s'il existe) suit le changement de propriétaire
+    move_certificate_nft(
+        certificate,
+        &ctx.accounts.authority,
+        ctx.accounts.nft_mint.as_ref(),
+        ctx.accounts.from_nft_account.as_ref(),
+        ctx.accounts.to_nft_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &new_owner,
+    )?;
+
+    // Inscrire la correction dans la page de provenance courante (sans verrouillage)
+    let provenance_page = &mut ctx.accounts.provenance_page;
+    provenance_page.init_if_new(
+        certificate_key,
+        certificate.current_provenance_page(),
+        ctx.bumps.provenance_page,
+    );
+    let old_owner = certificate.transfer_ownership(
+        new_owner,
+        TransferKind::AdminCorrection,
+        provenance_page,
+        clock.unix_timestamp,
+        0,
+    )?;
+
+    ctx.accounts.from_activity.decrement_certificate_count()?;
+    to_activity.increment_certificate_count()?;
+
+    msg!("Proprietaire corrige: {}", certificate.serial_number);
+    msg!("De: {}", old_owner);
+    msg!("Vers: {}", new_owner);
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// Processor: Create Council
+//
+// Active le conseil d'administration M-sur-N. À partir de là, les opérations
+// sensibles (certificateurs, trésorerie, configuration) exigent une proposition
+// approuvée par le seuil de membres : la clé admin seule ne suffit plus.
+
+use crate::errors::ErrorCode;
+use crate::state::AdminCouncil;
+use crate::CreateCouncil;
+use anchor_lang::prelude::*;
+
+/// Handler pour créer le conseil d'administration
+pub fn handler(ctx: Context<CreateCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    let authority = &mut ctx.accounts.authority;
+    require!(!authority.council_enabled, ErrorCode::CouncilAlreadyEnabled);
+
+    AdminCouncil::validate_members(&members, threshold)?;
+
+    let council = &mut ctx.accounts.council;
+    council.members = members;
+    council.threshold = threshold;
+    council.proposal_count = 0;
+    council.bump = ctx.bumps.council;
+
+    authority.council_enabled = true;
+
+    msg!("Conseil active: {} membres", council.members.len());
+    msg!("Seuil d'approbation: {}", council.threshold);
+
+    Ok(())
+}
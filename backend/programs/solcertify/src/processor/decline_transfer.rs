@@ -0,0 +1,14 @@
+// Processor: Decline Transfer
+//
+// Le destinataire refuse une offre de transfert (le compte d'offre est fermé).
+
+use crate::DeclineTransfer;
+use anchor_lang::prelude::*;
+
+/// Handler pour refuser une offre de transfert
+pub fn handler(ctx: Context<DeclineTransfer>) -> Result<()> {
+    msg!("Offre de transfert refusee: {}", ctx.accounts.certificate.serial_number);
+    msg!("Par: {}", ctx.accounts.recipient.key());
+
+    Ok(())
+}
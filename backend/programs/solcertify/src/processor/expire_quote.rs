@@ -0,0 +1,31 @@
+// Processor: Expire Quote
+//
+// Clôture une demande dont le devis a expiré sans être accepté
+// (permissionless). Le créneau du certificateur est libéré et le compte de
+// demande est fermé (loyer rendu au demandeur).
+
+use crate::errors::ErrorCode;
+use crate::state::RequestStatus;
+use crate::ExpireQuote;
+use anchor_lang::prelude::*;
+
+/// Handler pour clôturer une demande au devis expiré
+pub fn handler(ctx: Context<ExpireQuote>) -> Result<()> {
+    let clock = Clock::get()?;
+    let request = &ctx.accounts.request;
+
+    require!(
+        request.status == RequestStatus::Quoted,
+        ErrorCode::NoQuoteToAccept
+    );
+    require!(
+        clock.unix_timestamp > request.quote_expires_at,
+        ErrorCode::QuoteNotExpired
+    );
+
+    ctx.accounts.certifier_profile.record_expiry()?;
+
+    msg!("Devis expire pour: {}", request.serial_number);
+
+    Ok(())
+}
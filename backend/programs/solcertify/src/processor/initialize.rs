@@ -2,6 +2,7 @@
 //
 // Initialise l'autorité de certification du programme SolCertify.
 
+use crate::state::ProtocolConfig;
 use crate::Initialize;
 use anchor_lang::prelude::*;
 
@@ -11,12 +12,23 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
 
     authority.admin = ctx.accounts.admin.key();
     authority.treasury = ctx.accounts.treasury.key();
-    authority.approved_certifiers = Vec::new();
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.payout_address = ctx.accounts.admin.key();
+    treasury.total_collected = 0;
+    treasury.total_withdrawn = 0;
+    treasury.bump = ctx.bumps.treasury;
+    authority.certifier_count = 0;
     authority.total_issued = 0;
     authority.standard_count = 0;
     authority.premium_count = 0;
     authority.luxury_count = 0;
     authority.exceptional_count = 0;
+    authority.config = ProtocolConfig::initial();
+    authority.council_enabled = false;
+    authority.council_epoch = 0;
+    authority.queued_change_count = 0;
+    authority.paused = false;
     authority.bump = ctx.bumps.authority;
 
     msg!("SolCertify initialise avec succes");
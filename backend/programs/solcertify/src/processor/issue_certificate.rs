@@ -3,8 +3,12 @@
 // Émet un nouveau certificat d'authenticité pour une montre de luxe.
 
 use crate::errors::ErrorCode;
-use crate::state::constants::{COOLDOWN_PERIOD, LOCK_PERIOD, MAX_CERTIFICATES};
-use crate::state::{Certificate, CertificationType};
+use crate::processor::{certificate_nft, token_fees};
+use crate::state::constants::MAX_CERTIFICATES;
+use crate::state::{
+    Certificate, CertificateStatus, CertificationType, CertifierDelegate, DelegateAction,
+    TransferPolicy,
+};
 use crate::IssueCertificate;
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
@@ -18,20 +22,36 @@ pub fn handler(
     cert_type: CertificationType,
     estimated_value: u64,
     metadata_uri: String,
+    metadata_hash: [u8; 32],
+    transfer_policy: TransferPolicy,
 ) -> Result<()> {
     let authority = &mut ctx.accounts.authority;
     let certificate = &mut ctx.accounts.certificate;
     let owner_activity = &mut ctx.accounts.owner_activity;
     let clock = Clock::get()?;
+    let certifier_key = ctx.accounts.certifier.key();
 
-    // Vérifier que le signataire est un certificateur agréé
+    // Vérifier que le certificateur est agréé (profil actif)
     require!(
-        authority
-            .approved_certifiers
-            .contains(&ctx.accounts.certifier.key()),
+        ctx.accounts.certifier_profile.is_approved(),
         ErrorCode::UnauthorizedCertifier
     );
 
+    // Vérifier que l'accréditation n'a pas expiré
+    require!(
+        ctx.accounts.certifier_profile.is_accredited(clock.unix_timestamp),
+        ErrorCode::AccreditationExpired
+    );
+
+    // Le signataire doit être le certificateur ou un délégué habilité
+    let acting_delegate = CertifierDelegate::authorize(
+        &ctx.accounts.operator.key(),
+        &certifier_key,
+        ctx.accounts.delegate.as_deref(),
+        DelegateAction::Issue,
+        clock.unix_timestamp,
+    )?;
+
     // Vérifier les longueurs des chaînes
     require!(
         serial_number.len() <= Certificate::MAX_SERIAL_LEN,
@@ -51,12 +71,7 @@ pub fn handler(
     );
 
     // Initialiser UserActivity si c'est un nouveau compte
-    if owner_activity.user == Pubkey::default() {
-        owner_activity.user = ctx.accounts.owner.key();
-        owner_activity.certificate_count = 0;
-        owner_activity.last_action_at = 0;
-        owner_activity.bump = ctx.bumps.owner_activity;
-    }
+    owner_activity.init_if_new(ctx.accounts.owner.key(), ctx.bumps.owner_activity);
 
     // Vérifier la limite de possession (max 4 certificats)
     require!(
@@ -65,23 +80,59 @@ pub fn handler(
     );
 
     // Vérifier le cooldown (5 minutes entre les actions)
-    if owner_activity.last_action_at > 0 {
-        let elapsed = clock.unix_timestamp - owner_activity.last_action_at;
-        require!(elapsed >= COOLDOWN_PERIOD, ErrorCode::CooldownNotElapsed);
-    }
+    owner_activity.require_cooldown_elapsed(clock.unix_timestamp, authority.config.cooldown_period)?;
 
     // Calculer et transférer les frais de certification
-    let fee = cert_type.get_fee();
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.certifier.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
-            },
-        ),
-        fee,
-    )?;
+    // (jeton SPL de la liste blanche vers le compte de jeton de la trésorerie, sinon SOL)
+    match ctx.accounts.fee_mint.as_deref() {
+        Some(fee_mint) => {
+            let (Some(mint), Some(payer), Some(treasury_tokens), Some(token_program)) = (
+                ctx.accounts.payment_mint.as_ref(),
+                ctx.accounts.payer_token_account.as_ref(),
+                ctx.accounts.treasury_token_account.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::TokenAccountsMissing);
+            };
+            token_fees::require_accepted(fee_mint, mint)?;
+            token_fees::require_token_account(
+                treasury_tokens,
+                &mint.key(),
+                &ctx.accounts.treasury.key(),
+            )?;
+
+            let fee = fee_mint.fee_for(&cert_type);
+            token_fees::transfer_tokens(
+                token_program,
+                mint,
+                payer,
+                treasury_tokens,
+                ctx.accounts.operator.to_account_info(),
+                &[],
+                fee,
+            )?;
+            msg!("Frais payes en jetons: {} ({})", fee, mint.key());
+        }
+        None => {
+            let fee = authority.config.fee_in_lamports(
+                &cert_type,
+                ctx.accounts.price_feed.as_deref(),
+                clock.unix_timestamp,
+            )?;
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.operator.to_account_info(),
+                        to: ctx.accounts.treasury.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
+            ctx.accounts.treasury.record_deposit(fee)?;
+            msg!("Frais payes: {} lamports", fee);
+        }
+    }
 
     // Initialiser le certificat
     certificate.serial_number = serial_number.clone();
@@ -90,12 +141,31 @@ pub fn handler(
     certificate.cert_type = cert_type.clone();
     certificate.estimated_value = estimated_value;
     certificate.owner = ctx.accounts.owner.key();
-    certificate.certifier = ctx.accounts.certifier.key();
+    certificate.certifier = certifier_key;
+    certificate.acting_delegate = acting_delegate;
+    certificate.transfer_delegate = None;
+    certificate.transfer_delegate_expires_at = 0;
     certificate.metadata_uri = metadata_uri;
+    certificate.metadata_hash = metadata_hash;
+    certificate.metadata_version = 0;
+    certificate.metadata_updated_at = clock.unix_timestamp;
     certificate.created_at = clock.unix_timestamp;
     certificate.last_transfer_at = clock.unix_timestamp;
-    certificate.locked_until = clock.unix_timestamp + LOCK_PERIOD;
-    certificate.previous_owners = Vec::new();
+    certificate.locked_until = clock.unix_timestamp + authority.config.lock_period;
+    certificate.valid_until = authority.config.validity_until(&cert_type, clock.unix_timestamp)?;
+    certificate.last_certified_at = clock.unix_timestamp;
+    certificate.last_certified_by = certifier_key;
+    certificate.transfer_count = 0;
+    certificate.last_sale_price = 0;
+    certificate.total_royalties_paid = 0;
+    certificate.status = CertificateStatus::Active;
+    certificate.retirement_reason = None;
+    certificate.retired_at = 0;
+    certificate.service_count = 0;
+    certificate.last_serviced_at = 0;
+    certificate.appraisal_count = 0;
+    certificate.nft_mint = None;
+    certificate.transfer_policy = transfer_policy;
     certificate.bump = ctx.bumps.certificate;
 
     // Mettre à jour l'activité du propriétaire
@@ -103,17 +173,35 @@ pub fn handler(
     owner_activity.last_action_at = clock.unix_timestamp;
 
     // Mettre à jour les compteurs de l'autorité
-    authority.total_issued += 1;
-    match cert_type {
-        CertificationType::Standard => authority.standard_count += 1,
-        CertificationType::Premium => authority.premium_count += 1,
-        CertificationType::Luxury => authority.luxury_count += 1,
-        CertificationType::Exceptional => authority.exceptional_count += 1,
+    authority.increment_counter(&cert_type)?;
+
+    // Comptabiliser l'émission directe dans le profil du certificateur
+    ctx.accounts.certifier_profile.record_direct_issue()?;
+
+    // Émettre le jeton NFT dès la création si le mint est fourni
+    if let Some(mint) = ctx.accounts.nft_mint.as_deref() {
+        let (Some(owner_nft_account), Some(token_program), Some(associated_token_program)) = (
+            ctx.accounts.owner_nft_account.as_ref(),
+            ctx.accounts.nft_token_program.as_ref(),
+            ctx.accounts.associated_token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::NftAccountsMissing);
+        };
+        certificate_nft::mint_certificate_nft(
+            &mut ctx.accounts.certificate,
+            &ctx.accounts.authority,
+            mint,
+            &ctx.accounts.owner,
+            owner_nft_account,
+            &ctx.accounts.operator.to_account_info(),
+            token_program,
+            associated_token_program,
+            &ctx.accounts.system_program,
+        )?;
     }
 
     msg!("Certificat emis pour: {}", serial_number);
     msg!("Proprietaire: {}", ctx.accounts.owner.key());
-    msg!("Frais payes: {} lamports", fee);
 
     Ok(())
 }
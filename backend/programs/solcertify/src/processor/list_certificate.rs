@@ -0,0 +1,54 @@
+// Processor: List Certificate
+//
+// Le propriétaire met son certificat en vente à prix fixe, pour un acheteur
+// désigné ou pour tout acheteur.
+
+use crate::errors::ErrorCode;
+use crate::ListCertificate;
+use anchor_lang::prelude::*;
+
+/// Handler pour mettre un certificat en vente
+pub fn handler(ctx: Context<ListCertificate>, price: u64, buyer: Option<Pubkey>) -> Result<()> {
+    let certificate = &ctx.accounts.certificate;
+    let clock = Clock::get()?;
+
+    // Un certificat retiré (ou en cours de retrait) ne circule plus
+    certificate.require_active()?;
+
+    // La vente atomique n'a pas d'étape d'approbation : seuls les certificats
+    // librement transférables peuvent être mis en vente
+    certificate.require_transferable()?;
+    require!(
+        !certificate.requires_transfer_approval(),
+        ErrorCode::TransferApprovalRequired
+    );
+
+    require!(
+        certificate.is_owner(&ctx.accounts.seller.key()),
+        ErrorCode::NotOwner
+    );
+    require!(price > 0, ErrorCode::InvalidPrice);
+    if let Some(buyer) = buyer {
+        require!(
+            buyer != ctx.accounts.seller.key(),
+            ErrorCode::InvalidTransferRecipient
+        );
+    }
+
+    let listing = &mut ctx.accounts.listing;
+    listing.certificate = certificate.key();
+    listing.seller = ctx.accounts.seller.key();
+    listing.price = price;
+    listing.buyer = buyer;
+    listing.created_at = clock.unix_timestamp;
+    listing.bump = ctx.bumps.listing;
+
+    msg!("Certificat mis en vente: {}", certificate.serial_number);
+    msg!("Prix: {} lamports", price);
+    match buyer {
+        Some(buyer) => msg!("Acheteur designe: {}", buyer),
+        None => msg!("Ouvert a tout acheteur"),
+    }
+
+    Ok(())
+}
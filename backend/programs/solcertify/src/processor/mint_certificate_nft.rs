@@ -0,0 +1,32 @@
+// Processor: Mint Certificate NFT
+//
+// Émet, à la demande du propriétaire, un jeton non fongible Token-2022 qui
+// rend le certificat visible dans les portefeuilles et places de marché
+// (pour un certificat émis sans jeton). Les métadonnées (nom, symbole, URI)
+// sont inscrites dans le mint lui-même. Le programme reste autorité de mint,
+// de gel et délégué permanent : le compte de jeton du propriétaire est gelé et
+// le jeton ne bouge qu'avec `certificate.owner` (voir `certificate_nft`).
+
+use crate::errors::ErrorCode;
+use crate::processor::certificate_nft;
+use crate::MintCertificateNft;
+use anchor_lang::prelude::*;
+
+/// Handler pour émettre le jeton NFT d'un certificat
+pub fn handler(ctx: Context<MintCertificateNft>) -> Result<()> {
+    let certificate = &ctx.accounts.certificate;
+    certificate.require_active()?;
+    require!(certificate.nft_mint.is_none(), ErrorCode::NftAlreadyMinted);
+
+    certificate_nft::mint_certificate_nft(
+        &mut ctx.accounts.certificate,
+        &ctx.accounts.authority,
+        &ctx.accounts.mint,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.owner_nft_account.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.associated_token_program,
+        &ctx.accounts.system_program,
+    )
+}
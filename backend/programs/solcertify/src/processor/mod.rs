@@ -7,9 +7,58 @@ pub mod add_certifier;
 pub mod initialize;
 pub mod issue_certificate;
 pub mod remove_certifier;
+pub mod renew_certifier;
 pub mod transfer_certificate;
 pub mod verify_certificate;
 pub mod request_certification;
 pub mod approve_certification;
 pub mod reject_certification;
+pub mod add_delegate;
+pub mod remove_delegate;
+pub mod withdraw_earnings;
+pub mod set_payout_address;
+pub mod set_payouts_held;
+pub mod withdraw_treasury;
+pub mod set_treasury;
 
+pub mod create_council;
+pub mod propose_admin_action;
+pub mod approve_admin_action;
+pub mod update_config;
+pub mod update_council;
+pub mod queue_change;
+pub mod cancel_change;
+pub mod set_operator_roles;
+pub mod set_paused;
+pub mod accept_transfer;
+pub mod decline_transfer;
+pub mod cancel_transfer;
+pub mod list_certificate;
+pub mod cancel_listing;
+pub mod purchase_certificate;
+pub mod approve_delegate;
+pub mod revoke_delegate;
+pub mod correct_owner;
+pub mod retire_certificate;
+pub mod confirm_retirement;
+pub mod reclaim_certificate_rent;
+pub mod add_service_record;
+pub mod request_appraisal;
+pub mod complete_appraisal;
+pub mod cancel_appraisal;
+pub mod update_metadata;
+pub mod recertify;
+pub mod certificate_nft;
+pub mod mint_certificate_nft;
+pub mod approve_transfer;
+pub mod token_fees;
+pub mod set_fee_mint;
+pub mod withdraw_token_earnings;
+pub mod withdraw_treasury_tokens;
+pub mod set_price_oracle;
+pub mod update_price;
+pub mod request_quote;
+pub mod post_quote;
+pub mod accept_quote;
+pub mod cancel_request;
+pub mod expire_quote;
@@ -0,0 +1,67 @@
+// Processor: Post Quote
+//
+// Le certificateur assigné (ou un délégué habilité) publie le prix de
+// l'inspection et son échéance. Un devis peut être révisé tant qu'il n'a pas
+// été accepté.
+
+use crate::errors::ErrorCode;
+use crate::events::QuotePosted;
+use crate::state::{CertifierDelegate, DelegateAction, RequestStatus, MAX_QUOTE_VALIDITY};
+use crate::PostQuote;
+use anchor_lang::prelude::*;
+
+/// Handler pour publier un devis
+pub fn handler(ctx: Context<PostQuote>, amount: u64, expires_at: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    let certifier_key = ctx.accounts.certifier.key();
+    let request = &ctx.accounts.request;
+
+    require!(
+        matches!(request.status, RequestStatus::AwaitingQuote | RequestStatus::Quoted),
+        ErrorCode::QuoteNotRequested
+    );
+    require!(
+        request.assigned_certifier == Some(certifier_key),
+        ErrorCode::NotAssignedCertifier
+    );
+
+    // Le signataire doit être le certificateur ou un délégué habilité
+    CertifierDelegate::authorize(
+        &ctx.accounts.operator.key(),
+        &certifier_key,
+        ctx.accounts.delegate.as_deref(),
+        DelegateAction::Approve,
+        clock.unix_timestamp,
+    )?;
+
+    require!(
+        ctx.accounts.certifier_profile.is_approved(),
+        ErrorCode::UnauthorizedCertifier
+    );
+
+    // Échéance dans le futur, bornée
+    require!(amount > 0, ErrorCode::InvalidQuote);
+    require!(
+        expires_at > clock.unix_timestamp
+            && expires_at - clock.unix_timestamp <= MAX_QUOTE_VALIDITY,
+        ErrorCode::InvalidQuote
+    );
+
+    let request = &mut ctx.accounts.request;
+    request.quote_amount = amount;
+    request.quote_expires_at = expires_at;
+    request.status = RequestStatus::Quoted;
+
+    emit!(QuotePosted {
+        request: request.key(),
+        certifier: certifier_key,
+        amount,
+        expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Devis pour {}: {} lamports", request.serial_number, amount);
+    msg!("Valable jusqu'au: {}", expires_at);
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+// Processor: Propose Admin Action
+//
+// Un membre du conseil propose une opération sensible.
+// Le proposant compte comme première approbation.
+
+use crate::errors::ErrorCode;
+use crate::state::{AdminAction, AdminCouncil};
+use crate::ProposeAdminAction;
+use anchor_lang::prelude::*;
+
+/// Handler pour proposer une opération au conseil
+pub fn handler(ctx: Context<ProposeAdminAction>, action: AdminAction) -> Result<()> {
+    let clock = Clock::get()?;
+    let proposer = ctx.accounts.proposer.key();
+    let council = &mut ctx.accounts.council;
+
+    require!(council.is_member(&proposer), ErrorCode::NotCouncilMember);
+
+    // Rejeter dès la proposition les paramètres qui échoueraient à l'exécution
+    match &action {
+        AdminAction::QueueChange { change } => change.validate()?,
+        AdminAction::UpdateCouncil { members, threshold } => {
+            AdminCouncil::validate_members(members, *threshold)?
+        }
+        _ => {}
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.id = council.proposal_count;
+    proposal.proposer = proposer;
+    proposal.council_epoch = ctx.accounts.authority.council_epoch;
+    proposal.action = action;
+    proposal.approvals = Vec::new();
+    proposal.approved = false;
+    proposal.executed = false;
+    proposal.created_at = clock.unix_timestamp;
+    proposal.executed_at = 0;
+    proposal.bump = ctx.bumps.proposal;
+    proposal.approve(proposer, council.threshold)?;
+
+    council.proposal_count = council
+        .proposal_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    msg!("Proposition #{} creee par {}", proposal.id, proposer);
+    msg!(
+        "Approbations: {}/{}",
+        proposal.approvals.len(),
+        council.threshold
+    );
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+// Processor: Purchase Certificate
+//
+// Achat atomique d'un certificat mis en vente : le paiement de l'acheteur est
+// séquestré dans le compte de vente puis reversé au vendeur dans la même
+// instruction que le changement de propriétaire. Les contrôles de verrouillage,
+// cooldown et limite de possession s'appliquent comme pour un transfert.
+// Les redevances de revente sont prélevées sur le séquestre au profit du
+// certificateur émetteur (coffre de gains) et de la plateforme (trésorerie).
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::errors::ErrorCode;
+use crate::processor::certificate_nft::move_certificate_nft;
+use crate::events::CertificateSold;
+use crate::state::constants::MAX_CERTIFICATES;
+use crate::state::TransferKind;
+use crate::PurchaseCertificate;
+
+/// Handler pour acheter un certificat mis en vente
+pub fn handler(ctx: Context<PurchaseCertificate>, max_price: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.authority.config;
+    let buyer = ctx.accounts.buyer.key();
+    let listing = &ctx.accounts.listing;
+    let price = listing.price;
+
+    // Vérifier les conditions de la vente
+    require!(listing.is_buyer_allowed(&buyer), ErrorCode::NotDesignatedBuyer);
+    require!(price <= max_price, ErrorCode::PriceAboveMaximum);
+
+    let certificate = &mut ctx.accounts.certificate;
+    let seller_activity = &mut ctx.accounts.seller_activity;
+    let buyer_activity = &mut ctx.accounts.buyer_activity;
+
+    // Un certificat retiré (ou en cours de retrait) ne circule plus
+    certificate.require_active()?;
+
+    // Le vendeur doit toujours être propriétaire
+    require!(
+        certificate.is_owner(&ctx.accounts.seller.key()),
+        ErrorCode::NotOwner
+    );
+
+    // Vérifier que le certificat n'est pas verrouillé
+    require!(
+        !certificate.is_locked(clock.unix_timestamp),
+        ErrorCode::CertificateLocked
+    );
+
+    // Vérifier le cooldown du vendeur
+    seller_activity.require_cooldown_elapsed(clock.unix_timestamp, config.cooldown_period)?;
+
+    // Initialiser UserActivity de l'acheteur si nécessaire
+    buyer_activity.init_if_new(buyer, ctx.bumps.buyer_activity);
+
+    // Vérifier le cooldown et la limite de possession de l'acheteur
+    buyer_activity.require_cooldown_elapsed(clock.unix_timestamp, config.cooldown_period)?;
+    require!(
+        buyer_activity.can_receive_certificate(MAX_CERTIFICATES),
+        ErrorCode::MaxCertificatesReached
+    );
+
+    // Séquestrer le paiement de l'acheteur dans le compte de vente
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.listing.to_account_info(),
+            },
+        ),
+        price,
+    )?;
+
+    // Répartir le séquestre : redevances puis solde au vendeur
+    // (le loyer du compte de vente revient au vendeur à la fermeture)
+    let (certifier_royalty, platform_royalty) = config.royalties(price)?;
+    let seller_proceeds = price - certifier_royalty - platform_royalty;
+    let listing_info = ctx.accounts.listing.to_account_info();
+
+    **listing_info.try_borrow_mut_lamports()? -= price;
+    **ctx.accounts.certifier_vault.to_account_info().try_borrow_mut_lamports()? += certifier_royalty;
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += platform_royalty;
+    **ctx.accounts.seller.try_borrow_mut_lamports()? += seller_proceeds;
+
+    ctx.accounts.certifier_vault.credit(certifier_royalty)?;
+    ctx.accounts.treasury.record_deposit(platform_royalty)?;
+    certificate.record_sale(price, certifier_royalty + platform_royalty)?;
+
+    // Le jeton NFT du certificat (s'il existe) suit le changement de propriétaire
+    move_certificate_nft(
+        certificate,
+        &ctx.accounts.authority,
+        ctx.accounts.nft_mint.as_ref(),
+        ctx.accounts.from_nft_account.as_ref(),
+        ctx.accounts.to_nft_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &buyer,
+    )?;
+
+    // Effectuer le transfert (inscrit dans la page de provenance courante)
+    let provenance_page = &mut ctx.accounts.provenance_page;
+    provenance_page.init_if_new(
+        certificate.key(),
+        certificate.current_provenance_page(),
+        ctx.bumps.provenance_page,
+    );
+    let old_owner = certificate.transfer_ownership(
+        buyer,
+        TransferKind::Sale,
+        provenance_page,
+        clock.unix_timestamp,
+        config.lock_period,
+    )?;
+
+    // Mettre à jour les compteurs
+    seller_activity.decrement_certificate_count()?;
+    seller_activity.update_last_action(clock.unix_timestamp);
+
+    buyer_activity.increment_certificate_count()?;
+    buyer_activity.update_last_action(clock.unix_timestamp);
+
+    emit!(CertificateSold {
+        certificate: certificate.key(),
+        seller: old_owner,
+        buyer,
+        price,
+        certifier_royalty,
+        platform_royalty,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Certificat vendu: {}", certificate.serial_number);
+    msg!("Prix: {} lamports", price);
+    msg!(
+        "Redevances - certificateur: {}, plateforme: {} lamports",
+        certifier_royalty,
+        platform_royalty
+    );
+    msg!("Vendeur: {}", old_owner);
+    msg!("Acheteur: {}", buyer);
+    msg!("Verrouille jusqu'a: {}", certificate.locked_until);
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+// Processor: Queue Change
+//
+// Met en file d'attente une modification du protocole (agrément d'un certificateur,
+// configuration). Elle ne pourra être appliquée qu'après le délai de préavis,
+// laissant aux certificateurs et propriétaires le temps de réagir.
+
+use crate::events::ChangeQueued;
+use crate::state::{AdminAction, ChangeStatus, TimelockedChange};
+use crate::QueueChange;
+use anchor_lang::prelude::*;
+
+/// Handler pour mettre une modification en file d'attente
+pub fn handler(ctx: Context<QueueChange>, change: TimelockedChange) -> Result<()> {
+    let clock = Clock::get()?;
+
+    change.validate()?;
+
+    // Autoriser l'opération (opérateur habilité, admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_operator_or_admin(
+        ctx.accounts.operator_role.is_some(),
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::QueueChange {
+            change: change.clone(),
+        },
+        clock.unix_timestamp,
+    )?;
+
+    let authority = &mut ctx.accounts.authority;
+    let queued_change = &mut ctx.accounts.queued_change;
+    queued_change.id = authority.queued_change_count;
+    queued_change.change = change;
+    queued_change.queued_by = ctx.accounts.admin.key();
+    queued_change.queued_at = clock.unix_timestamp;
+    queued_change.effective_at = clock
+        .unix_timestamp
+        .checked_add(authority.config.timelock_delay)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    queued_change.status = ChangeStatus::Queued;
+    queued_change.resolved_at = 0;
+    queued_change.bump = ctx.bumps.queued_change;
+
+    authority.queued_change_count = authority
+        .queued_change_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit!(ChangeQueued {
+        id: queued_change.id,
+        change: queued_change.change.clone(),
+        queued_by: queued_change.queued_by,
+        effective_at: queued_change.effective_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Modification #{} mise en file d'attente", queued_change.id);
+    msg!("Applicable a partir de: {}", queued_change.effective_at);
+
+    Ok(())
+}
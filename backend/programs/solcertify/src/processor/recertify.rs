@@ -0,0 +1,91 @@
+// Processor: Recertify
+//
+// Le propriétaire présente la montre à un certificateur agréé qui renouvelle
+// l'authentification. Les frais de recertification (pourcentage des frais de
+// la gamme) sont payés par le propriétaire et répartis comme les frais de
+// certification. La nouvelle fin de validité part de la recertification.
+
+use crate::errors::ErrorCode;
+use crate::state::{CertifierDelegate, DelegateAction};
+use crate::Recertify;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Handler pour recertifier un certificat
+pub fn handler(ctx: Context<Recertify>) -> Result<()> {
+    let clock = Clock::get()?;
+    let certifier_key = ctx.accounts.certifier.key();
+
+    ctx.accounts.certificate.require_active()?;
+
+    // Le certificateur doit être agréé et accrédité
+    require!(
+        ctx.accounts.certifier_profile.is_approved(),
+        ErrorCode::UnauthorizedCertifier
+    );
+    require!(
+        ctx.accounts.certifier_profile.is_accredited(clock.unix_timestamp),
+        ErrorCode::AccreditationExpired
+    );
+
+    // Le signataire doit être le certificateur ou un délégué habilité
+    CertifierDelegate::authorize(
+        &ctx.accounts.operator.key(),
+        &certifier_key,
+        ctx.accounts.delegate.as_deref(),
+        DelegateAction::Approve,
+        clock.unix_timestamp,
+    )?;
+
+    // La gamme doit avoir une période de validité
+    let config = ctx.accounts.authority.config.clone();
+    let cert_type = ctx.accounts.certificate.cert_type.clone();
+    let valid_until = config.validity_until(&cert_type, clock.unix_timestamp)?;
+    require!(valid_until != 0, ErrorCode::ValidityNotApplicable);
+
+    // Frais payés par le propriétaire : part du certificateur puis plateforme
+    let fee = config.recertification_fee_for(
+        &cert_type,
+        ctx.accounts.price_feed.as_deref(),
+        clock.unix_timestamp,
+    )?;
+    let certifier_share = config.certifier_share(fee)?;
+    let treasury_share = fee - certifier_share;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.certifier_vault.to_account_info(),
+            },
+        ),
+        certifier_share,
+    )?;
+    ctx.accounts.certifier_vault.credit(certifier_share)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        ),
+        treasury_share,
+    )?;
+    ctx.accounts.treasury.record_deposit(treasury_share)?;
+
+    // Renouveler l'authentification
+    let certificate = &mut ctx.accounts.certificate;
+    let previous_valid_until = certificate.valid_until;
+    certificate.valid_until = valid_until;
+    certificate.last_certified_at = clock.unix_timestamp;
+    certificate.last_certified_by = certifier_key;
+
+    msg!("Certificat recertifie: {}", certificate.serial_number);
+    msg!("Validite: {} -> {}", previous_valid_until, valid_until);
+    msg!("Frais payes: {} lamports", fee);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// Processor: Reclaim Certificate Rent
+//
+// Le propriétaire d'un certificat retiré récupère une partie du loyer : le compte
+// est réduit à la taille exacte de ses données, qui restent consultables. Le
+// compte n'est pas fermé afin que le numéro de série ne puisse pas être réémis.
+
+use crate::errors::ErrorCode;
+use crate::state::CertificateStatus;
+use crate::ReclaimCertificateRent;
+use anchor_lang::prelude::*;
+
+/// Handler pour récupérer le loyer d'un certificat retiré
+pub fn handler(ctx: Context<ReclaimCertificateRent>) -> Result<()> {
+    let certificate = &ctx.accounts.certificate;
+    require!(
+        certificate.status == CertificateStatus::Retired,
+        ErrorCode::CertificateNotRetired
+    );
+
+    // Taille exacte : discriminator + données sérialisées
+    let compact_len = 8 + certificate.try_to_vec()?.len();
+    let certificate_info = certificate.to_account_info();
+    if compact_len >= certificate_info.data_len() {
+        msg!("Aucun loyer a recuperer: {}", certificate.serial_number);
+        return Ok(());
+    }
+
+    certificate_info.realloc(compact_len, false)?;
+
+    // Rendre l'excédent au propriétaire (le compte reste exempté de loyer)
+    let rent_exempt = Rent::get()?.minimum_balance(compact_len);
+    let refund = certificate_info.lamports().saturating_sub(rent_exempt);
+    **certificate_info.try_borrow_mut_lamports()? -= refund;
+    **ctx.accounts.owner.try_borrow_mut_lamports()? += refund;
+
+    msg!("Loyer recupere: {} lamports", refund);
+    msg!("Certificat: {}", certificate.serial_number);
+
+    Ok(())
+}
@@ -1,12 +1,20 @@
 use anchor_lang::prelude::*;
-use crate::state::{CertificationRequest, RequestStatus};
+use crate::state::{
+    CertificationRequest, CertifierDelegate, DelegateAction, RejectionCode, RequestStatus,
+};
 use crate::errors::ErrorCode;
+use crate::processor::token_fees;
 use crate::RejectCertification;
 
 /// Handler pour rejeter une demande de certification
-/// SEUL le certificateur assigné peut rejeter cette demande
-/// Les frais sont rembourses au demandeur
-pub fn handler(ctx: Context<RejectCertification>, reason: String) -> Result<()> {
+/// SEUL le certificateur assigné (ou un délégué habilité) peut rejeter cette demande
+/// Les frais sont rembourses au demandeur, sauf les frais d'inspection si le motif
+/// correspond a une inspection physique (repartis entre certificateur et plateforme)
+pub fn handler(
+    ctx: Context<RejectCertification>,
+    rejection_code: RejectionCode,
+    reason: String,
+) -> Result<()> {
     let clock = Clock::get()?;
 
     // Lire les valeurs avant les emprunts mutables
@@ -15,6 +23,7 @@ pub fn handler(ctx: Context<RejectCertification>, reason: String) -> Result<()>
     let serial_number = ctx.accounts.request.serial_number.clone();
     let assigned_certifier = ctx.accounts.request.assigned_certifier;
     let created_at = ctx.accounts.request.created_at;
+    let certifier_key = ctx.accounts.certifier.key();
 
     // Verifier que la demande est en attente
     require!(
@@ -24,13 +33,22 @@ pub fn handler(ctx: Context<RejectCertification>, reason: String) -> Result<()>
 
     // NOUVELLE VERIFICATION: Seul le certificateur ASSIGNÉ peut rejeter
     require!(
-        assigned_certifier == Some(ctx.accounts.certifier.key()),
+        assigned_certifier == Some(certifier_key),
         ErrorCode::NotAssignedCertifier
     );
 
+    // Le signataire doit être le certificateur ou un délégué habilité
+    let acting_delegate = CertifierDelegate::authorize(
+        &ctx.accounts.operator.key(),
+        &certifier_key,
+        ctx.accounts.delegate.as_deref(),
+        DelegateAction::Reject,
+        clock.unix_timestamp,
+    )?;
+
     // Verifier que le certificateur est toujours agrée (sécurité)
     require!(
-        ctx.accounts.authority.approved_certifiers.contains(&ctx.accounts.certifier.key()),
+        ctx.accounts.certifier_profile.is_approved(),
         ErrorCode::UnauthorizedCertifier
     );
 
@@ -40,24 +58,122 @@ pub fn handler(ctx: Context<RejectCertification>, reason: String) -> Result<()>
         ErrorCode::RejectionReasonTooLong
     );
 
-    // Rembourser les frais au demandeur depuis le compte request PDA
-    **ctx.accounts.request.to_account_info().try_borrow_mut_lamports()? -= fee_paid;
-    **ctx.accounts.requester.try_borrow_mut_lamports()? += fee_paid;
+    // Frais d'inspection retenus selon le motif, le solde est remboursé
+    let config = ctx.accounts.authority.config.clone();
+    let inspection_fee = if rejection_code.charges_inspection_fee() {
+        config.inspection_fee(fee_paid)?
+    } else {
+        0
+    };
+    let certifier_share = config.certifier_share(inspection_fee)?;
+    let treasury_share = inspection_fee - certifier_share;
+    let refund = fee_paid - inspection_fee;
+
+    if let Some(fee_mint) = ctx.accounts.request.fee_mint {
+        // Frais en jetons : répartis / remboursés depuis le séquestre de la demande, puis séquestre fermé
+        let (Some(mint), Some(escrow), Some(requester_tokens), Some(token_program)) = (
+            ctx.accounts.payment_mint.as_ref(),
+            ctx.accounts.escrow_token_account.as_ref(),
+            ctx.accounts.requester_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::TokenAccountsMissing);
+        };
+        let request_key = ctx.accounts.request.key();
+        require!(mint.key() == fee_mint, ErrorCode::InvalidTokenAccount);
+        token_fees::require_token_account(escrow, &fee_mint, &request_key)?;
+        token_fees::require_token_account(requester_tokens, &fee_mint, &ctx.accounts.requester.key())?;
+
+        let bump = [ctx.accounts.request.bump];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"request", serial_number.as_bytes(), &bump]];
+        let request_info = ctx.accounts.request.to_account_info();
+        if inspection_fee > 0 {
+            let (Some(certifier_tokens), Some(treasury_tokens)) = (
+                ctx.accounts.certifier_token_account.as_ref(),
+                ctx.accounts.treasury_token_account.as_ref(),
+            ) else {
+                return err!(ErrorCode::TokenAccountsMissing);
+            };
+            token_fees::require_token_account(
+                certifier_tokens,
+                &fee_mint,
+                &ctx.accounts.certifier_vault.key(),
+            )?;
+            token_fees::require_token_account(treasury_tokens, &fee_mint, &ctx.accounts.treasury.key())?;
+            token_fees::transfer_tokens(
+                token_program,
+                mint,
+                escrow,
+                certifier_tokens,
+                request_info.clone(),
+                signer_seeds,
+                certifier_share,
+            )?;
+            token_fees::transfer_tokens(
+                token_program,
+                mint,
+                escrow,
+                treasury_tokens,
+                request_info.clone(),
+                signer_seeds,
+                treasury_share,
+            )?;
+        }
+        token_fees::transfer_tokens(
+            token_program,
+            mint,
+            escrow,
+            requester_tokens,
+            request_info.clone(),
+            signer_seeds,
+            refund,
+        )?;
+        token_fees::close_escrow(
+            token_program,
+            escrow,
+            ctx.accounts.requester.to_account_info(),
+            request_info,
+            signer_seeds,
+        )?;
+    } else {
+        // Frais d'inspection : part du certificateur dans son coffre, solde à la plateforme
+        if inspection_fee > 0 {
+            **ctx.accounts.request.to_account_info().try_borrow_mut_lamports()? -= certifier_share;
+            **ctx.accounts.certifier_vault.to_account_info().try_borrow_mut_lamports()? += certifier_share;
+            ctx.accounts.certifier_vault.credit(certifier_share)?;
+
+            **ctx.accounts.request.to_account_info().try_borrow_mut_lamports()? -= treasury_share;
+            **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += treasury_share;
+            ctx.accounts.treasury.record_deposit(treasury_share)?;
+        }
+
+        // Rembourser le solde au demandeur depuis le compte request PDA
+        **ctx.accounts.request.to_account_info().try_borrow_mut_lamports()? -= refund;
+        **ctx.accounts.requester.try_borrow_mut_lamports()? += refund;
+    }
 
     // Mettre à jour les stats du profil certificateur
+    // (les gains cumulés du profil sont suivis en lamports, hors frais en jetons)
     let certifier_profile = &mut ctx.accounts.certifier_profile;
     let processing_time = (clock.unix_timestamp - created_at) as u64;
-    certifier_profile.resolve_request(processing_time)?;
+    let lamports_earned = if ctx.accounts.request.fee_mint.is_some() { 0 } else { certifier_share };
+    certifier_profile.record_rejection(processing_time, lamports_earned)?;
 
     // Marquer la demande comme rejetee
     let request = &mut ctx.accounts.request;
     request.status = RequestStatus::Rejected;
     request.rejection_reason = reason.clone();
+    request.rejection_code = Some(rejection_code);
+    request.inspection_fee = inspection_fee;
     request.resolved_at = clock.unix_timestamp;
 
     msg!("Certification rejected for: {}", serial_number);
-    msg!("Reason: {}", reason);
-    msg!("Refunded: {} lamports", fee_paid);
+    msg!("Reason: {:?} - {}", rejection_code, reason);
+    msg!("Inspection fee kept: {}", inspection_fee);
+    msg!("Refunded: {}", refund);
+    if let Some(delegate) = acting_delegate {
+        msg!("Rejected by delegate: {}", delegate);
+    }
 
     Ok(())
 }
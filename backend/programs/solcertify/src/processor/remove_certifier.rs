@@ -1,30 +1,37 @@
 // Processor: Remove Certifier
 //
-// Retire un certificateur de la liste des agréés.
+// Retire l'agrément d'un certificateur en désactivant son profil.
 
 use crate::errors::ErrorCode;
+use crate::state::AdminAction;
 use crate::RemoveCertifier;
 use anchor_lang::prelude::*;
 
 /// Handler pour retirer un certificateur
 pub fn handler(ctx: Context<RemoveCertifier>, certifier: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Autoriser l'opération (opérateur habilité, admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_operator_or_admin(
+        ctx.accounts.operator_role.is_some(),
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::RemoveCertifier { certifier },
+        clock.unix_timestamp,
+    )?;
+
     let authority = &mut ctx.accounts.authority;
+    let profile = &mut ctx.accounts.certifier_profile;
 
-    // Trouver la position du certificateur dans la liste
-    let position = authority
-        .approved_certifiers
-        .iter()
-        .position(|&c| c == certifier)
-        .ok_or(ErrorCode::CertifierNotFound)?;
+    // Le certificateur doit être actuellement agréé
+    require!(profile.is_approved(), ErrorCode::CertifierNotFound);
 
-    // Retirer le certificateur de la liste
-    authority.approved_certifiers.remove(position);
+    // Désactiver le profil (les statistiques sont conservées)
+    profile.is_active = false;
+    authority.remove_certifier();
 
     msg!("Certificateur retire: {}", certifier);
-    msg!(
-        "Certificateurs restants: {}",
-        authority.approved_certifiers.len()
-    );
+    msg!("Certificateurs restants: {}", authority.certifier_count);
 
     Ok(())
 }
@@ -0,0 +1,23 @@
+// Processor: Remove Delegate
+//
+// Révoque une clé déléguée d'un certificateur et ferme son compte.
+
+use crate::RemoveDelegate;
+use anchor_lang::prelude::*;
+
+/// Handler pour révoquer une clé déléguée
+pub fn handler(ctx: Context<RemoveDelegate>) -> Result<()> {
+    ctx.accounts.certifier_profile.remove_delegate();
+
+    msg!(
+        "Delegue revoque: {} pour {}",
+        ctx.accounts.delegate_record.delegate,
+        ctx.accounts.certifier.key()
+    );
+    msg!(
+        "Delegues restants: {}",
+        ctx.accounts.certifier_profile.delegate_count
+    );
+
+    Ok(())
+}
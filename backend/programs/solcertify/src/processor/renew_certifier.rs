@@ -0,0 +1,34 @@
+// Processor: Renew Certifier
+//
+// Renouvelle l'accréditation annuelle d'un certificateur agréé.
+
+use crate::errors::ErrorCode;
+use crate::state::{AdminAction, ACCREDITATION_PERIOD};
+use crate::RenewCertifier;
+use anchor_lang::prelude::*;
+
+/// Handler pour renouveler l'accréditation d'un certificateur
+pub fn handler(ctx: Context<RenewCertifier>, certifier: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Autoriser l'opération (opérateur habilité, admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_operator_or_admin(
+        ctx.accounts.operator_role.is_some(),
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::RenewCertifier { certifier },
+        clock.unix_timestamp,
+    )?;
+
+    let profile = &mut ctx.accounts.certifier_profile;
+
+    // Seul un certificateur encore agréé peut être renouvelé
+    require!(profile.is_approved(), ErrorCode::CertifierNotFound);
+
+    profile.renew_accreditation(clock.unix_timestamp, ACCREDITATION_PERIOD)?;
+
+    msg!("Accreditation renouvelee: {}", certifier);
+    msg!("Valable jusqu'au: {}", profile.accredited_until);
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+// Processor: Request Appraisal
+//
+// Le propriétaire demande la réévaluation de son certificat à un certificateur
+// agréé. Il séquestre un dépôt couvrant au plus la différence de frais qu'il
+// accepte de payer si le certificat monte en gamme ; le reliquat lui est
+// restitué à la réévaluation.
+
+use crate::errors::ErrorCode;
+use crate::RequestAppraisal;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Handler pour demander une réévaluation
+pub fn handler(ctx: Context<RequestAppraisal>, certifier: Pubkey, max_fee_difference: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    ctx.accounts.certificate.require_active()?;
+
+    // Le certificateur sollicité doit être agréé et accrédité
+    require!(
+        ctx.accounts.certifier_profile.is_approved(),
+        ErrorCode::UnauthorizedCertifier
+    );
+    require!(
+        ctx.accounts.certifier_profile.is_accredited(clock.unix_timestamp),
+        ErrorCode::AccreditationExpired
+    );
+
+    // Séquestrer le dépôt sur le compte de demande
+    if max_fee_difference > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.appraisal_request.to_account_info(),
+                },
+            ),
+            max_fee_difference,
+        )?;
+    }
+
+    let request = &mut ctx.accounts.appraisal_request;
+    request.certificate = ctx.accounts.certificate.key();
+    request.owner = ctx.accounts.owner.key();
+    request.certifier = certifier;
+    request.max_fee_difference = max_fee_difference;
+    request.created_at = clock.unix_timestamp;
+    request.bump = ctx.bumps.appraisal_request;
+
+    msg!("Reevaluation demandee: {}", ctx.accounts.certificate.serial_number);
+    msg!("Certificateur: {}", certifier);
+    msg!("Depot: {} lamports", max_fee_difference);
+
+    Ok(())
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use crate::state::{CertificationRequest, CertificationType, RequestStatus};
+use crate::state::{CertificationRequest, CertificationType, RequestStatus, TransferPolicy};
 use crate::errors::ErrorCode;
+use crate::processor::token_fees;
 use crate::RequestCertification;
 
 /// Handler pour soumettre une demande de certification
@@ -15,6 +16,8 @@ pub fn handler(
     cert_type: CertificationType,
     estimated_value: u64,
     metadata_uri: String,
+    metadata_hash: [u8; 32],
+    transfer_policy: TransferPolicy,
     target_certifier: Pubkey, // Certificateur choisi par le demandeur
 ) -> Result<()> {
     let clock = Clock::get()?;
@@ -37,27 +40,60 @@ pub fn handler(
         ErrorCode::MetadataUriTooLong
     );
 
-    // Vérifier que le certificateur est dans la liste des agréés
+    // Vérifier que l'accréditation du certificateur n'a pas expiré
     require!(
-        ctx.accounts.authority.is_approved_certifier(&target_certifier),
-        ErrorCode::UnauthorizedCertifier
+        ctx.accounts.certifier_profile.is_accredited(clock.unix_timestamp),
+        ErrorCode::AccreditationExpired
     );
 
-    // Calculer les frais
-    let fee = cert_type.get_fee();
+    // Frais en jeton SPL de la liste blanche, sinon en SOL
+    // Les frais sont séquestrés jusqu'à l'approbation (distribués) ou au rejet (remboursés)
+    let (fee, fee_mint) = match ctx.accounts.fee_mint.as_deref() {
+        Some(fee_mint) => {
+            let (Some(mint), Some(payer), Some(escrow), Some(token_program)) = (
+                ctx.accounts.payment_mint.as_ref(),
+                ctx.accounts.payer_token_account.as_ref(),
+                ctx.accounts.escrow_token_account.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::TokenAccountsMissing);
+            };
+            token_fees::require_accepted(fee_mint, mint)?;
+            token_fees::require_token_account(escrow, &mint.key(), &ctx.accounts.request.key())?;
 
-    // Transferer les frais vers le compte request PDA (le programme en est proprietaire)
-    // Les frais seront distribues lors de l'approbation ou rembourses lors du rejet
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.requester.to_account_info(),
-                to: ctx.accounts.request.to_account_info(),
-            },
-        ),
-        fee,
-    )?;
+            let fee = fee_mint.fee_for(&cert_type);
+            token_fees::transfer_tokens(
+                token_program,
+                mint,
+                payer,
+                escrow,
+                ctx.accounts.requester.to_account_info(),
+                &[],
+                fee,
+            )?;
+            (fee, Some(mint.key()))
+        }
+        None => {
+            // Transferer les frais vers le compte request PDA (le programme en est proprietaire)
+            // (frais en euros : convertis au cours de l'oracle au moment de la demande)
+            let fee = ctx.accounts.authority.config.fee_in_lamports(
+                &cert_type,
+                ctx.accounts.price_feed.as_deref(),
+                clock.unix_timestamp,
+            )?;
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.requester.to_account_info(),
+                        to: ctx.accounts.request.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
+            (fee, None)
+        }
+    };
 
     // Incrémenter la charge du certificateur cible
     let certifier_profile = &mut ctx.accounts.certifier_profile;
@@ -72,17 +108,27 @@ pub fn handler(
     request.cert_type = cert_type;
     request.estimated_value = estimated_value;
     request.metadata_uri = metadata_uri;
+    request.metadata_hash = metadata_hash;
+    request.transfer_policy = transfer_policy;
     request.status = RequestStatus::Pending;
     request.assigned_certifier = Some(target_certifier); // ASSIGNATION OBLIGATOIRE
     request.rejection_reason = String::new();
+    request.rejection_code = None;
+    request.inspection_fee = 0;
     request.created_at = clock.unix_timestamp;
     request.resolved_at = 0;
     request.fee_paid = fee;
+    request.fee_mint = fee_mint;
+    request.quote_amount = 0;
+    request.quote_expires_at = 0;
     request.bump = ctx.bumps.request;
 
     msg!("Certification request created for: {}", serial_number);
     msg!("Assigned to certifier: {}", target_certifier);
-    msg!("Fee paid: {} lamports", fee);
+    match fee_mint {
+        Some(mint) => msg!("Fee paid: {} tokens ({})", fee, mint),
+        None => msg!("Fee paid: {} lamports", fee),
+    }
 
     Ok(())
 }
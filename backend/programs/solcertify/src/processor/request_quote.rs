@@ -0,0 +1,82 @@
+// Processor: Request Quote
+//
+// Ouvre une demande de certification sans paiement : le certificateur choisi
+// publie ensuite un devis sur mesure (post_quote), que le demandeur accepte en
+// approvisionnant le séquestre (accept_quote). La demande suit alors le flux
+// normal d'approbation / de rejet.
+
+use crate::errors::ErrorCode;
+use crate::state::{CertificationRequest, CertificationType, RequestStatus, TransferPolicy};
+use crate::RequestQuote;
+use anchor_lang::prelude::*;
+
+/// Handler pour ouvrir une demande de certification sur devis
+pub fn handler(
+    ctx: Context<RequestQuote>,
+    serial_number: String,
+    brand: String,
+    model: String,
+    cert_type: CertificationType,
+    estimated_value: u64,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
+    transfer_policy: TransferPolicy,
+    target_certifier: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Valider les longueurs
+    require!(
+        serial_number.len() <= CertificationRequest::MAX_SERIAL_LENGTH,
+        ErrorCode::SerialNumberTooLong
+    );
+    require!(
+        brand.len() <= CertificationRequest::MAX_BRAND_LENGTH,
+        ErrorCode::BrandTooLong
+    );
+    require!(
+        model.len() <= CertificationRequest::MAX_MODEL_LENGTH,
+        ErrorCode::ModelTooLong
+    );
+    require!(
+        metadata_uri.len() <= CertificationRequest::MAX_URI_LENGTH,
+        ErrorCode::MetadataUriTooLong
+    );
+
+    // Vérifier que l'accréditation du certificateur n'a pas expiré
+    require!(
+        ctx.accounts.certifier_profile.is_accredited(clock.unix_timestamp),
+        ErrorCode::AccreditationExpired
+    );
+
+    // La demande occupe un créneau du certificateur dès l'ouverture
+    ctx.accounts.certifier_profile.increment_load()?;
+
+    let request = &mut ctx.accounts.request;
+    request.requester = ctx.accounts.requester.key();
+    request.serial_number = serial_number.clone();
+    request.brand = brand;
+    request.model = model;
+    request.cert_type = cert_type;
+    request.estimated_value = estimated_value;
+    request.metadata_uri = metadata_uri;
+    request.metadata_hash = metadata_hash;
+    request.transfer_policy = transfer_policy;
+    request.status = RequestStatus::AwaitingQuote;
+    request.assigned_certifier = Some(target_certifier);
+    request.rejection_reason = String::new();
+    request.rejection_code = None;
+    request.inspection_fee = 0;
+    request.created_at = clock.unix_timestamp;
+    request.resolved_at = 0;
+    request.fee_paid = 0;
+    request.fee_mint = None;
+    request.quote_amount = 0;
+    request.quote_expires_at = 0;
+    request.bump = ctx.bumps.request;
+
+    msg!("Demande sur devis ouverte pour: {}", serial_number);
+    msg!("Certificateur sollicite: {}", target_certifier);
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+// Processor: Retire Certificate
+//
+// Le propriétaire retire un certificat dont la montre a été détruite, démontée ou
+// exportée définitivement. Pour les gammes Standard et Premium le retrait est
+// immédiat ; pour Luxury et Exceptional il reste en attente de confirmation par
+// l'admin (confirm_retirement). Un certificat en cours de retrait ne peut plus
+// être transféré, mis en vente ni délégué. Le jeton NFT éventuel est brûlé
+// lorsque le retrait devient effectif.
+
+use crate::events::CertificateRetired;
+use crate::processor::certificate_nft;
+use crate::state::{CertificateStatus, RetirementReason};
+use crate::RetireCertificate;
+use anchor_lang::prelude::*;
+
+/// Handler pour demander le retrait d'un certificat
+pub fn handler(ctx: Context<RetireCertificate>, reason: RetirementReason) -> Result<()> {
+    let certificate = &mut ctx.accounts.certificate;
+    let clock = Clock::get()?;
+
+    certificate.require_active()?;
+    certificate.retirement_reason = Some(reason);
+
+    if certificate.retirement_requires_confirmation() {
+        certificate.status = CertificateStatus::RetirementPending;
+        msg!("Retrait demande: {}", certificate.serial_number);
+        msg!("En attente de confirmation par l'admin");
+        return Ok(());
+    }
+
+    // Retrait immédiat : l'emplacement du propriétaire est libéré
+    certificate.retire(clock.unix_timestamp);
+    ctx.accounts.owner_activity.decrement_certificate_count()?;
+    certificate_nft::burn_certificate_nft(
+        certificate,
+        &ctx.accounts.authority,
+        ctx.accounts.nft_mint.as_ref(),
+        ctx.accounts.owner_nft_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+
+    emit!(CertificateRetired {
+        certificate: certificate.key(),
+        owner: certificate.owner,
+        reason,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Certificat retire: {}", certificate.serial_number);
+
+    Ok(())
+}
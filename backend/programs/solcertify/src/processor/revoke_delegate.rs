@@ -0,0 +1,18 @@
+// Processor: Revoke Delegate
+//
+// Le propriétaire révoque le délégué de transfert du certificat.
+
+use crate::RevokeDelegate;
+use anchor_lang::prelude::*;
+
+/// Handler pour révoquer le délégué de transfert
+pub fn handler(ctx: Context<RevokeDelegate>) -> Result<()> {
+    let certificate = &mut ctx.accounts.certificate;
+
+    certificate.transfer_delegate = None;
+    certificate.transfer_delegate_expires_at = 0;
+
+    msg!("Delegue de transfert revoque: {}", certificate.serial_number);
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+// Processor: Set Fee Mint
+//
+// Ajoute un jeton SPL (USDC, EURC...) à la liste blanche des frais, ou met à jour
+// ses frais par type. Comme les frais en SOL, la modification est soumise au
+// préavis : elle applique une modification mise en file d'attente via
+// queue_change, par n'importe quel signataire une fois le délai écoulé.
+
+use crate::events::ChangeExecuted;
+use crate::state::TimelockedChange;
+use crate::SetFeeMint;
+use anchor_lang::prelude::*;
+
+/// Handler pour ajouter ou mettre à jour un jeton accepté pour les frais
+pub fn handler(
+    ctx: Context<SetFeeMint>,
+    mint: Pubkey,
+    standard_fee: u64,
+    premium_fee: u64,
+    luxury_fee: u64,
+    exceptional_fee: u64,
+    enabled: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Consommer la modification mise en file d'attente (préavis écoulé)
+    let queued_change = &mut ctx.accounts.queued_change;
+    queued_change.execute(
+        &TimelockedChange::SetFeeMint {
+            mint,
+            standard_fee,
+            premium_fee,
+            luxury_fee,
+            exceptional_fee,
+            enabled,
+        },
+        clock.unix_timestamp,
+    )?;
+
+    emit!(ChangeExecuted {
+        id: queued_change.id,
+        executor: ctx.accounts.executor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    let fee_mint = &mut ctx.accounts.fee_mint;
+    fee_mint.mint = mint;
+    fee_mint.standard_fee = standard_fee;
+    fee_mint.premium_fee = premium_fee;
+    fee_mint.luxury_fee = luxury_fee;
+    fee_mint.exceptional_fee = exceptional_fee;
+    fee_mint.enabled = enabled;
+    fee_mint.bump = ctx.bumps.fee_mint;
+
+    msg!("Jeton de frais: {} (accepte: {})", mint, enabled);
+    msg!(
+        "Frais: {} / {} / {} / {} (unites du jeton)",
+        standard_fee,
+        premium_fee,
+        luxury_fee,
+        exceptional_fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+// Processor: Set Operator Roles
+//
+// Attribue ou retire des rôles opérationnels à un opérateur (gestion des
+// certificateurs, des frais, suspension, litiges) pour que les opérations
+// courantes ne nécessitent pas la clé admin.
+
+use crate::state::{AdminAction, OperatorRoles};
+use crate::SetOperatorRoles;
+use anchor_lang::prelude::*;
+
+/// Handler pour définir les rôles d'un opérateur
+pub fn handler(ctx: Context<SetOperatorRoles>, operator: Pubkey, roles: OperatorRoles) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Autoriser l'opération (admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_admin(
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::SetOperatorRoles { operator, roles },
+        clock.unix_timestamp,
+    )?;
+
+    let operator_role = &mut ctx.accounts.operator_role;
+    operator_role.operator = operator;
+    operator_role.roles = roles;
+    operator_role.updated_at = clock.unix_timestamp;
+    operator_role.bump = ctx.bumps.operator_role;
+
+    msg!("Roles de l'operateur {}", operator);
+    msg!(
+        "Certificateurs: {}, frais: {}, suspension: {}, litiges: {}",
+        roles.certifier_manager,
+        roles.fee_manager,
+        roles.pauser,
+        roles.dispute_resolver
+    );
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// Processor: Set Paused
+//
+// Suspend ou reprend l'activité du protocole (émissions, demandes, transferts).
+
+use crate::state::AdminAction;
+use crate::SetPaused;
+use anchor_lang::prelude::*;
+
+/// Handler pour suspendre/reprendre le protocole
+pub fn handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Autoriser l'opération (opérateur habilité, admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_operator_or_admin(
+        ctx.accounts.operator_role.is_some(),
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::SetPaused { paused },
+        clock.unix_timestamp,
+    )?;
+
+    let authority = &mut ctx.accounts.authority;
+    authority.paused = paused;
+
+    msg!("Protocole suspendu: {}", paused);
+
+    Ok(())
+}
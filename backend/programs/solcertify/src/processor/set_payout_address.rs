@@ -0,0 +1,25 @@
+// Processor: Set Payout Address
+//
+// Configure l'adresse de paiement des gains d'un certificateur.
+
+use crate::errors::ErrorCode;
+use crate::SetPayoutAddress;
+use anchor_lang::prelude::*;
+
+/// Handler pour configurer l'adresse de paiement
+pub fn handler(ctx: Context<SetPayoutAddress>, payout_address: Pubkey) -> Result<()> {
+    // Le coffre lui-même ne peut pas être sa propre destination
+    require!(
+        payout_address != Pubkey::default()
+            && payout_address != ctx.accounts.certifier_vault.key(),
+        ErrorCode::InvalidPayoutAddress
+    );
+
+    let vault = &mut ctx.accounts.certifier_vault;
+    vault.payout_address = payout_address;
+
+    msg!("Adresse de paiement mise a jour: {}", payout_address);
+    msg!("Certificateur: {}", vault.certifier);
+
+    Ok(())
+}
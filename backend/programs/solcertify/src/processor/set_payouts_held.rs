@@ -0,0 +1,28 @@
+// Processor: Set Payouts Held
+//
+// Bloque ou débloque les retraits d'un certificateur (litige en cours).
+
+use crate::state::AdminAction;
+use crate::SetPayoutsHeld;
+use anchor_lang::prelude::*;
+
+/// Handler pour bloquer/débloquer les paiements
+pub fn handler(ctx: Context<SetPayoutsHeld>, certifier: Pubkey, held: bool) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Autoriser l'opération (opérateur habilité, admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_operator_or_admin(
+        ctx.accounts.operator_role.is_some(),
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::SetPayoutsHeld { certifier, held },
+        clock.unix_timestamp,
+    )?;
+
+    let vault = &mut ctx.accounts.certifier_vault;
+    vault.payouts_held = held;
+
+    msg!("Paiements du certificateur {} bloques: {}", certifier, held);
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+// Processor: Set Price Oracle
+//
+// Désigne la clé autorisée à publier le cours SOL/EUR et l'ancienneté maximale
+// acceptée du cours. Crée le compte de cours lors du premier appel.
+// Changer d'oracle invalide le cours courant jusqu'à la prochaine publication.
+
+use crate::errors::ErrorCode;
+use crate::state::AdminAction;
+use crate::SetPriceOracle;
+use anchor_lang::prelude::*;
+
+/// Handler pour désigner l'oracle du cours SOL/EUR
+pub fn handler(ctx: Context<SetPriceOracle>, oracle: Pubkey, max_staleness: i64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Autoriser l'opération (admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_admin(
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::SetPriceOracle {
+            oracle,
+            max_staleness,
+        },
+        clock.unix_timestamp,
+    )?;
+
+    require!(max_staleness > 0, ErrorCode::InvalidPriceFeed);
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    if price_feed.oracle != oracle {
+        // Le cours publié par l'ancien oracle n'est plus utilisable
+        price_feed.sol_price_eur_cents = 0;
+        price_feed.updated_at = 0;
+    }
+    price_feed.oracle = oracle;
+    price_feed.max_staleness = max_staleness;
+    price_feed.bump = ctx.bumps.price_feed;
+
+    msg!("Oracle du cours SOL/EUR: {}", oracle);
+    msg!("Anciennete maximale du cours: {} secondes", max_staleness);
+
+    Ok(())
+}
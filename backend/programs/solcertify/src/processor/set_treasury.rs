@@ -0,0 +1,44 @@
+// Processor: Set Treasury
+//
+// Désigne l'adresse de paiement de la plateforme : les retraits du coffre de
+// trésorerie (lamports et jetons) ne peuvent être versés qu'à cette adresse.
+
+use crate::errors::ErrorCode;
+use crate::events::TreasuryUpdated;
+use crate::state::AdminAction;
+use crate::SetTreasury;
+use anchor_lang::prelude::*;
+
+/// Handler pour définir l'adresse de paiement de la trésorerie
+pub fn handler(ctx: Context<SetTreasury>, payout_address: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Le coffre lui-même ne peut pas être sa propre destination
+    require!(
+        payout_address != Pubkey::default() && payout_address != ctx.accounts.treasury.key(),
+        ErrorCode::InvalidPayoutAddress
+    );
+
+    // Autoriser l'opération (admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_admin(
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::SetTreasury { payout_address },
+        clock.unix_timestamp,
+    )?;
+
+    let treasury = &mut ctx.accounts.treasury;
+    let previous_payout_address = treasury.payout_address;
+    treasury.payout_address = payout_address;
+
+    emit!(TreasuryUpdated {
+        admin: ctx.accounts.admin.key(),
+        previous_payout_address,
+        new_payout_address: payout_address,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Adresse de paiement tresorerie: {} -> {}", previous_payout_address, payout_address);
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// Utilitaires: frais en jetons SPL
+//
+// Les frais peuvent être réglés dans un jeton SPL de la liste blanche (FeeMint).
+// Dans le flux de demande, les jetons sont séquestrés dans un compte de jeton
+// détenu par la demande, puis répartis comme les frais en SOL : part du
+// certificateur dans un compte de jeton détenu par son coffre, solde dans un
+// compte de jeton détenu par la trésorerie.
+
+use crate::errors::ErrorCode;
+use crate::state::FeeMint;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+/// Vérifie que le mint fourni est celui de la liste blanche et qu'il est accepté
+pub fn require_accepted(fee_mint: &FeeMint, mint: &InterfaceAccount<Mint>) -> Result<()> {
+    require!(
+        fee_mint.enabled && fee_mint.mint == mint.key(),
+        ErrorCode::FeeMintNotAccepted
+    );
+    Ok(())
+}
+
+/// Vérifie le mint et le détenteur d'un compte de jeton
+pub fn require_token_account(
+    account: &InterfaceAccount<TokenAccount>,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    require!(
+        account.mint == *mint && account.owner == *owner,
+        ErrorCode::InvalidTokenAccount
+    );
+    Ok(())
+}
+
+/// Transfère des jetons (signé par le détenteur ou par un PDA du programme)
+pub fn transfer_tokens<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority,
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// Ferme le séquestre vidé d'une demande (loyer rendu au demandeur)
+pub fn close_escrow<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    escrow: &InterfaceAccount<'info, TokenAccount>,
+    destination: AccountInfo<'info>,
+    request: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: escrow.to_account_info(),
+            destination,
+            authority: request,
+        },
+        signer_seeds,
+    ))
+}
@@ -1,80 +1,65 @@
 // Processor: Transfer Certificate
 //
-// Transfère la propriété d'un certificat à un nouveau propriétaire.
+// Première étape d'un transfert : le propriétaire propose le certificat à un
+// destinataire. La propriété ne change qu'à l'acceptation (accept_transfer),
+// ce qui évite d'imposer un certificat (et un emplacement) sans consentement.
+// Un délégué approuvé (approve_delegate) peut proposer le transfert pour le
+// compte du propriétaire.
 
 use crate::errors::ErrorCode;
-use crate::state::constants::{COOLDOWN_PERIOD, LOCK_PERIOD, MAX_CERTIFICATES};
-use crate::state::Certificate;
+use crate::state::TransferKind;
 use crate::TransferCertificate;
 use anchor_lang::prelude::*;
 
-/// Handler pour transférer un certificat
-pub fn handler(ctx: Context<TransferCertificate>) -> Result<()> {
-    let certificate = &mut ctx.accounts.certificate;
-    let from_activity = &mut ctx.accounts.from_activity;
-    let to_activity = &mut ctx.accounts.to_activity;
+/// Handler pour proposer le transfert d'un certificat
+pub fn handler(ctx: Context<TransferCertificate>, kind: TransferKind) -> Result<()> {
+    let certificate = &ctx.accounts.certificate;
     let clock = Clock::get()?;
 
-    // Vérifier que le signataire est le propriétaire actuel
+    // Un certificat retiré (ou en cours de retrait) ne circule plus
+    certificate.require_active()?;
+
+    // Un certificat lié à son propriétaire ne peut pas être proposé
+    certificate.require_transferable()?;
+
+    // Vérifier que le signataire est le propriétaire ou son délégué
+    certificate.authorize_transfer(&ctx.accounts.from.key(), clock.unix_timestamp)?;
+    require!(
+        ctx.accounts.to.key() != certificate.owner,
+        ErrorCode::InvalidTransferRecipient
+    );
+
+    // La correction administrative passe par admin_correct_owner
     require!(
-        ctx.accounts.from.key() == certificate.owner,
-        ErrorCode::NotOwner
+        kind != TransferKind::AdminCorrection,
+        ErrorCode::InvalidTransferKind
     );
 
     // Vérifier que le certificat n'est pas verrouillé
     require!(
-        clock.unix_timestamp >= certificate.locked_until,
+        !certificate.is_locked(clock.unix_timestamp),
         ErrorCode::CertificateLocked
     );
 
-    // Vérifier le cooldown du vendeur
-    if from_activity.last_action_at > 0 {
-        let elapsed = clock.unix_timestamp - from_activity.last_action_at;
-        require!(elapsed >= COOLDOWN_PERIOD, ErrorCode::CooldownNotElapsed);
-    }
-
-    // Initialiser UserActivity du nouveau propriétaire si nécessaire
-    if to_activity.user == Pubkey::default() {
-        to_activity.user = ctx.accounts.to.key();
-        to_activity.certificate_count = 0;
-        to_activity.last_action_at = 0;
-        to_activity.bump = ctx.bumps.to_activity;
-    }
+    let pending_transfer = &mut ctx.accounts.pending_transfer;
+    pending_transfer.certificate = certificate.key();
+    pending_transfer.from = certificate.owner;
+    pending_transfer.initiator = ctx.accounts.from.key();
+    pending_transfer.to = ctx.accounts.to.key();
+    pending_transfer.kind = kind;
+    pending_transfer.certifier_approved = false;
+    pending_transfer.created_at = clock.unix_timestamp;
+    pending_transfer.bump = ctx.bumps.pending_transfer;
 
-    // Vérifier le cooldown du nouveau propriétaire
-    if to_activity.last_action_at > 0 {
-        let elapsed = clock.unix_timestamp - to_activity.last_action_at;
-        require!(elapsed >= COOLDOWN_PERIOD, ErrorCode::CooldownNotElapsed);
+    msg!("Transfert propose: {}", certificate.serial_number);
+    msg!("De: {}", pending_transfer.from);
+    if pending_transfer.initiator != pending_transfer.from {
+        msg!("Propose par le delegue: {}", pending_transfer.initiator);
     }
-
-    // Vérifier la limite de possession du nouveau propriétaire
-    require!(
-        to_activity.certificate_count < MAX_CERTIFICATES,
-        ErrorCode::MaxCertificatesReached
-    );
-
-    // Sauvegarder l'ancien propriétaire dans l'historique
-    let old_owner = certificate.owner;
-    if certificate.previous_owners.len() < Certificate::MAX_PREVIOUS_OWNERS {
-        certificate.previous_owners.push(old_owner);
+    msg!("Vers: {} (en attente d'acceptation)", pending_transfer.to);
+    if certificate.requires_transfer_approval() {
+        msg!("Approbation du certificateur emetteur requise");
     }
 
-    // Effectuer le transfert
-    certificate.owner = ctx.accounts.to.key();
-    certificate.last_transfer_at = clock.unix_timestamp;
-    certificate.locked_until = clock.unix_timestamp + LOCK_PERIOD;
-
-    // Mettre à jour les compteurs
-    from_activity.certificate_count = from_activity.certificate_count.saturating_sub(1);
-    from_activity.last_action_at = clock.unix_timestamp;
-
-    to_activity.certificate_count += 1;
-    to_activity.last_action_at = clock.unix_timestamp;
-
-    msg!("Certificat transfere: {}", certificate.serial_number);
-    msg!("De: {}", old_owner);
-    msg!("Vers: {}", ctx.accounts.to.key());
-    msg!("Verrouille jusqu'a: {}", certificate.locked_until);
-
     Ok(())
 }
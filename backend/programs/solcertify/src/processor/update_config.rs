@@ -0,0 +1,49 @@
+// Processor: Update Config
+//
+// Met à jour les paramètres du protocole (frais, répartition, délais).
+// La modification est soumise au préavis : elle applique une configuration mise en
+// file d'attente via queue_change, par n'importe quel signataire une fois le délai écoulé.
+
+use crate::events::ChangeExecuted;
+use crate::state::{ProtocolConfig, TimelockedChange};
+use crate::UpdateConfig;
+use anchor_lang::prelude::*;
+
+/// Handler pour mettre à jour la configuration du protocole
+pub fn handler(ctx: Context<UpdateConfig>, config: ProtocolConfig) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Consommer la modification mise en file d'attente (préavis écoulé)
+    let queued_change = &mut ctx.accounts.queued_change;
+    queued_change.execute(
+        &TimelockedChange::UpdateConfig {
+            config: config.clone(),
+        },
+        clock.unix_timestamp,
+    )?;
+
+    emit!(ChangeExecuted {
+        id: queued_change.id,
+        executor: ctx.accounts.executor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    let authority = &mut ctx.accounts.authority;
+    authority.config = config;
+
+    msg!(
+        "Frais: {} / {} / {} / {} lamports",
+        authority.config.standard_fee,
+        authority.config.premium_fee,
+        authority.config.luxury_fee,
+        authority.config.exceptional_fee
+    );
+    msg!("Part certificateur: {}%", authority.config.certifier_share_percent);
+    msg!(
+        "Verrouillage: {}s, cooldown: {}s",
+        authority.config.lock_period,
+        authority.config.cooldown_period
+    );
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+// Processor: Update Council
+//
+// Modifie la composition du conseil (membres et seuil).
+// Nécessite une proposition approuvée par le conseil actuel. Les propositions
+// en cours sont invalidées : elles devront être reproposées au nouveau conseil.
+
+use crate::state::{AdminAction, AdminCouncil};
+use crate::UpdateCouncil;
+use anchor_lang::prelude::*;
+
+/// Handler pour modifier le conseil d'administration
+pub fn handler(ctx: Context<UpdateCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    let clock = Clock::get()?;
+
+    AdminCouncil::validate_members(&members, threshold)?;
+
+    // Autoriser l'opération (proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_admin(
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::UpdateCouncil {
+            members: members.clone(),
+            threshold,
+        },
+        clock.unix_timestamp,
+    )?;
+
+    let council = &mut ctx.accounts.council;
+    council.members = members;
+    council.threshold = threshold;
+
+    let authority = &mut ctx.accounts.authority;
+    authority.council_epoch = authority
+        .council_epoch
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    msg!("Conseil mis a jour: {} membres", council.members.len());
+    msg!("Seuil d'approbation: {}", council.threshold);
+    msg!("Propositions anterieures invalidees (epoque {})", authority.council_epoch);
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+// Processor: Update Metadata
+//
+// Le certificateur émetteur (ou un délégué habilité à émettre) publie une
+// nouvelle version des métadonnées du certificat. La version courante est
+// archivée dans un compte MetadataVersion avant d'être remplacée, de sorte que
+// toutes les versions restent vérifiables par leur empreinte. Si un jeton NFT
+// a été émis, l'URI de ses métadonnées Token-2022 est mise à jour en même temps.
+
+use crate::errors::ErrorCode;
+use crate::events::MetadataUpdated;
+use crate::processor::certificate_nft;
+use crate::state::{Certificate, CertifierDelegate, DelegateAction};
+use crate::UpdateMetadata;
+use anchor_lang::prelude::*;
+
+/// Handler pour publier une nouvelle version des métadonnées
+pub fn handler(
+    ctx: Context<UpdateMetadata>,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let certifier_key = ctx.accounts.certifier.key();
+
+    // Le certificateur émetteur doit toujours être agréé et accrédité
+    require!(
+        ctx.accounts.certifier_profile.is_approved(),
+        ErrorCode::UnauthorizedCertifier
+    );
+    require!(
+        ctx.accounts.certifier_profile.is_accredited(clock.unix_timestamp),
+        ErrorCode::AccreditationExpired
+    );
+
+    // Le signataire doit être le certificateur ou un délégué habilité
+    CertifierDelegate::authorize(
+        &ctx.accounts.operator.key(),
+        &certifier_key,
+        ctx.accounts.delegate.as_deref(),
+        DelegateAction::Issue,
+        clock.unix_timestamp,
+    )?;
+
+    require!(
+        metadata_uri.len() <= Certificate::MAX_URI_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    let certificate = &mut ctx.accounts.certificate;
+    certificate.require_active()?;
+
+    // Archiver la version courante
+    let archive = &mut ctx.accounts.metadata_version;
+    archive.certificate = certificate.key();
+    archive.version = certificate.metadata_version;
+    archive.metadata_uri = certificate.metadata_uri.clone();
+    archive.metadata_hash = certificate.metadata_hash;
+    archive.published_at = certificate.metadata_updated_at;
+    archive.superseded_at = clock.unix_timestamp;
+    archive.superseded_by = ctx.accounts.operator.key();
+    archive.bump = ctx.bumps.metadata_version;
+
+    // Publier la nouvelle version
+    certificate.metadata_uri = metadata_uri;
+    certificate.metadata_hash = metadata_hash;
+    certificate.metadata_version = certificate
+        .metadata_version
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    certificate.metadata_updated_at = clock.unix_timestamp;
+
+    certificate_nft::update_certificate_nft_uri(
+        certificate,
+        &ctx.accounts.authority,
+        ctx.accounts.nft_mint.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.operator.to_account_info(),
+        &ctx.accounts.system_program,
+    )?;
+
+    emit!(MetadataUpdated {
+        certificate: certificate.key(),
+        version: certificate.metadata_version,
+        metadata_uri: certificate.metadata_uri.clone(),
+        metadata_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Metadonnees mises a jour: {}", certificate.serial_number);
+    msg!("Version: {}", certificate.metadata_version);
+    msg!("URI: {}", certificate.metadata_uri);
+
+    Ok(())
+}
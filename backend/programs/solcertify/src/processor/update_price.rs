@@ -0,0 +1,30 @@
+// Processor: Update Price
+//
+// Publication du cours SOL/EUR par l'oracle autorisé. Le cours sert à convertir
+// en lamports les frais exprimés en centimes d'euro.
+
+use crate::errors::ErrorCode;
+use crate::events::PriceUpdated;
+use crate::UpdatePrice;
+use anchor_lang::prelude::*;
+
+/// Handler pour publier le cours SOL/EUR
+pub fn handler(ctx: Context<UpdatePrice>, sol_price_eur_cents: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(sol_price_eur_cents > 0, ErrorCode::InvalidPriceFeed);
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.sol_price_eur_cents = sol_price_eur_cents;
+    price_feed.updated_at = clock.unix_timestamp;
+
+    emit!(PriceUpdated {
+        oracle: ctx.accounts.oracle.key(),
+        sol_price_eur_cents,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Cours SOL/EUR: {} centimes", sol_price_eur_cents);
+
+    Ok(())
+}
@@ -23,6 +23,9 @@ pub fn handler(ctx: Context<VerifyCertificate>) -> Result<CertificateInfo> {
     // Déterminer si le certificat est actuellement verrouillé
     let is_locked = clock.unix_timestamp < certificate.locked_until;
 
+    // Une authentification expirée n'affecte pas la propriété
+    let is_expired = certificate.is_expired(clock.unix_timestamp);
+
     let info = CertificateInfo {
         serial_number: certificate.serial_number.clone(),
         brand: certificate.brand.clone(),
@@ -31,12 +34,28 @@ pub fn handler(ctx: Context<VerifyCertificate>) -> Result<CertificateInfo> {
         estimated_value: certificate.estimated_value,
         owner: certificate.owner,
         certifier: certificate.certifier,
+        acting_delegate: certificate.acting_delegate,
         metadata_uri: certificate.metadata_uri.clone(),
+        metadata_hash: certificate.metadata_hash,
+        metadata_version: certificate.metadata_version,
         created_at: certificate.created_at,
         last_transfer_at: certificate.last_transfer_at,
         locked_until: certificate.locked_until,
         is_locked,
-        previous_owners_count: certificate.previous_owners.len() as u8,
+        transfer_count: certificate.transfer_count,
+        last_sale_price: certificate.last_sale_price,
+        total_royalties_paid: certificate.total_royalties_paid,
+        status: certificate.status,
+        retirement_reason: certificate.retirement_reason,
+        retired_at: certificate.retired_at,
+        service_count: certificate.service_count,
+        last_serviced_at: certificate.last_serviced_at,
+        appraisal_count: certificate.appraisal_count,
+        transfer_policy: certificate.transfer_policy,
+        valid_until: certificate.valid_until,
+        is_expired,
+        last_certified_at: certificate.last_certified_at,
+        last_certified_by: certificate.last_certified_by,
         total_certificates_issued: authority.total_issued,
     };
 
@@ -48,6 +67,16 @@ pub fn handler(ctx: Context<VerifyCertificate>) -> Result<CertificateInfo> {
     );
     msg!("Proprietaire actuel: {}", certificate.owner);
     msg!("Verrouille: {}", is_locked);
+    msg!("Statut: {:?}", certificate.status);
+    msg!("Transferabilite: {:?}", certificate.transfer_policy);
+    if is_expired {
+        msg!("Authentification expiree depuis: {}", certificate.valid_until);
+    }
+    msg!(
+        "Entretiens: {} (dernier: {})",
+        certificate.service_count,
+        certificate.last_serviced_at
+    );
 
     Ok(info)
 }
@@ -0,0 +1,25 @@
+// Processor: Withdraw Earnings
+//
+// Retire les gains accumulés du coffre d'un certificateur vers son adresse de paiement.
+
+use crate::WithdrawEarnings;
+use anchor_lang::prelude::*;
+
+/// Handler pour retirer des gains
+pub fn handler(ctx: Context<WithdrawEarnings>, amount: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.certifier_vault;
+
+    // Vérifier le blocage admin et le solde disponible
+    vault.debit(amount)?;
+
+    // Transférer depuis le coffre PDA (le programme en est propriétaire)
+    **vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.payout.try_borrow_mut_lamports()? += amount;
+
+    msg!("Gains retires: {} lamports", amount);
+    msg!("Vers: {}", ctx.accounts.payout.key());
+    msg!("Gains cumules: {} lamports", vault.lifetime_earnings);
+    msg!("Restant disponible: {} lamports", vault.available());
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+// Processor: Withdraw Token Earnings
+//
+// Retire les gains en jetons SPL (frais réglés en stablecoins) du compte de
+// jeton du coffre d'un certificateur vers son adresse de paiement.
+
+use crate::errors::ErrorCode;
+use crate::processor::token_fees;
+use crate::WithdrawTokenEarnings;
+use anchor_lang::prelude::*;
+
+/// Handler pour retirer des gains en jetons
+pub fn handler(ctx: Context<WithdrawTokenEarnings>, amount: u64) -> Result<()> {
+    let vault = &ctx.accounts.certifier_vault;
+
+    // Le blocage admin s'applique aussi aux gains en jetons
+    require!(!vault.payouts_held, ErrorCode::PayoutsHeld);
+    require!(
+        amount <= ctx.accounts.vault_token_account.amount,
+        ErrorCode::InsufficientEarnings
+    );
+
+    let certifier_key = ctx.accounts.certifier.key();
+    let bump = [vault.bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"certifier_vault", certifier_key.as_ref(), &bump]];
+    token_fees::transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.payment_mint,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.payout_token_account,
+        vault.to_account_info(),
+        signer_seeds,
+        amount,
+    )?;
+
+    msg!(
+        "Gains retires: {} jetons ({})",
+        amount,
+        ctx.accounts.payment_mint.key()
+    );
+    msg!("Vers: {}", ctx.accounts.payout_token_account.key());
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+// Processor: Withdraw Treasury
+//
+// Retire des fonds du coffre de la plateforme vers son adresse de paiement.
+
+use crate::errors::ErrorCode;
+use crate::state::AdminAction;
+use crate::events::TreasuryWithdrawal;
+use crate::WithdrawTreasury;
+use anchor_lang::prelude::*;
+
+/// Handler pour retirer des fonds de la trésorerie
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Autoriser l'opération (admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_admin(
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::WithdrawTreasury {
+            amount,
+            destination: ctx.accounts.destination.key(),
+        },
+        clock.unix_timestamp,
+    )?;
+
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+
+    // Le coffre doit rester exempté de loyer
+    let rent_exempt = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let available = treasury_info.lamports().saturating_sub(rent_exempt);
+    require!(amount <= available, ErrorCode::InsufficientTreasuryBalance);
+
+    // Transférer depuis le coffre PDA (le programme en est propriétaire)
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.destination.try_borrow_mut_lamports()? += amount;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.record_withdrawal(amount)?;
+
+    emit!(TreasuryWithdrawal {
+        admin: ctx.accounts.admin.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        total_withdrawn: treasury.total_withdrawn,
+        remaining_balance: available - amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Retrait tresorerie: {} lamports", amount);
+    msg!("Vers: {}", ctx.accounts.destination.key());
+
+    Ok(())
+}
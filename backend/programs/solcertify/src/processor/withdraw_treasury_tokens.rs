@@ -0,0 +1,53 @@
+// Processor: Withdraw Treasury Tokens
+//
+// Retire des jetons SPL (frais réglés en stablecoins) du compte de jeton de la
+// trésorerie vers un compte détenu par l'adresse de paiement de la plateforme.
+
+use crate::errors::ErrorCode;
+use crate::processor::token_fees;
+use crate::state::AdminAction;
+use crate::WithdrawTreasuryTokens;
+use anchor_lang::prelude::*;
+
+/// Handler pour retirer des jetons de la trésorerie
+pub fn handler(ctx: Context<WithdrawTreasuryTokens>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Autoriser l'opération (admin seul, ou proposition approuvée du conseil)
+    ctx.accounts.authority.authorize_admin(
+        &ctx.accounts.admin.key(),
+        ctx.accounts.proposal.as_deref_mut(),
+        &AdminAction::WithdrawTreasuryTokens {
+            mint: ctx.accounts.payment_mint.key(),
+            amount,
+            destination: ctx.accounts.destination.key(),
+        },
+        clock.unix_timestamp,
+    )?;
+
+    require!(
+        amount <= ctx.accounts.treasury_token_account.amount,
+        ErrorCode::InsufficientTreasuryBalance
+    );
+
+    let bump = [ctx.accounts.treasury.bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"treasury", &bump]];
+    token_fees::transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.payment_mint,
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.destination,
+        ctx.accounts.treasury.to_account_info(),
+        signer_seeds,
+        amount,
+    )?;
+
+    msg!(
+        "Retrait tresorerie: {} jetons ({})",
+        amount,
+        ctx.accounts.payment_mint.key()
+    );
+    msg!("Vers: {}", ctx.accounts.destination.key());
+
+    Ok(())
+}
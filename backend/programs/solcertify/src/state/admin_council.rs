@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{OperatorRoles, TimelockedChange};
+
+/// Opération sensible soumise à l'approbation du conseil d'administration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AdminAction {
+    QueueChange { change: TimelockedChange },
+    CancelChange { id: u64 },
+    RemoveCertifier { certifier: Pubkey },
+    RenewCertifier { certifier: Pubkey },
+    SetPayoutsHeld { certifier: Pubkey, held: bool },
+    WithdrawTreasury { amount: u64, destination: Pubkey },
+    WithdrawTreasuryTokens { mint: Pubkey, amount: u64, destination: Pubkey },
+    SetTreasury { payout_address: Pubkey },
+    SetPriceOracle { oracle: Pubkey, max_staleness: i64 },
+    SetOperatorRoles { operator: Pubkey, roles: OperatorRoles },
+    SetPaused { paused: bool },
+    CorrectOwner { certificate: Pubkey, new_owner: Pubkey },
+    ConfirmRetirement { certificate: Pubkey, approved: bool },
+    UpdateCouncil { members: Vec<Pubkey>, threshold: u8 },
+}
+
+impl AdminAction {
+    // Taille de la plus grande variante (UpdateCouncil) : 1 (tag) + (4+32*10) + 1
+    // QueueChange (1 + TimelockedChange::SPACE) est plus petite
+    pub const SPACE: usize = 1 + 4 + 32 * AdminCouncil::MAX_MEMBERS + 1;
+}
+
+/// Conseil d'administration M-sur-N (singleton)
+/// Une fois activé, les opérations sensibles exigent une proposition approuvée
+#[account]
+pub struct AdminCouncil {
+    /// Membres du conseil
+    pub members: Vec<Pubkey>,
+    /// Nombre d'approbations requises
+    pub threshold: u8,
+    /// Nombre de propositions créées (sert d'identifiant)
+    pub proposal_count: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AdminCouncil {
+    pub const MAX_MEMBERS: usize = 10;
+
+    pub const SPACE: usize = 8 +       // discriminator
+        4 + 32 * Self::MAX_MEMBERS +    // members
+        1 +                             // threshold
+        8 +                             // proposal_count
+        1;                              // bump
+
+    /// Vérifie si une clé est membre du conseil
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+
+    /// Vérifie la composition du conseil (membres uniques, seuil atteignable)
+    pub fn validate_members(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= Self::MAX_MEMBERS,
+            ErrorCode::InvalidCouncil
+        );
+        require!(
+            threshold >= 1 && threshold as usize <= members.len(),
+            ErrorCode::InvalidCouncil
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(!members[..i].contains(member), ErrorCode::InvalidCouncil);
+        }
+        Ok(())
+    }
+}
+
+/// Proposition d'opération sensible en attente d'approbations
+#[account]
+pub struct AdminProposal {
+    /// Identifiant (index dans le conseil)
+    pub id: u64,
+    /// Membre ayant créé la proposition
+    pub proposer: Pubkey,
+    /// Composition du conseil au moment de la proposition (voir council_epoch)
+    pub council_epoch: u64,
+    /// Opération proposée
+    pub action: AdminAction,
+    /// Membres ayant approuvé
+    pub approvals: Vec<Pubkey>,
+    /// Seuil atteint
+    pub approved: bool,
+    /// Déjà exécutée
+    pub executed: bool,
+    /// Date de création
+    pub created_at: i64,
+    /// Date d'exécution
+    pub executed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AdminProposal {
+    pub const SPACE: usize = 8 +       // discriminator
+        8 +                             // id
+        32 +                            // proposer
+        8 +                             // council_epoch
+        AdminAction::SPACE +            // action
+        4 + 32 * AdminCouncil::MAX_MEMBERS + // approvals
+        1 +                             // approved
+        1 +                             // executed
+        8 +                             // created_at
+        8 +                             // executed_at
+        1;                              // bump
+
+    /// Vérifie que le conseil n'a pas changé depuis la proposition : les
+    /// approbations recueillies ne valent que pour les membres et le seuil d'alors
+    pub fn require_current(&self, council_epoch: u64) -> Result<()> {
+        require!(self.council_epoch == council_epoch, ErrorCode::ProposalStale);
+        Ok(())
+    }
+
+    /// Ajoute l'approbation d'un membre et met à jour le statut
+    pub fn approve(&mut self, member: Pubkey, threshold: u8) -> Result<()> {
+        require!(!self.executed, ErrorCode::ProposalAlreadyExecuted);
+        require!(!self.approvals.contains(&member), ErrorCode::AlreadyApproved);
+        self.approvals.push(member);
+        self.approved = self.approvals.len() >= threshold as usize;
+        Ok(())
+    }
+
+    /// Consomme la proposition pour exécuter l'opération attendue
+    pub fn execute(
+        &mut self,
+        action: &AdminAction,
+        council_epoch: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        require!(!self.executed, ErrorCode::ProposalAlreadyExecuted);
+        self.require_current(council_epoch)?;
+        require!(self.approved, ErrorCode::ProposalNotApproved);
+        require!(self.action == *action, ErrorCode::ProposalActionMismatch);
+        self.executed = true;
+        self.executed_at = current_time;
+        Ok(())
+    }
+}
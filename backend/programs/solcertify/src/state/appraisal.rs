@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::CertificationType;
+
+/// Demande de réévaluation d'un certificat par son propriétaire
+/// Une seule demande à la fois par certificat. Le compte séquestre le dépôt
+/// destiné à couvrir la différence de frais si le type monte en gamme ; il est
+/// fermé (solde restitué au propriétaire) à la réévaluation ou à l'annulation.
+#[account]
+pub struct AppraisalRequest {
+    /// Certificat à réévaluer
+    pub certificate: Pubkey,
+    /// Propriétaire demandeur
+    pub owner: Pubkey,
+    /// Certificateur agréé sollicité
+    pub certifier: Pubkey,
+    /// Dépôt maximal pour la différence de frais (lamports)
+    pub max_fee_difference: u64,
+    /// Date de la demande
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AppraisalRequest {
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // certificate
+        32 +                            // owner
+        32 +                            // certifier
+        8 +                             // max_fee_difference
+        8 +                             // created_at
+        1;                              // bump
+}
+
+/// Entrée de l'historique des réévaluations d'un certificat
+/// La n-ième réévaluation d'un certificat est stockée à l'index n
+#[account]
+pub struct AppraisalRecord {
+    /// Certificat concerné
+    pub certificate: Pubkey,
+    /// Index de la réévaluation pour ce certificat
+    pub index: u32,
+    /// Certificateur ayant signé la nouvelle valeur
+    pub certifier: Pubkey,
+    /// Délégué ayant agi pour le certificateur (le cas échéant)
+    pub acting_delegate: Option<Pubkey>,
+    /// Valeur estimée avant réévaluation (EUR)
+    pub previous_value: u64,
+    /// Nouvelle valeur estimée (EUR)
+    pub new_value: u64,
+    /// Type de certification avant réévaluation
+    pub previous_type: CertificationType,
+    /// Nouveau type de certification
+    pub new_type: CertificationType,
+    /// Différence de frais payée par le propriétaire (lamports)
+    pub fee_paid: u64,
+    /// Date de la réévaluation
+    pub appraised_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AppraisalRecord {
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // certificate
+        4 +                             // index
+        32 +                            // certifier
+        1 + 32 +                        // acting_delegate Option<Pubkey>
+        8 +                             // previous_value
+        8 +                             // new_value
+        1 +                             // previous_type enum
+        1 +                             // new_type enum
+        8 +                             // fee_paid
+        8 +                             // appraised_at
+        1;                              // bump
+}
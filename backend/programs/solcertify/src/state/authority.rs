@@ -1,31 +1,85 @@
 use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{AdminAction, AdminProposal, ProtocolConfig};
 
 #[account]
 #[derive(InitSpace)]
 pub struct CertificationAuthority {
     pub admin: Pubkey, // Administrateur du système
-    #[max_len(50)]
-    pub approved_certifiers: Vec<Pubkey>, // Liste des certificateurs agréés (max 50)
+    pub certifier_count: u32, // Nombre de certificateurs agréés actifs (profils actifs)
     pub total_issued: u64, // Nombre total de certificats émis
     pub standard_count: u64, // Compteur de certificats Standard
     pub premium_count: u64, // Compteur de certificats Premium
     pub luxury_count: u64, // Compteur de certificats Luxury
     pub exceptional_count: u64, // Compteur de certificats Exceptional
-    pub treasury: Pubkey, // Compte de trésorerie pour les frais
+    pub treasury: Pubkey, // Coffre de trésorerie (PDA TreasuryVault) pour les frais
+    pub config: ProtocolConfig, // Paramètres du protocole (frais, répartition, délais)
+    pub council_enabled: bool, // Conseil M-sur-N actif : l'admin seul ne suffit plus
+    pub council_epoch: u64, // Version de la composition du conseil (invalide les propositions antérieures)
+    pub queued_change_count: u64, // Nombre de modifications mises en file d'attente (identifiants)
+    pub paused: bool, // Protocole suspendu (émissions, demandes et transferts bloqués)
     pub bump: u8,      // Bump seed du PDA
 }
 
 impl CertificationAuthority {
-    // Maximum de certificateurs agréés
-    pub const MAX_CERTIFIERS: usize = 50;
-
     // Taille du compte (discriminator + fields)
-    // 8 + 32 + (4+32*50) + 8*5 + 32 + 1
-    pub const SPACE: usize = 8 + 32 + 4 + (32 * 50) + 8 * 5 + 32 + 1;
+    // 8 + 32 + 4 + 8*5 + 32 + config + 1 + 8 + 8 + 1 + 1
+    pub const SPACE: usize = 8 + 32 + 4 + 8 * 5 + 32 + ProtocolConfig::SPACE + 1 + 8 + 8 + 1 + 1;
+
+    // Autorise une opération sensible :
+    // - sans conseil, le signataire doit être l'admin
+    // - avec conseil, une proposition approuvée correspondant à l'opération est consommée,
+    //   à condition d'avoir été créée sous la composition actuelle du conseil
+    pub fn authorize_admin(
+        &self,
+        signer: &Pubkey,
+        proposal: Option<&mut AdminProposal>,
+        action: &AdminAction,
+        current_time: i64,
+    ) -> Result<()> {
+        if !self.council_enabled {
+            require!(*signer == self.admin, ErrorCode::UnauthorizedCertifier);
+            return Ok(());
+        }
+        let proposal = proposal.ok_or(ErrorCode::ProposalRequired)?;
+        proposal.execute(action, self.council_epoch, current_time)
+    }
+
+    // Autorise une opération déléguable à un opérateur :
+    // - sans conseil, le rôle de l'opérateur suffit ; il est vérifié par les contraintes
+    //   du compte `operator_role`, seule sa présence est transmise ici (`has_role`)
+    // - avec conseil, le rôle ne suffit plus : comme pour `authorize_admin`, une
+    //   proposition approuvée correspondant à l'opération est consommée, afin qu'aucun
+    //   opérateur ne contourne le seuil M-sur-N
+    // - en l'absence de rôle, la règle de `authorize_admin` s'applique
+    pub fn authorize_operator_or_admin(
+        &self,
+        has_role: bool,
+        signer: &Pubkey,
+        proposal: Option<&mut AdminProposal>,
+        action: &AdminAction,
+        current_time: i64,
+    ) -> Result<()> {
+        if has_role && !self.council_enabled {
+            return Ok(());
+        }
+        self.authorize_admin(signer, proposal, action, current_time)
+    }
+
+    // L'agrément d'un certificateur est porté par son CertifierProfile (existence + is_active)
+
+    // Incrémente le nombre de certificateurs actifs
+    pub fn add_certifier(&mut self) -> Result<()> {
+        self.certifier_count = self
+            .certifier_count
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
 
-    // Vérifie si un certificateur est agréé
-    pub fn is_approved_certifier(&self, certifier: &Pubkey) -> bool {
-        self.approved_certifiers.contains(certifier)
+    // Décrémente le nombre de certificateurs actifs
+    pub fn remove_certifier(&mut self) {
+        self.certifier_count = self.certifier_count.saturating_sub(1);
     }
 
     // Incrémente le compteur approprié selon le type
@@ -34,32 +88,38 @@ impl CertificationAuthority {
             .total_issued
             .checked_add(1)
             .ok_or(ProgramError::ArithmeticOverflow)?;
-        match cert_type {
-            crate::state::CertificationType::Standard => {
-                self.standard_count = self
-                    .standard_count
-                    .checked_add(1)
-                    .ok_or(ProgramError::ArithmeticOverflow)?;
-            }
-            crate::state::CertificationType::Premium => {
-                self.premium_count = self
-                    .premium_count
-                    .checked_add(1)
-                    .ok_or(ProgramError::ArithmeticOverflow)?;
-            }
-            crate::state::CertificationType::Luxury => {
-                self.luxury_count = self
-                    .luxury_count
-                    .checked_add(1)
-                    .ok_or(ProgramError::ArithmeticOverflow)?;
-            }
-            crate::state::CertificationType::Exceptional => {
-                self.exceptional_count = self
-                    .exceptional_count
-                    .checked_add(1)
-                    .ok_or(ProgramError::ArithmeticOverflow)?;
-            }
+        let count = self.type_count_mut(cert_type);
+        *count = count
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    // Reclasse un certificat d'un type à un autre (réévaluation), sans changer le total émis
+    pub fn reclassify(
+        &mut self,
+        from: &crate::state::CertificationType,
+        to: &crate::state::CertificationType,
+    ) -> Result<()> {
+        if from == to {
+            return Ok(());
         }
+        let old_count = self.type_count_mut(from);
+        *old_count = old_count.saturating_sub(1);
+        let new_count = self.type_count_mut(to);
+        *new_count = new_count
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
         Ok(())
     }
+
+    // Compteur de certificats correspondant au type
+    fn type_count_mut(&mut self, cert_type: &crate::state::CertificationType) -> &mut u64 {
+        match cert_type {
+            crate::state::CertificationType::Standard => &mut self.standard_count,
+            crate::state::CertificationType::Premium => &mut self.premium_count,
+            crate::state::CertificationType::Luxury => &mut self.luxury_count,
+            crate::state::CertificationType::Exceptional => &mut self.exceptional_count,
+        }
+    }
 }
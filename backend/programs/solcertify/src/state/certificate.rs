@@ -1,4 +1,8 @@
-use crate::state::CertificationType;
+use crate::errors::ErrorCode;
+use crate::state::{
+    CertificateStatus, CertificationType, ProvenanceEntry, ProvenancePage, RetirementReason,
+    TransferKind, TransferPolicy,
+};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -14,13 +18,31 @@ pub struct Certificate {
     pub estimated_value: u64,         // Valeur estimée en EUR
     #[max_len(100)]
     pub metadata_uri: String, // URI des métadonnées IPFS (max 100 chars)
+    pub metadata_hash: [u8; 32],      // Empreinte SHA-256 du JSON de métadonnées courant
+    pub metadata_version: u32,        // Version courante des métadonnées (0 = émission)
+    pub metadata_updated_at: i64,     // Date de publication de la version courante
     pub owner: Pubkey,                // Propriétaire actuel
     pub certifier: Pubkey,            // Certificateur qui a émis le certificat
+    pub acting_delegate: Option<Pubkey>, // Délégué ayant agi pour le certificateur (le cas échéant)
+    pub transfer_delegate: Option<Pubkey>, // Délégué autorisé à transférer pour le propriétaire (négociant, maison de ventes)
+    pub transfer_delegate_expires_at: i64, // Expiration de la délégation de transfert (0 = sans expiration)
     pub created_at: i64,              // Timestamp de création
     pub last_transfer_at: i64,        // Timestamp du dernier transfert
     pub locked_until: i64,            // Timestamp jusqu'au verrouillage
-    #[max_len(20)]
-    pub previous_owners: Vec<Pubkey>, // Historique des propriétaires (max 20)
+    pub valid_until: i64,             // Fin de validité de l'authentification (0 = sans expiration)
+    pub last_certified_at: i64,       // Date de la dernière authentification (émission ou recertification)
+    pub last_certified_by: Pubkey,    // Certificateur de la dernière authentification
+    pub transfer_count: u32,          // Nombre de transferts (entrées dans les pages de provenance)
+    pub last_sale_price: u64,         // Prix de la dernière vente via le programme (lamports)
+    pub total_royalties_paid: u64,    // Redevances cumulées versées (certificateur + plateforme)
+    pub status: CertificateStatus,    // Cycle de vie (actif, retrait en attente, retiré)
+    pub retirement_reason: Option<RetirementReason>, // Motif du retrait (le cas échéant)
+    pub retired_at: i64,              // Timestamp du retrait définitif (0 = non retiré)
+    pub service_count: u32,           // Nombre d'entretiens enregistrés (comptes ServiceRecord)
+    pub last_serviced_at: i64,        // Date du dernier entretien (0 = aucun)
+    pub appraisal_count: u32,         // Nombre de réévaluations (comptes AppraisalRecord)
+    pub nft_mint: Option<Pubkey>,     // Mint Token-2022 représentant le certificat (optionnel)
+    pub transfer_policy: TransferPolicy, // Politique de transférabilité choisie à l'émission
     pub bump: u8,                     // Bump seed du PDA
 }
 
@@ -30,26 +52,133 @@ impl Certificate {
     pub const MAX_BRAND_LEN: usize = 30;
     pub const MAX_MODEL_LEN: usize = 50;
     pub const MAX_URI_LEN: usize = 100;
-    pub const MAX_PREVIOUS_OWNERS: usize = 20;
 
     // Taille du compte (discriminator + fields)
-    // 8 + (4+50) + (4+30) + (4+50) + 1 + 8 + (4+100) + 32 + 32 + 8 + 8 + 8 + (4+32*20) + 1
-    pub const SPACE: usize = 8 + 54 + 34 + 54 + 1 + 8 + 104 + 32 + 32 + 8 + 8 + 8 + 644 + 1;
+    // 8 + (4+50) + (4+30) + (4+50) + 1 + 8 + (4+100) + 32 + 4 + 8 + 32 + 32 + (1+32) + (1+32) + 8 + 8 + 8 + 8
+    //   + 8 + 8 + 32 + 4 + 8 + 8 + 1 + (1+1) + 8 + 4 + 8 + 4 + (1+32) + 1 + 1
+    pub const SPACE: usize = 8 + 54 + 34 + 54 + 1 + 8 + 104 + 32 + 4 + 8 + 32 + 32 + 33 + 33 + 8 + 8 + 8 + 8
+        + 8 + 8 + 32 + 4 + 8 + 8 + 1 + 2 + 8 + 4 + 8 + 4 + 33 + 1 + 1;
 
     // Vérifie si le certificat est actuellement verrouillé
     pub fn is_locked(&self, current_time: i64) -> bool {
         current_time < self.locked_until
     }
 
+    // Exige que le certificat soit en circulation (ni retiré, ni en cours de retrait)
+    pub fn require_active(&self) -> Result<()> {
+        require!(
+            self.status == CertificateStatus::Active,
+            ErrorCode::CertificateRetired
+        );
+        Ok(())
+    }
+
+    // Les retraits des gammes hautes doivent être confirmés par l'admin
+    pub fn retirement_requires_confirmation(&self) -> bool {
+        matches!(
+            self.cert_type,
+            CertificationType::Luxury | CertificationType::Exceptional
+        )
+    }
+
+    // Retire définitivement le certificat (état terminal, délégation levée)
+    pub fn retire(&mut self, current_time: i64) {
+        self.status = CertificateStatus::Retired;
+        self.retired_at = current_time;
+        self.transfer_delegate = None;
+        self.transfer_delegate_expires_at = 0;
+    }
+
+    // Enregistre un entretien (le dernier entretien est le plus récent en date)
+    pub fn record_service(&mut self, serviced_at: i64) -> Result<()> {
+        self.service_count = self
+            .service_count
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.last_serviced_at = self.last_serviced_at.max(serviced_at);
+        Ok(())
+    }
+
+    // Vérifie que la politique du certificat autorise les transferts
+    pub fn require_transferable(&self) -> Result<()> {
+        require!(
+            self.transfer_policy != TransferPolicy::Soulbound,
+            ErrorCode::CertificateSoulbound
+        );
+        Ok(())
+    }
+
+    // Vérifie si chaque transfert doit être approuvé par le certificateur émetteur
+    pub fn requires_transfer_approval(&self) -> bool {
+        self.transfer_policy == TransferPolicy::CertifierApproval
+    }
+
+    // Vérifie si l'authentification a expiré (la propriété n'est pas affectée)
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        self.valid_until != 0 && current_time >= self.valid_until
+    }
+
     // Vérifie si l'adresse est le propriétaire actuel
     pub fn is_owner(&self, address: &Pubkey) -> bool {
         self.owner == *address
     }
 
-    // Ajoute un propriétaire à l'historique
-    pub fn add_to_history(&mut self, previous_owner: Pubkey) {
-        if self.previous_owners.len() < 20 {
-            self.previous_owners.push(previous_owner);
+    // Vérifie que le signataire peut transférer : propriétaire ou délégué approuvé non expiré
+    pub fn authorize_transfer(&self, signer: &Pubkey, current_time: i64) -> Result<()> {
+        if self.is_owner(signer) {
+            return Ok(());
         }
+        require!(self.transfer_delegate == Some(*signer), ErrorCode::NotOwner);
+        require!(
+            self.transfer_delegate_expires_at == 0 || current_time < self.transfer_delegate_expires_at,
+            ErrorCode::DelegateExpired
+        );
+        Ok(())
+    }
+
+    // Index de la page de provenance qui recevra le prochain transfert
+    pub fn current_provenance_page(&self) -> u32 {
+        ProvenancePage::page_for(self.transfer_count)
+    }
+
+    // Enregistre une vente et les redevances versées
+    pub fn record_sale(&mut self, price: u64, royalties: u64) -> Result<()> {
+        self.last_sale_price = price;
+        self.total_royalties_paid = self
+            .total_royalties_paid
+            .checked_add(royalties)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    // Change de propriétaire (provenance, date de transfert, verrouillage)
+    // Le transfert est inscrit dans la page de provenance courante
+    // La délégation de transfert de l'ancien propriétaire est révoquée
+    // Retourne l'ancien propriétaire
+    pub fn transfer_ownership(
+        &mut self,
+        new_owner: Pubkey,
+        kind: TransferKind,
+        provenance: &mut ProvenancePage,
+        current_time: i64,
+        lock_period: i64,
+    ) -> Result<Pubkey> {
+        let old_owner = self.owner;
+        provenance.append(ProvenanceEntry {
+            previous_owner: old_owner,
+            new_owner,
+            transferred_at: current_time,
+            kind,
+        })?;
+        self.transfer_count = self
+            .transfer_count
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.owner = new_owner;
+        self.transfer_delegate = None;
+        self.transfer_delegate_expires_at = 0;
+        self.last_transfer_at = current_time;
+        self.locked_until = current_time + lock_period;
+        Ok(old_owner)
     }
 }
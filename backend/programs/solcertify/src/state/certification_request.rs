@@ -1,18 +1,15 @@
 use anchor_lang::prelude::*;
-use crate::state::CertificationType;
+use crate::state::{CertificationType, RejectionCode, TransferPolicy};
 
 /// Statut d'une demande de certification
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
 pub enum RequestStatus {
+    #[default]
     Pending,    // En attente d'examen
     Approved,   // Approuve - certificat emis
     Rejected,   // Rejete par le certificateur
-}
-
-impl Default for RequestStatus {
-    fn default() -> Self {
-        RequestStatus::Pending
-    }
+    AwaitingQuote, // Ouverte sans paiement, en attente du devis du certificateur
+    Quoted,     // Devis publie, en attente d'acceptation (paiement) par le demandeur
 }
 
 /// Demande de certification soumise par un utilisateur
@@ -32,18 +29,32 @@ pub struct CertificationRequest {
     pub estimated_value: u64,
     /// URI vers les photos/metadonnees IPFS
     pub metadata_uri: String,
+    /// Empreinte SHA-256 du JSON de métadonnées
+    pub metadata_hash: [u8; 32],
+    /// Politique de transférabilité demandée pour le certificat
+    pub transfer_policy: TransferPolicy,
     /// Statut de la demande
     pub status: RequestStatus,
     /// Certificateur assigne (optionnel)
     pub assigned_certifier: Option<Pubkey>,
     /// Raison du rejet (si rejete)
     pub rejection_reason: String,
+    /// Motif normalisé du rejet (si rejete)
+    pub rejection_code: Option<RejectionCode>,
+    /// Frais d'inspection retenus lors du rejet
+    pub inspection_fee: u64,
     /// Date de creation
     pub created_at: i64,
     /// Date de resolution (approval/rejection)
     pub resolved_at: i64,
     /// Frais payes par le demandeur
     pub fee_paid: u64,
+    /// Jeton SPL des frais (None = frais en SOL)
+    pub fee_mint: Option<Pubkey>,
+    /// Montant du devis du certificateur (lamports, 0 = pas de devis)
+    pub quote_amount: u64,
+    /// Échéance du devis
+    pub quote_expires_at: i64,
     /// PDA bump
     pub bump: u8,
 }
@@ -63,11 +74,23 @@ impl CertificationRequest {
         1 +                             // cert_type enum
         8 +                             // estimated_value
         4 + Self::MAX_URI_LENGTH +      // metadata_uri
+        32 +                            // metadata_hash
+        1 +                             // transfer_policy enum
         1 +                             // status enum
         1 + 32 +                        // assigned_certifier Option<Pubkey>
         4 + Self::MAX_REJECTION_REASON + // rejection_reason
+        1 + 1 +                         // rejection_code Option<enum>
+        8 +                             // inspection_fee
         8 +                             // created_at
         8 +                             // resolved_at
         8 +                             // fee_paid
+        1 + 32 +                        // fee_mint Option<Pubkey>
+        8 +                             // quote_amount
+        8 +                             // quote_expires_at
         1;                              // bump
+
+    /// Le demandeur peut encore annuler la demande (aucun paiement effectué)
+    pub fn is_unfunded(&self) -> bool {
+        matches!(self.status, RequestStatus::AwaitingQuote | RequestStatus::Quoted)
+    }
 }
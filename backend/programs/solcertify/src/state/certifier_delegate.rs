@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+/// Permissions accordées à un délégué d'un certificateur
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct DelegatePermissions {
+    pub approve: bool, // Peut approuver les demandes assignées
+    pub reject: bool,  // Peut rejeter les demandes assignées
+    pub issue: bool,   // Peut émettre des certificats en direct
+    pub service: bool, // Peut enregistrer des entretiens
+}
+
+/// Action effectuée par un délégué
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DelegateAction {
+    Approve,
+    Reject,
+    Issue,
+    Service,
+}
+
+/// Clé déléguée d'un certificateur (employé d'un laboratoire)
+/// Permet de traiter les demandes sans partager la clé principale
+#[account]
+pub struct CertifierDelegate {
+    /// Certificateur pour le compte duquel le délégué agit
+    pub certifier: Pubkey,
+    /// Clé du délégué
+    pub delegate: Pubkey,
+    /// Permissions accordées
+    pub permissions: DelegatePermissions,
+    /// Date d'expiration de la délégation
+    pub expires_at: i64,
+    /// Date de création
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CertifierDelegate {
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // certifier
+        32 +                            // delegate
+        4 +                             // permissions (4 bools)
+        8 +                             // expires_at
+        8 +                             // created_at
+        1;                              // bump
+
+    /// Vérifie si la délégation autorise l'action à l'instant donné
+    pub fn allows(&self, action: DelegateAction, current_time: i64) -> bool {
+        if current_time >= self.expires_at {
+            return false;
+        }
+        match action {
+            DelegateAction::Approve => self.permissions.approve,
+            DelegateAction::Reject => self.permissions.reject,
+            DelegateAction::Issue => self.permissions.issue,
+            DelegateAction::Service => self.permissions.service,
+        }
+    }
+
+    /// Vérifie que l'opérateur peut agir pour le certificateur
+    /// Retourne la clé du délégué si l'opérateur n'est pas le certificateur lui-même
+    pub fn authorize(
+        operator: &Pubkey,
+        certifier: &Pubkey,
+        delegate: Option<&CertifierDelegate>,
+        action: DelegateAction,
+        current_time: i64,
+    ) -> Result<Option<Pubkey>> {
+        if operator == certifier {
+            return Ok(None);
+        }
+
+        let delegate = delegate.ok_or(ErrorCode::DelegateNotAuthorized)?;
+        require!(
+            delegate.certifier == *certifier && delegate.delegate == *operator,
+            ErrorCode::DelegateNotAuthorized
+        );
+        require!(current_time < delegate.expires_at, ErrorCode::DelegateExpired);
+        require!(delegate.allows(action, current_time), ErrorCode::DelegateNotAuthorized);
+
+        Ok(Some(*operator))
+    }
+}
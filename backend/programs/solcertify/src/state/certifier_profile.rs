@@ -12,14 +12,33 @@ pub struct CertifierProfile {
     pub total_processed: u64,
     /// Temps de traitement cumulé (en secondes) pour calculer la moyenne
     pub total_processing_time: u64,
+    /// Nombre de demandes approuvées
+    pub total_approved: u64,
+    /// Nombre de demandes rejetées
+    pub total_rejected: u64,
+    /// Nombre de demandes annulées par le demandeur
+    pub total_cancelled: u64,
+    /// Nombre de demandes expirées sans traitement
+    pub total_expired: u64,
+    /// Nombre de certificats émis en direct (hors flux de demande)
+    pub total_direct_issued: u64,
+    /// Nombre de réévaluations effectuées
+    pub total_appraisals: u64,
+    /// Frais cumulés perçus en SOL par le certificateur (en lamports uniquement :
+    /// les frais payés en jetons SPL sont suivis par les comptes de jeton de son coffre)
+    pub total_lamports_earned: u64,
     /// Adresse physique / lieu de dépôt pour l'envoi des montres
     pub physical_address: String,
     /// Nom d'affichage du certificateur
     pub display_name: String,
     /// Actif ou non (peut être désactivé par l'admin)
     pub is_active: bool,
+    /// Nombre de clés déléguées actuellement enregistrées
+    pub delegate_count: u8,
     /// Date d'inscription
     pub registered_at: i64,
+    /// Fin de validité de l'accréditation (ré-accréditation périodique)
+    pub accredited_until: i64,
     /// PDA bump
     pub bump: u8,
 }
@@ -33,10 +52,19 @@ impl CertifierProfile {
         2 +                             // current_load u16
         8 +                             // total_processed u64
         8 +                             // total_processing_time u64
+        8 +                             // total_approved u64
+        8 +                             // total_rejected u64
+        8 +                             // total_cancelled u64
+        8 +                             // total_expired u64
+        8 +                             // total_direct_issued u64
+        8 +                             // total_appraisals u64
+        8 +                             // total_lamports_earned u64
         4 + Self::MAX_ADDRESS_LENGTH +  // physical_address String
         4 + Self::MAX_NAME_LENGTH +     // display_name String
         1 +                             // is_active bool
+        1 +                             // delegate_count u8
         8 +                             // registered_at i64
+        8 +                             // accredited_until i64
         1;                              // bump
 
     /// Calcule le délai moyen de traitement en secondes
@@ -52,6 +80,25 @@ impl CertifierProfile {
         self.is_active && self.current_load < max_concurrent
     }
 
+    /// Vérifie si le certificateur est agréé (profil existant et actif)
+    pub fn is_approved(&self) -> bool {
+        self.is_active
+    }
+
+    /// Vérifie si l'accréditation est toujours valide
+    pub fn is_accredited(&self, current_time: i64) -> bool {
+        current_time < self.accredited_until
+    }
+
+    /// Prolonge l'accréditation d'une période à partir de l'échéance (ou de maintenant si expirée)
+    pub fn renew_accreditation(&mut self, current_time: i64, period: i64) -> Result<()> {
+        let start = self.accredited_until.max(current_time);
+        self.accredited_until = start
+            .checked_add(period)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
     /// Incrémente la charge (nouvelle demande assignée)
     pub fn increment_load(&mut self) -> Result<()> {
         self.current_load = self
@@ -61,8 +108,93 @@ impl CertifierProfile {
         Ok(())
     }
 
-    /// Décrémente la charge et met à jour les stats (demande résolue)
-    pub fn resolve_request(&mut self, processing_time: u64) -> Result<()> {
+    /// Enregistre une nouvelle clé déléguée
+    pub fn add_delegate(&mut self, max_delegates: u8) -> Result<()> {
+        require!(
+            self.delegate_count < max_delegates,
+            crate::errors::ErrorCode::MaxDelegatesReached
+        );
+        self.delegate_count += 1;
+        Ok(())
+    }
+
+    /// Retire une clé déléguée
+    pub fn remove_delegate(&mut self) {
+        self.delegate_count = self.delegate_count.saturating_sub(1);
+    }
+
+    /// Enregistre une approbation et les frais perçus en lamports par le certificateur
+    pub fn record_approval(&mut self, processing_time: u64, lamports_earned: u64) -> Result<()> {
+        self.resolve_request(processing_time)?;
+        self.total_approved = self
+            .total_approved
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.total_lamports_earned = self
+            .total_lamports_earned
+            .checked_add(lamports_earned)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Enregistre un rejet et les frais d'inspection perçus en lamports par le certificateur
+    pub fn record_rejection(&mut self, processing_time: u64, lamports_earned: u64) -> Result<()> {
+        self.resolve_request(processing_time)?;
+        self.total_rejected = self
+            .total_rejected
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.total_lamports_earned = self
+            .total_lamports_earned
+            .checked_add(lamports_earned)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Enregistre une demande annulée par le demandeur (libère la charge)
+    pub fn record_cancellation(&mut self) -> Result<()> {
+        self.current_load = self.current_load.saturating_sub(1);
+        self.total_cancelled = self
+            .total_cancelled
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Enregistre une demande expirée sans traitement (libère la charge)
+    pub fn record_expiry(&mut self) -> Result<()> {
+        self.current_load = self.current_load.saturating_sub(1);
+        self.total_expired = self
+            .total_expired
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Enregistre un certificat émis en direct via issue_certificate
+    pub fn record_direct_issue(&mut self) -> Result<()> {
+        self.total_direct_issued = self
+            .total_direct_issued
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Enregistre une réévaluation et les frais perçus en lamports par le certificateur
+    pub fn record_appraisal(&mut self, lamports_earned: u64) -> Result<()> {
+        self.total_appraisals = self
+            .total_appraisals
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.total_lamports_earned = self
+            .total_lamports_earned
+            .checked_add(lamports_earned)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Décrémente la charge et met à jour les stats communes (demande résolue)
+    fn resolve_request(&mut self, processing_time: u64) -> Result<()> {
         self.current_load = self
             .current_load
             .saturating_sub(1);
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+/// Coffre des gains d'un certificateur
+/// Les parts de frais y sont accumulées puis retirées vers l'adresse de paiement
+#[account]
+pub struct CertifierVault {
+    /// Certificateur propriétaire du coffre
+    pub certifier: Pubkey,
+    /// Adresse de paiement configurée pour les retraits
+    pub payout_address: Pubkey,
+    /// Total des gains crédités depuis la création (en lamports)
+    pub lifetime_earnings: u64,
+    /// Total déjà retiré (en lamports)
+    pub total_withdrawn: u64,
+    /// Paiements bloqués par l'admin (litige en cours)
+    pub payouts_held: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CertifierVault {
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // certifier
+        32 +                            // payout_address
+        8 +                             // lifetime_earnings
+        8 +                             // total_withdrawn
+        1 +                             // payouts_held
+        1;                              // bump
+
+    /// Gains disponibles au retrait
+    pub fn available(&self) -> u64 {
+        self.lifetime_earnings.saturating_sub(self.total_withdrawn)
+    }
+
+    /// Crédite des gains dans le coffre
+    pub fn credit(&mut self, amount: u64) -> Result<()> {
+        self.lifetime_earnings = self
+            .lifetime_earnings
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Débite un retrait après vérification du blocage et du solde
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        require!(!self.payouts_held, ErrorCode::PayoutsHeld);
+        require!(amount <= self.available(), ErrorCode::InsufficientEarnings);
+        self.total_withdrawn = self
+            .total_withdrawn
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
@@ -2,19 +2,64 @@
 pub const COOLDOWN_PERIOD: i64 = 1; // 1 seconde pour le test
 pub const LOCK_PERIOD: i64 = 20; // 20 secondes pour le test
 
+// Répartition des frais (valeur initiale de la configuration)
+pub const CERTIFIER_SHARE_PERCENT: u8 = 60; // 60% certificateur, 40% plateforme
+
+// Frais d'inspection retenus sur un rejet après inspection (valeur initiale de la configuration)
+pub const INSPECTION_FEE_PERCENT: u8 = 20; // 20% des frais payés, remboursement du solde
+
+// Redevances sur les reventes (valeurs initiales de la configuration, en points de base)
+pub const CERTIFIER_ROYALTY_BPS: u16 = 200; // 2% au certificateur émetteur
+pub const PLATFORM_ROYALTY_BPS: u16 = 100; // 1% à la plateforme
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// Validité des certificats par gamme (valeurs initiales de la configuration, 0 = sans expiration)
+pub const STANDARD_VALIDITY_PERIOD: i64 = 0;
+pub const PREMIUM_VALIDITY_PERIOD: i64 = 5 * 365 * 24 * 60 * 60; // 5 ans
+pub const LUXURY_VALIDITY_PERIOD: i64 = 3 * 365 * 24 * 60 * 60; // 3 ans
+pub const EXCEPTIONAL_VALIDITY_PERIOD: i64 = 2 * 365 * 24 * 60 * 60; // 2 ans
+pub const RECERTIFICATION_FEE_PERCENT: u8 = 50; // 50% des frais de certification de la gamme
+
 // Limites de possession
 pub const MAX_CERTIFICATES: u8 = 4; // Maximum 4 certificats par utilisateur
 
-// Limites de taille
-pub const MAX_CERTIFIERS: usize = 50; // Maximum 50 certificateurs agréés
-pub const MAX_PREVIOUS_OWNERS: usize = 20; // Maximum 20 propriétaires dans l'historique
-
 // Limites de charge pour les certificateurs (Anti-Monopole)
 pub const MAX_CONCURRENT_REQUESTS: u16 = 10; // Maximum 10 demandes simultanées par certificateur
 
+// Devis des certificateurs (inspection sur mesure)
+pub const MAX_QUOTE_VALIDITY: i64 = 30 * 24 * 60 * 60; // Un devis reste valable 30 jours au plus
+
+// Accréditation des certificateurs
+pub const ACCREDITATION_PERIOD: i64 = 365 * 24 * 60 * 60; // Accréditation valable 1 an
+
+// Délégation des certificateurs
+pub const MAX_DELEGATES: u8 = 10; // Maximum 10 clés déléguées par certificateur
+
+// Préavis des modifications du protocole (timelock, valeur initiale de la configuration)
+pub const TIMELOCK_DELAY: i64 = 5; // 5 secondes pour le test (Prod: 48 heures)
+
 // Seeds pour les PDAs
 pub const AUTHORITY_SEED: &[u8] = b"authority";
 pub const CERTIFICATE_SEED: &[u8] = b"certificate";
 pub const USER_ACTIVITY_SEED: &[u8] = b"user_activity";
 pub const CERTIFIER_PROFILE_SEED: &[u8] = b"certifier_profile";
+pub const CERTIFIER_DELEGATE_SEED: &[u8] = b"certifier_delegate";
+pub const CERTIFIER_VAULT_SEED: &[u8] = b"certifier_vault";
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const ADMIN_COUNCIL_SEED: &[u8] = b"admin_council";
+pub const ADMIN_PROPOSAL_SEED: &[u8] = b"admin_proposal";
+pub const QUEUED_CHANGE_SEED: &[u8] = b"queued_change";
+pub const OPERATOR_ROLE_SEED: &[u8] = b"operator_role";
+pub const PENDING_TRANSFER_SEED: &[u8] = b"pending_transfer";
+pub const LISTING_SEED: &[u8] = b"listing";
+pub const PROVENANCE_SEED: &[u8] = b"provenance";
+pub const SERVICE_RECORD_SEED: &[u8] = b"service_record";
+pub const APPRAISAL_REQUEST_SEED: &[u8] = b"appraisal_request";
+pub const APPRAISAL_SEED: &[u8] = b"appraisal";
+pub const METADATA_VERSION_SEED: &[u8] = b"metadata_version";
+pub const CERTIFICATE_MINT_SEED: &[u8] = b"certificate_mint";
+pub const FEE_MINT_SEED: &[u8] = b"fee_mint";
+pub const PRICE_FEED_SEED: &[u8] = b"price_feed";
 
+// Jeton NFT des certificats (Token-2022, métadonnées intégrées au mint)
+pub const CERTIFICATE_NFT_SYMBOL: &str = "SOLCERT";
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::CertificationType;
+
+/// Jeton SPL accepté pour le paiement des frais (liste blanche, ex. USDC, EURC)
+/// Les frais sont exprimés en unités de base du jeton, par type de certification
+#[account]
+pub struct FeeMint {
+    /// Mint du jeton accepté
+    pub mint: Pubkey,
+    /// Frais Standard (unités de base du jeton)
+    pub standard_fee: u64,
+    /// Frais Premium
+    pub premium_fee: u64,
+    /// Frais Luxury
+    pub luxury_fee: u64,
+    /// Frais Exceptional
+    pub exceptional_fee: u64,
+    /// Jeton actuellement accepté
+    pub enabled: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl FeeMint {
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // mint
+        8 * 4 +                         // frais par type
+        1 +                             // enabled
+        1;                              // bump
+
+    /// Retourne les frais en jetons pour un type donné
+    pub fn fee_for(&self, cert_type: &CertificationType) -> u64 {
+        match cert_type {
+            CertificationType::Standard => self.standard_fee,
+            CertificationType::Premium => self.premium_fee,
+            CertificationType::Luxury => self.luxury_fee,
+            CertificationType::Exceptional => self.exceptional_fee,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+/// Mise en vente d'un certificat à prix fixe (en lamports)
+/// Le paiement de l'acheteur transite par ce compte (séquestre) et l'échange
+/// propriété / fonds est atomique lors de purchase_certificate
+#[account]
+pub struct Listing {
+    /// Certificat mis en vente
+    pub certificate: Pubkey,
+    /// Vendeur (propriétaire au moment de la mise en vente, récupère le loyer ;
+    /// une vente caduque peut être fermée par le propriétaire actuel)
+    pub seller: Pubkey,
+    /// Prix demandé (en lamports)
+    pub price: u64,
+    /// Acheteur désigné (None = ouvert à tous)
+    pub buyer: Option<Pubkey>,
+    /// Date de mise en vente
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Listing {
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // certificate
+        32 +                            // seller
+        8 +                             // price
+        1 + 32 +                        // buyer Option<Pubkey>
+        8 +                             // created_at
+        1;                              // bump
+
+    /// Vérifie si l'adresse peut acheter
+    pub fn is_buyer_allowed(&self, buyer: &Pubkey) -> bool {
+        self.buyer.is_none() || self.buyer == Some(*buyer)
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::Certificate;
+
+/// Version archivée des métadonnées d'un certificat
+/// La version courante est portée par le Certificate ; lorsqu'une nouvelle
+/// version est publiée, la précédente est conservée ici (index = numéro de version)
+#[account]
+pub struct MetadataVersion {
+    /// Certificat concerné
+    pub certificate: Pubkey,
+    /// Numéro de la version archivée (0 = version d'émission)
+    pub version: u32,
+    /// URI des métadonnées de cette version
+    pub metadata_uri: String,
+    /// Empreinte SHA-256 du JSON de métadonnées
+    pub metadata_hash: [u8; 32],
+    /// Date de publication de cette version
+    pub published_at: i64,
+    /// Date à laquelle elle a été remplacée
+    pub superseded_at: i64,
+    /// Signataire ayant publié la version suivante
+    pub superseded_by: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MetadataVersion {
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // certificate
+        4 +                             // version
+        4 + Certificate::MAX_URI_LEN +  // metadata_uri
+        32 +                            // metadata_hash
+        8 +                             // published_at
+        8 +                             // superseded_at
+        32 +                            // superseded_by
+        1;                              // bump
+}
@@ -7,6 +7,24 @@
 // - CertificationType : Enum des types de certification
 // - CertificationRequest : Demande de certification en attente
 // - CertifierProfile : Profil et statistiques d'un certificateur
+// - CertifierDelegate : Cle deleguee d'un certificateur (employe)
+// - CertifierVault : Coffre des gains d'un certificateur
+// - TreasuryVault : Coffre de la plateforme
+// - ProtocolConfig : Parametres du protocole (frais, repartition, delais)
+// - AdminCouncil / AdminProposal : Conseil M-sur-N et propositions
+// - QueuedChange : Modification du protocole en attente (timelock)
+// - OperatorRole : Roles operationnels d'un operateur (hors admin)
+// - PendingTransfer : Offre de transfert en attente d'acceptation
+// - Listing : Mise en vente d'un certificat avec paiement sequestre
+// - ProvenancePage : Page de l'historique de provenance d'un certificat
+// - CertificateStatus / RetirementReason : Cycle de vie et retrait d'un certificat
+// - ServiceRecord : Entrée du carnet d'entretien d'un certificat
+// - AppraisalRequest / AppraisalRecord : Réévaluation d'un certificat et historique
+// - MetadataVersion : Version archivée des métadonnées d'un certificat
+// - TransferPolicy : Politique de transférabilité d'un certificat
+// - FeeMint : Jeton SPL accepté pour le paiement des frais
+// - PriceFeed : Cours SOL/EUR publié par l'oracle (frais en euros)
+// - RejectionCode : Motif normalisé du rejet d'une demande
 // - constants : Constantes du programme
 
 pub mod constants;
@@ -16,6 +34,24 @@ pub mod certificate;
 pub mod user_activity;
 pub mod certification_request;
 pub mod certifier_profile;
+pub mod certifier_delegate;
+pub mod certifier_vault;
+pub mod treasury_vault;
+pub mod protocol_config;
+pub mod admin_council;
+pub mod queued_change;
+pub mod operator_role;
+pub mod pending_transfer;
+pub mod listing;
+pub mod provenance;
+pub mod retirement;
+pub mod service_record;
+pub mod appraisal;
+pub mod metadata_version;
+pub mod transfer_policy;
+pub mod fee_mint;
+pub mod price_feed;
+pub mod rejection_code;
 
 pub use constants::*;
 pub use certification_type::*;
@@ -24,4 +60,22 @@ pub use certificate::*;
 pub use user_activity::*;
 pub use certification_request::*;
 pub use certifier_profile::*;
+pub use certifier_delegate::*;
+pub use certifier_vault::*;
+pub use treasury_vault::*;
+pub use protocol_config::*;
+pub use admin_council::*;
+pub use queued_change::*;
+pub use operator_role::*;
+pub use pending_transfer::*;
+pub use listing::*;
+pub use provenance::*;
+pub use retirement::*;
+pub use service_record::*;
+pub use appraisal::*;
+pub use metadata_version::*;
+pub use transfer_policy::*;
+pub use fee_mint::*;
+pub use price_feed::*;
+pub use rejection_code::*;
 
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::TimelockedChange;
+
+/// Rôles opérationnels attribuables par l'admin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct OperatorRoles {
+    pub certifier_manager: bool, // Agrée, renouvelle et retire les certificateurs
+    pub fee_manager: bool,       // Propose les modifications de configuration (frais, délais, jetons acceptés)
+    pub pauser: bool,            // Suspend ou reprend l'activité du protocole
+    pub dispute_resolver: bool,  // Bloque les paiements en cas de litige, corrige les propriétaires
+}
+
+impl OperatorRoles {
+    /// Vérifie si les rôles permettent de mettre en file d'attente une modification
+    pub fn allows_change(&self, change: &TimelockedChange) -> bool {
+        match change {
+            TimelockedChange::AddCertifier { .. } => self.certifier_manager,
+            TimelockedChange::UpdateConfig { .. } | TimelockedChange::SetFeeMint { .. } => {
+                self.fee_manager
+            }
+        }
+    }
+}
+
+/// Rôles d'un opérateur (permet les opérations courantes sans la clé admin).
+/// Une fois le conseil activé, les rôles ne suffisent plus : toute opération
+/// passe par une proposition approuvée du conseil.
+#[account]
+pub struct OperatorRole {
+    /// Clé de l'opérateur
+    pub operator: Pubkey,
+    /// Rôles accordés
+    pub roles: OperatorRoles,
+    /// Date de dernière modification des rôles
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl OperatorRole {
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // operator
+        4 +                             // roles (4 bools)
+        8 +                             // updated_at
+        1;                              // bump
+}
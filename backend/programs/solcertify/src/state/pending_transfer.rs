@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::TransferKind;
+
+/// Offre de transfert en attente d'acceptation par le destinataire
+/// Une seule offre à la fois par certificat ; le compte est fermé à l'acceptation,
+/// au refus ou à l'annulation (loyer rendu à l'initiateur de l'offre).
+/// Le propriétaire actuel peut annuler une offre devenue caduque (changement de
+/// propriétaire hors de cette offre) pour en proposer une nouvelle.
+#[account]
+pub struct PendingTransfer {
+    /// Certificat concerné
+    pub certificate: Pubkey,
+    /// Propriétaire au moment de l'offre
+    pub from: Pubkey,
+    /// Signataire ayant créé l'offre (propriétaire ou délégué), récupère le loyer
+    pub initiator: Pubkey,
+    /// Destinataire qui doit accepter
+    pub to: Pubkey,
+    /// Nature déclarée du transfert (vente, don, succession)
+    pub kind: TransferKind,
+    /// Approbation du certificateur émetteur (politique CertifierApproval)
+    pub certifier_approved: bool,
+    /// Date de création de l'offre
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PendingTransfer {
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // certificate
+        32 +                            // from
+        32 +                            // initiator
+        32 +                            // to
+        1 +                             // kind
+        1 +                             // certifier_approved
+        8 +                             // created_at
+        1;                              // bump
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use crate::errors::ErrorCode;
+
+/// Cours SOL/EUR publié par un oracle autorisé (singleton)
+/// Sert à convertir en lamports les frais exprimés en centimes d'euro
+#[account]
+pub struct PriceFeed {
+    /// Clé autorisée à publier le cours
+    pub oracle: Pubkey,
+    /// Prix d'un SOL en centimes d'euro
+    pub sol_price_eur_cents: u64,
+    /// Date de la dernière publication (0 = jamais publié)
+    pub updated_at: i64,
+    /// Ancienneté maximale acceptée du cours (secondes)
+    pub max_staleness: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // oracle
+        8 +                             // sol_price_eur_cents
+        8 +                             // updated_at
+        8 +                             // max_staleness
+        1;                              // bump
+
+    /// Vérifie que le cours a été publié et n'est pas périmé
+    pub fn require_fresh(&self, current_time: i64) -> Result<()> {
+        require!(
+            self.updated_at > 0 && self.sol_price_eur_cents > 0,
+            ErrorCode::PriceFeedStale
+        );
+        require!(
+            current_time.saturating_sub(self.updated_at) <= self.max_staleness,
+            ErrorCode::PriceFeedStale
+        );
+        Ok(())
+    }
+
+    /// Convertit un montant en centimes d'euro en lamports au cours courant
+    pub fn eur_cents_to_lamports(&self, eur_cents: u64, current_time: i64) -> Result<u64> {
+        self.require_fresh(current_time)?;
+        let lamports = (eur_cents as u128)
+            .checked_mul(LAMPORTS_PER_SOL as u128)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            / self.sol_price_eur_cents as u128;
+        Ok(u64::try_from(lamports).map_err(|_| ProgramError::ArithmeticOverflow)?)
+    }
+}
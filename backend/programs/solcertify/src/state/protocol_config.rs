@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::constants::{
+    BPS_DENOMINATOR, CERTIFIER_ROYALTY_BPS, CERTIFIER_SHARE_PERCENT, COOLDOWN_PERIOD,
+    EXCEPTIONAL_VALIDITY_PERIOD, INSPECTION_FEE_PERCENT, LOCK_PERIOD, LUXURY_VALIDITY_PERIOD, PLATFORM_ROYALTY_BPS,
+    PREMIUM_VALIDITY_PERIOD, RECERTIFICATION_FEE_PERCENT, STANDARD_VALIDITY_PERIOD, TIMELOCK_DELAY,
+};
+use crate::state::{CertificationType, PriceFeed};
+
+/// Unité dans laquelle sont exprimés les frais de certification de la configuration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub enum FeeCurrency {
+    /// Montants fixes en lamports
+    #[default]
+    Lamports,
+    /// Montants en centimes d'euro, convertis en lamports au cours de l'oracle
+    EurCents,
+}
+
+/// Paramètres du protocole modifiables par la gouvernance
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct ProtocolConfig {
+    pub standard_fee: u64,           // Frais Standard (dans l'unité fee_currency)
+    pub premium_fee: u64,            // Frais Premium
+    pub luxury_fee: u64,             // Frais Luxury
+    pub exceptional_fee: u64,        // Frais Exceptional
+    pub certifier_share_percent: u8, // Part des frais reversée au certificateur
+    pub lock_period: i64,            // Verrouillage après acquisition (secondes)
+    pub cooldown_period: i64,        // Délai entre deux actions (secondes)
+    pub certifier_royalty_bps: u16,  // Redevance de revente au certificateur émetteur (points de base)
+    pub platform_royalty_bps: u16,   // Redevance de revente à la plateforme (points de base)
+    pub standard_validity: i64,      // Validité d'un certificat Standard (secondes, 0 = sans expiration)
+    pub premium_validity: i64,       // Validité d'un certificat Premium
+    pub luxury_validity: i64,        // Validité d'un certificat Luxury
+    pub exceptional_validity: i64,   // Validité d'un certificat Exceptional
+    pub recertification_fee_percent: u8, // Frais de recertification (% des frais de la gamme)
+    pub fee_currency: FeeCurrency,   // Unité des frais (lamports ou centimes d'euro)
+    pub inspection_fee_percent: u8,  // Frais retenus sur un rejet après inspection (% des frais payés)
+    pub timelock_delay: i64,         // Préavis avant application d'une modification (secondes)
+}
+
+impl ProtocolConfig {
+    // 8*4 (frais) + 1 (part) + 8 (lock) + 8 (cooldown) + 2*2 (redevances) + 8*4 (validités) + 1 (recertification)
+    // + 1 (unité des frais) + 1 (frais d'inspection) + 8 (préavis)
+    pub const SPACE: usize = 8 * 4 + 1 + 8 + 8 + 2 * 2 + 8 * 4 + 1 + 1 + 1 + 8;
+
+    /// Configuration initiale reprenant les valeurs historiques du programme
+    pub fn initial() -> Self {
+        Self {
+            standard_fee: CertificationType::Standard.get_fee(),
+            premium_fee: CertificationType::Premium.get_fee(),
+            luxury_fee: CertificationType::Luxury.get_fee(),
+            exceptional_fee: CertificationType::Exceptional.get_fee(),
+            certifier_share_percent: CERTIFIER_SHARE_PERCENT,
+            lock_period: LOCK_PERIOD,
+            cooldown_period: COOLDOWN_PERIOD,
+            certifier_royalty_bps: CERTIFIER_ROYALTY_BPS,
+            platform_royalty_bps: PLATFORM_ROYALTY_BPS,
+            standard_validity: STANDARD_VALIDITY_PERIOD,
+            premium_validity: PREMIUM_VALIDITY_PERIOD,
+            luxury_validity: LUXURY_VALIDITY_PERIOD,
+            exceptional_validity: EXCEPTIONAL_VALIDITY_PERIOD,
+            recertification_fee_percent: RECERTIFICATION_FEE_PERCENT,
+            fee_currency: FeeCurrency::Lamports,
+            inspection_fee_percent: INSPECTION_FEE_PERCENT,
+            timelock_delay: TIMELOCK_DELAY,
+        }
+    }
+
+    /// Retourne les frais de certification pour un type donné (dans l'unité fee_currency)
+    pub fn fee_for(&self, cert_type: &CertificationType) -> u64 {
+        match cert_type {
+            CertificationType::Standard => self.standard_fee,
+            CertificationType::Premium => self.premium_fee,
+            CertificationType::Luxury => self.luxury_fee,
+            CertificationType::Exceptional => self.exceptional_fee,
+        }
+    }
+
+    /// Retourne la fin de validité d'un certificat authentifié à l'instant donné (0 = sans expiration)
+    pub fn validity_until(&self, cert_type: &CertificationType, current_time: i64) -> Result<i64> {
+        let period = match cert_type {
+            CertificationType::Standard => self.standard_validity,
+            CertificationType::Premium => self.premium_validity,
+            CertificationType::Luxury => self.luxury_validity,
+            CertificationType::Exceptional => self.exceptional_validity,
+        };
+        if period == 0 {
+            return Ok(0);
+        }
+        Ok(current_time
+            .checked_add(period)
+            .ok_or(ProgramError::ArithmeticOverflow)?)
+    }
+
+    /// Convertit un montant de la configuration en lamports
+    /// (cours de l'oracle requis et à jour si les frais sont en euros)
+    pub fn to_lamports(
+        &self,
+        amount: u64,
+        price_feed: Option<&PriceFeed>,
+        current_time: i64,
+    ) -> Result<u64> {
+        match self.fee_currency {
+            FeeCurrency::Lamports => Ok(amount),
+            // Aucun frais : pas de conversion, le cours n'est pas requis
+            FeeCurrency::EurCents if amount == 0 => Ok(0),
+            FeeCurrency::EurCents => {
+                let price_feed = price_feed.ok_or(ErrorCode::PriceFeedRequired)?;
+                price_feed.eur_cents_to_lamports(amount, current_time)
+            }
+        }
+    }
+
+    /// Frais de certification en lamports pour un type donné, au cours courant
+    pub fn fee_in_lamports(
+        &self,
+        cert_type: &CertificationType,
+        price_feed: Option<&PriceFeed>,
+        current_time: i64,
+    ) -> Result<u64> {
+        self.to_lamports(self.fee_for(cert_type), price_feed, current_time)
+    }
+
+    /// Frais de recertification en lamports pour un type donné, au cours courant
+    pub fn recertification_fee_for(
+        &self,
+        cert_type: &CertificationType,
+        price_feed: Option<&PriceFeed>,
+        current_time: i64,
+    ) -> Result<u64> {
+        let fee = (self.fee_for(cert_type) as u128)
+            .checked_mul(self.recertification_fee_percent as u128)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            / 100;
+        self.to_lamports(fee as u64, price_feed, current_time)
+    }
+
+    /// Frais d'inspection retenus sur les frais payés d'une demande rejetée
+    pub fn inspection_fee(&self, fee_paid: u64) -> Result<u64> {
+        let fee = (fee_paid as u128)
+            .checked_mul(self.inspection_fee_percent as u128)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            / 100;
+        Ok(fee as u64)
+    }
+
+    /// Calcule la part du certificateur sur un montant de frais
+    pub fn certifier_share(&self, fee: u64) -> Result<u64> {
+        let share = (fee as u128)
+            .checked_mul(self.certifier_share_percent as u128)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            / 100;
+        Ok(share as u64)
+    }
+
+    /// Calcule les redevances (certificateur, plateforme) sur un prix de revente
+    pub fn royalties(&self, price: u64) -> Result<(u64, u64)> {
+        let bps = |rate: u16| -> Result<u64> {
+            let amount = (price as u128)
+                .checked_mul(rate as u128)
+                .ok_or(ProgramError::ArithmeticOverflow)?
+                / BPS_DENOMINATOR as u128;
+            Ok(amount as u64)
+        };
+        Ok((bps(self.certifier_royalty_bps)?, bps(self.platform_royalty_bps)?))
+    }
+
+    /// Vérifie la cohérence des paramètres
+    pub fn validate(&self) -> Result<()> {
+        require!(self.certifier_share_percent <= 100, ErrorCode::InvalidConfig);
+        require!(
+            self.lock_period >= 0 && self.cooldown_period >= 0 && self.timelock_delay >= 0,
+            ErrorCode::InvalidConfig
+        );
+        require!(
+            self.certifier_royalty_bps as u64 + self.platform_royalty_bps as u64 <= BPS_DENOMINATOR,
+            ErrorCode::InvalidConfig
+        );
+        require!(
+            self.standard_validity >= 0
+                && self.premium_validity >= 0
+                && self.luxury_validity >= 0
+                && self.exceptional_validity >= 0,
+            ErrorCode::InvalidConfig
+        );
+        require!(self.recertification_fee_percent <= 100, ErrorCode::InvalidConfig);
+        require!(self.inspection_fee_percent <= 100, ErrorCode::InvalidConfig);
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+/// Nature d'un changement de propriétaire
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransferKind {
+    Sale,            // Vente (via le programme ou déclarée par le vendeur)
+    Gift,            // Don
+    Inheritance,     // Succession
+    AdminCorrection, // Correction administrative du propriétaire
+}
+
+/// Entrée de l'historique de provenance d'un certificat
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProvenanceEntry {
+    pub previous_owner: Pubkey, // Propriétaire cédant
+    pub new_owner: Pubkey,      // Propriétaire acquéreur
+    pub transferred_at: i64,    // Date du transfert
+    pub kind: TransferKind,     // Nature du transfert
+}
+
+impl ProvenanceEntry {
+    pub const SPACE: usize = 32 + 32 + 8 + 1;
+}
+
+/// Page de l'historique de provenance (append-only, sans limite de pages)
+/// Le transfert n°i d'un certificat est enregistré dans la page i / PAGE_SIZE
+#[account]
+pub struct ProvenancePage {
+    /// Certificat concerné
+    pub certificate: Pubkey,
+    /// Index de la page
+    pub page: u32,
+    /// Entrées de la page, dans l'ordre chronologique
+    pub entries: Vec<ProvenanceEntry>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ProvenancePage {
+    pub const PAGE_SIZE: u32 = 10;
+
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // certificate
+        4 +                             // page
+        4 + ProvenanceEntry::SPACE * Self::PAGE_SIZE as usize + // entries
+        1;                              // bump
+
+    /// Index de la page qui reçoit le transfert n°transfer_index
+    pub fn page_for(transfer_index: u32) -> u32 {
+        transfer_index / Self::PAGE_SIZE
+    }
+
+    /// Initialise la page si elle vient d'être créée
+    pub fn init_if_new(&mut self, certificate: Pubkey, page: u32, bump: u8) {
+        if self.certificate == Pubkey::default() {
+            self.certificate = certificate;
+            self.page = page;
+            self.entries = Vec::new();
+            self.bump = bump;
+        }
+    }
+
+    /// Ajoute une entrée (la page ne doit pas être pleine)
+    pub fn append(&mut self, entry: ProvenanceEntry) -> Result<()> {
+        require!(
+            self.entries.len() < Self::PAGE_SIZE as usize,
+            ErrorCode::ProvenancePageFull
+        );
+        self.entries.push(entry);
+        Ok(())
+    }
+}
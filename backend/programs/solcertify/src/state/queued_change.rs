@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{CertifierProfile, ProtocolConfig};
+
+/// Modification du protocole soumise au délai de préavis (timelock)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum TimelockedChange {
+    AddCertifier {
+        certifier: Pubkey,
+        display_name: String,
+        physical_address: String,
+    },
+    UpdateConfig { config: ProtocolConfig },
+    SetFeeMint {
+        mint: Pubkey,
+        standard_fee: u64,
+        premium_fee: u64,
+        luxury_fee: u64,
+        exceptional_fee: u64,
+        enabled: bool,
+    },
+}
+
+impl TimelockedChange {
+    // Taille de la plus grande variante (AddCertifier) : 1 (tag) + 32 + (4+50) + (4+200)
+    pub const SPACE: usize = 1
+        + 32
+        + 4 + CertifierProfile::MAX_NAME_LENGTH
+        + 4 + CertifierProfile::MAX_ADDRESS_LENGTH;
+
+    /// Vérifie les paramètres dès la mise en file d'attente
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            TimelockedChange::AddCertifier {
+                display_name,
+                physical_address,
+                ..
+            } => {
+                require!(
+                    display_name.len() <= CertifierProfile::MAX_NAME_LENGTH,
+                    ErrorCode::DisplayNameTooLong
+                );
+                require!(
+                    physical_address.len() <= CertifierProfile::MAX_ADDRESS_LENGTH,
+                    ErrorCode::PhysicalAddressTooLong
+                );
+                Ok(())
+            }
+            TimelockedChange::UpdateConfig { config } => config.validate(),
+            TimelockedChange::SetFeeMint { .. } => Ok(()),
+        }
+    }
+}
+
+/// Statut d'une modification en file d'attente
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ChangeStatus {
+    #[default]
+    Queued,    // En attente de la fin du préavis
+    Cancelled, // Annulée pendant le préavis
+    Executed,  // Appliquée
+}
+
+/// Modification en file d'attente (lisible par les certificateurs et propriétaires)
+/// Le compte est conservé après exécution ou annulation pour l'historique
+#[account]
+pub struct QueuedChange {
+    /// Identifiant (index dans l'autorité)
+    pub id: u64,
+    /// Modification prévue
+    pub change: TimelockedChange,
+    /// Signataire ayant mis la modification en file d'attente
+    pub queued_by: Pubkey,
+    /// Date de mise en file d'attente
+    pub queued_at: i64,
+    /// Date à partir de laquelle la modification peut être appliquée
+    pub effective_at: i64,
+    /// Statut courant
+    pub status: ChangeStatus,
+    /// Date d'exécution ou d'annulation
+    pub resolved_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl QueuedChange {
+    pub const SPACE: usize = 8 +       // discriminator
+        8 +                             // id
+        TimelockedChange::SPACE +       // change
+        32 +                            // queued_by
+        8 +                             // queued_at
+        8 +                             // effective_at
+        1 +                             // status
+        8 +                             // resolved_at
+        1;                              // bump
+
+    /// Annule la modification pendant le préavis (une fois le préavis écoulé,
+    /// la modification annoncée est acquise et ne peut plus qu'être appliquée)
+    pub fn cancel(&mut self, current_time: i64) -> Result<()> {
+        require!(self.status == ChangeStatus::Queued, ErrorCode::ChangeNotQueued);
+        require!(current_time < self.effective_at, ErrorCode::TimelockElapsed);
+        self.status = ChangeStatus::Cancelled;
+        self.resolved_at = current_time;
+        Ok(())
+    }
+
+    /// Consomme la modification une fois le préavis écoulé
+    pub fn execute(&mut self, change: &TimelockedChange, current_time: i64) -> Result<()> {
+        require!(self.status == ChangeStatus::Queued, ErrorCode::ChangeNotQueued);
+        require!(current_time >= self.effective_at, ErrorCode::TimelockNotElapsed);
+        require!(self.change == *change, ErrorCode::ChangeMismatch);
+        self.status = ChangeStatus::Executed;
+        self.resolved_at = current_time;
+        Ok(())
+    }
+}
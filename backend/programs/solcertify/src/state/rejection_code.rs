@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Motif normalisé du rejet d'une demande de certification
+/// Détermine si les frais d'inspection sont retenus sur le remboursement
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum RejectionCode {
+    Counterfeit,               // Contrefaçon avérée après inspection
+    NonOriginalParts,          // Pièces non d'origine ou montre modifiée
+    DetailsMismatch,           // Montre non conforme à la demande (série, modèle)
+    InsufficientDocumentation, // Photos ou documents insuffisants, pas d'inspection
+    WatchNotReceived,          // Montre jamais déposée chez le certificateur
+    Other,                     // Autre motif (détaillé dans la raison)
+}
+
+impl RejectionCode {
+    /// Le rejet fait suite à une inspection physique : les frais d'inspection sont retenus
+    pub fn charges_inspection_fee(&self) -> bool {
+        matches!(
+            self,
+            RejectionCode::Counterfeit
+                | RejectionCode::NonOriginalParts
+                | RejectionCode::DetailsMismatch
+        )
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Cycle de vie d'un certificat
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub enum CertificateStatus {
+    #[default]
+    Active,            // En circulation
+    RetirementPending, // Retrait demandé, en attente de confirmation par l'admin
+    Retired,           // Retiré définitivement (état terminal)
+}
+
+/// Motif du retrait d'un certificat
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum RetirementReason {
+    Destroyed, // Montre détruite
+    PartedOut, // Montre démontée pour pièces
+    Exported,  // Exportée définitivement hors du registre
+}
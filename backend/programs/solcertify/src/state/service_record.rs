@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+/// Entrée du carnet d'entretien d'un certificat (révision, réparation)
+/// Le n-ième entretien d'un certificat est stocké à l'index n
+#[account]
+pub struct ServiceRecord {
+    /// Certificat concerné
+    pub certificate: Pubkey,
+    /// Index de l'entretien pour ce certificat
+    pub index: u32,
+    /// Certificateur agréé responsable de l'enregistrement
+    pub certifier: Pubkey,
+    /// Délégué ayant enregistré l'entretien (le cas échéant)
+    pub acting_delegate: Option<Pubkey>,
+    /// Date de l'intervention
+    pub serviced_at: i64,
+    /// Travaux effectués
+    pub work_performed: String,
+    /// Pièces remplacées
+    pub parts_replaced: String,
+    /// URI IPFS des justificatifs (facture, photos)
+    pub evidence_uri: String,
+    /// Date d'enregistrement on-chain
+    pub recorded_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ServiceRecord {
+    pub const MAX_WORK_LEN: usize = 200;
+    pub const MAX_PARTS_LEN: usize = 200;
+    pub const MAX_EVIDENCE_URI_LEN: usize = 100;
+
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // certificate
+        4 +                             // index
+        32 +                            // certifier
+        1 + 32 +                        // acting_delegate Option<Pubkey>
+        8 +                             // serviced_at
+        4 + Self::MAX_WORK_LEN +        // work_performed String
+        4 + Self::MAX_PARTS_LEN +       // parts_replaced String
+        4 + Self::MAX_EVIDENCE_URI_LEN + // evidence_uri String
+        8 +                             // recorded_at
+        1;                              // bump
+}
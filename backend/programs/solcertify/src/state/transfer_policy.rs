@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+/// Politique de transférabilité d'un certificat, choisie à l'émission
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub enum TransferPolicy {
+    #[default]
+    Transferable,      // Transferts et ventes libres
+    Soulbound,         // Lié au propriétaire : aucun transfert (musées, assureurs)
+    CertifierApproval, // Chaque offre doit être approuvée par le certificateur émetteur
+}
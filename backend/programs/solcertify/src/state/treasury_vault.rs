@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+/// Coffre de la plateforme (PDA détenu par le programme)
+/// Les frais de la plateforme y sont accumulés et n'en sortent que via withdraw_treasury,
+/// vers l'adresse de paiement désignée par set_treasury
+#[account]
+pub struct TreasuryVault {
+    /// Adresse de paiement de la plateforme (seule destination des retraits)
+    pub payout_address: Pubkey,
+    /// Total des frais encaissés (en lamports)
+    pub total_collected: u64,
+    /// Total des retraits effectués (en lamports)
+    pub total_withdrawn: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl TreasuryVault {
+    pub const SPACE: usize = 8 +       // discriminator
+        32 +                            // payout_address
+        8 +                             // total_collected
+        8 +                             // total_withdrawn
+        1;                              // bump
+
+    /// Enregistre un encaissement de frais
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_collected = self
+            .total_collected
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Enregistre un retrait
+    pub fn record_withdrawal(&mut self, amount: u64) -> Result<()> {
+        self.total_withdrawn = self
+            .total_withdrawn
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
@@ -23,6 +23,25 @@ impl UserActivity {
         }
     }
 
+    // Exige que le cooldown soit écoulé (aucune action précédente = pas de cooldown)
+    pub fn require_cooldown_elapsed(&self, current_time: i64, cooldown_period: i64) -> Result<()> {
+        require!(
+            self.last_action_at == 0 || self.has_cooldown_elapsed(current_time, cooldown_period),
+            crate::errors::ErrorCode::CooldownNotElapsed
+        );
+        Ok(())
+    }
+
+    // Initialise le compte d'activité s'il vient d'être créé
+    pub fn init_if_new(&mut self, user: Pubkey, bump: u8) {
+        if self.user == Pubkey::default() {
+            self.user = user;
+            self.certificate_count = 0;
+            self.last_action_at = 0;
+            self.bump = bump;
+        }
+    }
+
     // Vérifie si l'utilisateur peut acquérir un nouveau certificat
     pub fn can_receive_certificate(&self, max_certificates: u8) -> bool {
         self.certificate_count < max_certificates
@@ -0,0 +1,76 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { PublicKey, Keypair } from "@solana/web3.js";
+import * as fs from 'fs';
+
+// Oracle de substitution pour le développement local :
+// publie un cours SOL/EUR fixe, éventuellement à intervalle régulier
+// pour que le cours ne devienne jamais périmé.
+async function main() {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+
+    const program = anchor.workspace.Solcertify as Program<any>;
+
+    const eurPerSol = parseFloat(process.argv[2]);
+    const intervalSeconds = process.argv[3] ? parseInt(process.argv[3]) : 0;
+    if (!eurPerSol || eurPerSol <= 0) {
+        console.error("Usage: npx ts-node scripts/price-feeder.ts <EUR_PAR_SOL> [INTERVALLE_SECONDES]");
+        process.exit(1);
+    }
+    const solPriceEurCents = Math.round(eurPerSol * 100);
+
+    // CHARGEMENT DE LA CLÉ ORACLE
+    // Si le fichier n'existe pas, on fallback sur le provider wallet
+    const oraclePath = process.env.ORACLE_KEYPAIR || 'tests/keypairs/oracle.json';
+    let oracleKeypair: Keypair | undefined;
+    try {
+        const keyData = JSON.parse(fs.readFileSync(oraclePath, 'utf-8'));
+        oracleKeypair = Keypair.fromSecretKey(new Uint8Array(keyData));
+        console.log(`Oracle chargé (${oraclePath}): ${oracleKeypair.publicKey.toBase58()}`);
+    } catch (e) {
+        console.log("Fichier oracle non trouvé, utilisation du wallet provider par défaut.");
+    }
+    const oracle = oracleKeypair ? oracleKeypair.publicKey : provider.wallet.publicKey;
+
+    const [priceFeedPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("price_feed")],
+        program.programId
+    );
+
+    // L'oracle doit avoir été désigné par l'admin (instruction set_price_oracle)
+    const priceFeed = await (program.account as any).priceFeed.fetchNullable(priceFeedPda);
+    if (!priceFeed || !priceFeed.oracle.equals(oracle)) {
+        console.error(`Cette clé n'est pas l'oracle autorisé. Désignez d'abord ${oracle.toBase58()} via set_price_oracle.`);
+        process.exit(1);
+    }
+
+    const publish = async () => {
+        let builder = (program.methods as any)
+            .updatePrice(new anchor.BN(solPriceEurCents))
+            .accounts({
+                oracle,
+                priceFeed: priceFeedPda,
+            });
+        if (oracleKeypair) {
+            builder = builder.signers([oracleKeypair]);
+        }
+        const tx = await builder.rpc();
+        console.log(`Cours publié: 1 SOL = ${eurPerSol} EUR (${solPriceEurCents} centimes) - ${tx}`);
+    };
+
+    await publish();
+    if (intervalSeconds > 0) {
+        console.log(`Publication toutes les ${intervalSeconds} secondes (Ctrl+C pour arrêter)...`);
+        setInterval(() => publish().catch((err) => console.error("Erreur de publication:", err)), intervalSeconds * 1000);
+        await new Promise(() => {});
+    }
+}
+
+main().then(
+    () => process.exit(),
+    (err) => {
+        console.error(err);
+        process.exit(-1);
+    }
+);
@@ -4,6 +4,26 @@ import { Program } from "@coral-xyz/anchor";
 import { expect } from "chai";
 import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import * as fs from 'fs';
+import { createHash } from 'crypto';
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createTransferCheckedInstruction,
+  getAccount,
+  getMint,
+  getTokenMetadata,
+  createMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount,
+} from "@solana/spl-token";
+
+// Empreinte SHA-256 d'un contenu de métadonnées (argument [u8; 32])
+function sha256(content: string): number[] {
+  return Array.from(createHash('sha256').update(content).digest());
+}
 
 // Helper pour charger ou créer une keypair persistante
 function loadOrGenerateKeypair(path: string): Keypair {
@@ -36,12 +56,12 @@ describe("solcertify", () => {
   let owner1: Keypair;
   let owner2: Keypair;
   let owner3: Keypair;
-  let treasury: Keypair;
   let unauthorized: Keypair;
 
   // PDAs
   let authorityPda: PublicKey;
   let authorityBump: number;
+  let treasuryPda: PublicKey;
 
   // Fonction utilitaire pour airdrop et confirmation
   // Fonction utilitaire pour financer les comptes (via transfert depuis le provider qui est riche)
@@ -94,17 +114,145 @@ describe("solcertify", () => {
     );
   }
 
+  function getCertifierVaultPda(certifier: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("certifier_vault"), certifier.toBuffer()],
+      program.programId
+    );
+  }
+
+  function getCertifierDelegatePda(certifier: PublicKey, delegate: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("certifier_delegate"), certifier.toBuffer(), delegate.toBuffer()],
+      program.programId
+    );
+  }
+
+  function getPendingTransferPda(certificate: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_transfer"), certificate.toBuffer()],
+      program.programId
+    );
+  }
+
+  function getProvenancePagePda(certificate: PublicKey, page: number): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("provenance"), certificate.toBuffer(), new anchor.BN(page).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    );
+  }
+
+  // Page de provenance qui recevra le prochain transfert du certificat
+  async function getNextProvenancePagePda(certificate: PublicKey): Promise<PublicKey> {
+    const { transferCount } = await program.account.certificate.fetch(certificate);
+    return getProvenancePagePda(certificate, Math.floor(transferCount / 10))[0];
+  }
+
+  // Historique complet de provenance (toutes les pages, ordre chronologique)
+  async function fetchProvenance(certificate: PublicKey): Promise<any[]> {
+    const { transferCount } = await program.account.certificate.fetch(certificate);
+    const entries: any[] = [];
+    for (let page = 0; page * 10 < transferCount; page++) {
+      const account = await program.account.provenancePage.fetch(getProvenancePagePda(certificate, page)[0]);
+      entries.push(...account.entries);
+    }
+    return entries;
+  }
+
+  // Certificat sans jeton NFT : les comptes de jeton optionnels sont absents
+  const noNftAccounts = { nftMint: null, fromNftAccount: null, toNftAccount: null, tokenProgram: null };
+
+  // Certificat émis sans jeton NFT : comptes du mint optionnels absents
+  const noNftMintAccounts = { nftMint: null, ownerNftAccount: null, nftTokenProgram: null, associatedTokenProgram: null };
+
+  // Frais réglés en SOL : les comptes de jeton SPL optionnels sont absents
+  const noTokenAccounts = {
+    feeMint: null,
+    paymentMint: null,
+    payerTokenAccount: null,
+    escrowTokenAccount: null,
+    certifierTokenAccount: null,
+    treasuryTokenAccount: null,
+    requesterTokenAccount: null,
+    tokenProgram: null,
+  };
+
+  // Émet un certificat en direct (certificateur principal) pour un propriétaire donné
+  async function issueCertificateTo(
+    serial: string,
+    certType: any,
+    owner: Keypair,
+    value: number = 150000,
+    transferPolicy: any = { transferable: {} }
+  ): Promise<PublicKey> {
+    const [certPda] = getCertificatePda(serial);
+    await program.methods
+      .issueCertificate(serial, "Patek Philippe", "Calatrava", certType, new anchor.BN(value), "ipfs://QmCalatrava", sha256("ipfs://QmCalatrava"), transferPolicy)
+      .accounts({
+        ...noNftMintAccounts,
+        ...noTokenAccounts,
+        priceFeed: null,
+        operator: certifier.publicKey,
+        certifier: certifier.publicKey,
+        delegate: null,
+        owner: owner.publicKey,
+        authority: authorityPda,
+        certificate: certPda,
+        ownerActivity: getUserActivityPda(owner.publicKey)[0],
+        certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+        treasury: treasuryPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([certifier])
+      .rpc();
+    return certPda;
+  }
+
+  function getQueuedChangePda(id: number): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("queued_change"), new anchor.BN(id).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+  }
+
+  function getMetadataVersionPda(certificate: PublicKey, version: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata_version"), certificate.toBuffer(), new anchor.BN(version).toArrayLike(Buffer, "le", 4)],
+      program.programId
+    )[0];
+  }
+
+  // Délai de préavis des modifications du protocole (TIMELOCK_DELAY + marge)
+  const TIMELOCK_WAIT_MS = 6000;
+
+  // Met une modification en file d'attente (admin) et retourne son PDA
+  async function queueChange(change: any): Promise<PublicKey> {
+    const authority = await program.account.certificationAuthority.fetch(authorityPda);
+    const [queuedChangePda] = getQueuedChangePda(authority.queuedChangeCount.toNumber());
+    await program.methods
+      .queueChange(change)
+      .accounts({
+        admin: admin.publicKey,
+        authority: authorityPda,
+        proposal: null,
+        operatorRole: null,
+        queuedChange: queuedChangePda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+    return queuedChangePda;
+  }
+
   before(async () => {
     console.log("Programme ID:", program.programId.toBase58());
 
     // Générer les keypairs - Persister les acteurs clés via .env
     const adminPath = process.env.ADMIN_KEYPAIR || './tests/keypairs/admin.json';
-    const treasuryPath = process.env.TREASURY_KEYPAIR || './tests/keypairs/treasury.json';
     const certifierPath = process.env.CERTIFIER_KEYPAIR || './tests/keypairs/certifier.json';
     const certifier2Path = process.env.CERTIFIER2_KEYPAIR || './tests/keypairs/certifier2.json';
 
     admin = loadOrGenerateKeypair(adminPath);
-    treasury = loadOrGenerateKeypair(treasuryPath);
     certifier = loadOrGenerateKeypair(certifierPath);
     certifier2 = loadOrGenerateKeypair(certifier2Path);
 
@@ -131,6 +279,12 @@ describe("solcertify", () => {
       program.programId
     );
 
+    // Coffre de tresorerie detenu par le programme
+    [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury")],
+      program.programId
+    );
+
     console.log("Authority PDA:", authorityPda.toBase58());
   });
 
@@ -143,7 +297,7 @@ describe("solcertify", () => {
           .accounts({
             admin: admin.publicKey,
             authority: authorityPda,
-            treasury: treasury.publicKey,
+            treasury: treasuryPda,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([admin])
@@ -160,8 +314,8 @@ describe("solcertify", () => {
       const authority = await program.account.certificationAuthority.fetch(authorityPda);
 
       expect(authority.admin.toString()).to.equal(admin.publicKey.toString());
-      expect(authority.treasury.toString()).to.equal(treasury.publicKey.toString());
-      expect(authority.approvedCertifiers).to.have.lengthOf(0);
+      expect(authority.treasury.toString()).to.equal(treasuryPda.toString());
+      expect(authority.certifierCount).to.equal(0);
       expect(authority.totalIssued.toNumber()).to.equal(0);
 
       console.log("Autorité initialisée avec succès");
@@ -171,6 +325,14 @@ describe("solcertify", () => {
   describe("Tests de base - Gestion des certificateurs", () => {
     it("Ajoute un certificateur agréé", async () => {
       const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+      const queuedChangePda = await queueChange({
+        addCertifier: {
+          certifier: certifier.publicKey,
+          displayName: "Horlogerie Paris",
+          physicalAddress: "123 Rue de la Paix, 75001 Paris",
+        },
+      });
+      await new Promise(r => setTimeout(r, TIMELOCK_WAIT_MS));
 
       await program.methods
         .addCertifier(
@@ -179,16 +341,18 @@ describe("solcertify", () => {
           "123 Rue de la Paix, 75001 Paris" // physical_address
         )
         .accounts({
-          admin: admin.publicKey,
+          executor: admin.publicKey,
           authority: authorityPda,
+          queuedChange: queuedChangePda,
           certifierProfile: certifierProfilePda,
+          certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([admin])
         .rpc();
 
       const authority = await program.account.certificationAuthority.fetch(authorityPda);
-      expect(authority.approvedCertifiers).to.have.lengthOf(1);
+      expect(authority.certifierCount).to.equal(1);
 
       // Vérifier le profil créé
       const profile = await program.account.certifierProfile.fetch(certifierProfilePda);
@@ -201,7 +365,16 @@ describe("solcertify", () => {
 
     it("Ajoute un deuxième certificateur", async () => {
       const [certifierProfile2Pda] = getCertifierProfilePda(certifier2.publicKey);
+      const queuedChangePda = await queueChange({
+        addCertifier: {
+          certifier: certifier2.publicKey,
+          displayName: "Horlogerie Lyon",
+          physicalAddress: "456 Place Bellecour, 69002 Lyon",
+        },
+      });
+      await new Promise(r => setTimeout(r, TIMELOCK_WAIT_MS));
 
+      // L'exécution est permissionless une fois le préavis écoulé
       await program.methods
         .addCertifier(
           certifier2.publicKey,
@@ -209,31 +382,61 @@ describe("solcertify", () => {
           "456 Place Bellecour, 69002 Lyon"
         )
         .accounts({
-          admin: admin.publicKey,
+          executor: certifier2.publicKey,
           authority: authorityPda,
+          queuedChange: queuedChangePda,
           certifierProfile: certifierProfile2Pda,
+          certifierVault: getCertifierVaultPda(certifier2.publicKey)[0],
           systemProgram: anchor.web3.SystemProgram.programId,
         })
-        .signers([admin])
+        .signers([certifier2])
         .rpc();
 
       const authority = await program.account.certificationAuthority.fetch(authorityPda);
-      expect(authority.approvedCertifiers).to.have.lengthOf(2);
+      expect(authority.certifierCount).to.equal(2);
       console.log("Deuxième certificateur ajouté");
     });
 
+    it("Renouvelle l'accréditation d'un certificateur", async () => {
+      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+      const profileBefore = await program.account.certifierProfile.fetch(certifierProfilePda);
+
+      await program.methods
+        .renewCertifier(certifier.publicKey)
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          proposal: null,
+          operatorRole: null,
+          certifierProfile: certifierProfilePda,
+        })
+        .signers([admin])
+        .rpc();
+
+      const profileAfter = await program.account.certifierProfile.fetch(certifierProfilePda);
+      expect(profileAfter.accreditedUntil.toNumber()).to.be.greaterThan(profileBefore.accreditedUntil.toNumber());
+      console.log("Accréditation renouvelée jusqu'au:", profileAfter.accreditedUntil.toString());
+    });
+
     it("Retire un certificateur", async () => {
       await program.methods
         .removeCertifier(certifier2.publicKey)
         .accounts({
           admin: admin.publicKey,
           authority: authorityPda,
+          proposal: null,
+          operatorRole: null,
+          certifierProfile: getCertifierProfilePda(certifier2.publicKey)[0],
         })
         .signers([admin])
         .rpc();
 
       const authority = await program.account.certificationAuthority.fetch(authorityPda);
-      expect(authority.approvedCertifiers).to.have.lengthOf(1);
+      expect(authority.certifierCount).to.equal(1);
+
+      // Le profil est conservé mais désactivé : il fait foi pour l'agrément
+      const profile = await program.account.certifierProfile.fetch(getCertifierProfilePda(certifier2.publicKey)[0]);
+      expect(profile.isActive).to.be.false;
       console.log("Certificateur retiré");
     });
   });
@@ -251,15 +454,23 @@ describe("solcertify", () => {
           "Submariner Date",
           { standard: {} },
           new anchor.BN(5000),
-          "ipfs://QmRolexSubmariner001"
+          "ipfs://QmRolexSubmariner001",
+          sha256("ipfs://QmRolexSubmariner001"),
+          { transferable: {} }
         )
         .accounts({
+          ...noNftMintAccounts,
+          ...noTokenAccounts,
+          priceFeed: null,
+          operator: certifier.publicKey,
           certifier: certifier.publicKey,
+          delegate: null,
           owner: owner1.publicKey,
           authority: authorityPda,
           certificate: certificatePda,
           ownerActivity: ownerActivityPda,
-          treasury: treasury.publicKey,
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          treasury: treasuryPda,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([certifier])
@@ -268,6 +479,8 @@ describe("solcertify", () => {
       const certificate = await program.account.certificate.fetch(certificatePda);
       expect(certificate.serialNumber).to.equal(serialNumber);
       expect(certificate.brand).to.equal("Rolex");
+      expect(certificate.metadataHash).to.deep.equal(sha256("ipfs://QmRolexSubmariner001"));
+      expect(certificate.metadataVersion).to.equal(0);
 
       const authority = await program.account.certificationAuthority.fetch(authorityPda);
       expect(authority.totalIssued.toNumber()).to.equal(1);
@@ -288,15 +501,23 @@ describe("solcertify", () => {
           "Seamaster Diver 300M",
           { premium: {} },
           new anchor.BN(8000),
-          "ipfs://QmOmegaSeamaster002"
+          "ipfs://QmOmegaSeamaster002",
+          sha256("ipfs://QmOmegaSeamaster002"),
+          { transferable: {} }
         )
         .accounts({
+          ...noNftMintAccounts,
+          ...noTokenAccounts,
+          priceFeed: null,
+          operator: certifier.publicKey,
           certifier: certifier.publicKey,
+          delegate: null,
           owner: owner2.publicKey,
           authority: authorityPda,
           certificate: certificatePda,
           ownerActivity: ownerActivityPda,
-          treasury: treasury.publicKey,
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          treasury: treasuryPda,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([certifier])
@@ -320,15 +541,23 @@ describe("solcertify", () => {
           "Nautilus 5711",
           { luxury: {} },
           new anchor.BN(50000),
-          "ipfs://QmPatekNautilus003"
+          "ipfs://QmPatekNautilus003",
+          sha256("ipfs://QmPatekNautilus003"),
+          { transferable: {} }
         )
         .accounts({
+          ...noNftMintAccounts,
+          ...noTokenAccounts,
+          priceFeed: null,
+          operator: certifier.publicKey,
           certifier: certifier.publicKey,
+          delegate: null,
           owner: owner2.publicKey,
           authority: authorityPda,
           certificate: certificatePda,
           ownerActivity: ownerActivityPda,
-          treasury: treasury.publicKey,
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          treasury: treasuryPda,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([certifier])
@@ -352,15 +581,23 @@ describe("solcertify", () => {
           "Royal Oak",
           { exceptional: {} },
           new anchor.BN(150000),
-          "ipfs://QmAPRoyalOak004"
+          "ipfs://QmAPRoyalOak004",
+          sha256("ipfs://QmAPRoyalOak004"),
+          { transferable: {} }
         )
         .accounts({
+          ...noNftMintAccounts,
+          ...noTokenAccounts,
+          priceFeed: null,
+          operator: certifier.publicKey,
           certifier: certifier.publicKey,
+          delegate: null,
           owner: owner2.publicKey,
           authority: authorityPda,
           certificate: certificatePda,
           ownerActivity: ownerActivityPda,
-          treasury: treasury.publicKey,
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          treasury: treasuryPda,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([certifier])
@@ -375,19 +612,30 @@ describe("solcertify", () => {
   // ==================== TESTS DE SÉCURITÉ ====================
   describe("Tests de sécurité", () => {
     it("Un utilisateur non autorisé ne peut pas ajouter de certificateur", async () => {
+      const authority = await program.account.certificationAuthority.fetch(authorityPda);
       try {
         await program.methods
-          .addCertifier(unauthorized.publicKey)
+          .queueChange({
+            addCertifier: {
+              certifier: unauthorized.publicKey,
+              displayName: "Faux labo",
+              physicalAddress: "Nulle part",
+            },
+          })
           .accounts({
             admin: unauthorized.publicKey,
             authority: authorityPda,
+            proposal: null,
+            operatorRole: null,
+            queuedChange: getQueuedChangePda(authority.queuedChangeCount.toNumber())[0],
+            systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([unauthorized])
           .rpc();
 
         expect.fail("Devrait lever une erreur");
       } catch (err: any) {
-        expect(err.toString()).to.include("Error");
+        expect(err.toString()).to.include("UnauthorizedCertifier");
         console.log("Erreur attendue: utilisateur non autorisé");
       }
     });
@@ -405,15 +653,23 @@ describe("solcertify", () => {
             "Watch",
             { standard: {} },
             new anchor.BN(100),
-            "ipfs://fake"
+            "ipfs://fake",
+            sha256("ipfs://fake"),
+            { transferable: {} }
           )
           .accounts({
+            ...noNftMintAccounts,
+            ...noTokenAccounts,
+            priceFeed: null,
+            operator: unauthorized.publicKey,
             certifier: unauthorized.publicKey,
+            delegate: null,
             owner: owner1.publicKey,
             authority: authorityPda,
             certificate: certificatePda,
             ownerActivity: ownerActivityPda,
-            treasury: treasury.publicKey,
+            certifierProfile: getCertifierProfilePda(unauthorized.publicKey)[0],
+            treasury: treasuryPda,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([unauthorized])
@@ -421,7 +677,8 @@ describe("solcertify", () => {
 
         expect.fail("Devrait lever une erreur UnauthorizedCertifier");
       } catch (err: any) {
-        expect(err.toString()).to.include("UnauthorizedCertifier");
+        // Sans profil de certificateur, le compte n'existe pas
+        expect(err.toString()).to.match(/UnauthorizedCertifier|AccountNotInitialized/);
         console.log("Erreur attendue: certificateur non agréé");
       }
     });
@@ -440,15 +697,23 @@ describe("solcertify", () => {
             "Test",
             { standard: {} },
             new anchor.BN(5000),
-            "ipfs://duplicate"
+            "ipfs://duplicate",
+            sha256("ipfs://duplicate"),
+            { transferable: {} }
           )
           .accounts({
+            ...noNftMintAccounts,
+            ...noTokenAccounts,
+            priceFeed: null,
+            operator: certifier.publicKey,
             certifier: certifier.publicKey,
+            delegate: null,
             owner: owner2.publicKey,
             authority: authorityPda,
             certificate: certificatePda,
             ownerActivity: ownerActivityPda,
-            treasury: treasury.publicKey,
+            certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+            treasury: treasuryPda,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([certifier])
@@ -478,15 +743,23 @@ describe("solcertify", () => {
           "Speedmaster",
           { standard: {} },
           new anchor.BN(6000),
-          "ipfs://QmOmegaSpeedmaster005"
+          "ipfs://QmOmegaSpeedmaster005",
+          sha256("ipfs://QmOmegaSpeedmaster005"),
+          { transferable: {} }
         )
         .accounts({
+          ...noNftMintAccounts,
+          ...noTokenAccounts,
+          priceFeed: null,
+          operator: certifier.publicKey,
           certifier: certifier.publicKey,
+          delegate: null,
           owner: owner2.publicKey,
           authority: authorityPda,
           certificate: certificatePda,
           ownerActivity: ownerActivityPda,
-          treasury: treasury.publicKey,
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          treasury: treasuryPda,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([certifier])
@@ -511,15 +784,23 @@ describe("solcertify", () => {
             "Navitimer",
             { standard: {} },
             new anchor.BN(4000),
-            "ipfs://QmBreitlingNav006"
+            "ipfs://QmBreitlingNav006",
+            sha256("ipfs://QmBreitlingNav006"),
+            { transferable: {} }
           )
           .accounts({
+            ...noNftMintAccounts,
+            ...noTokenAccounts,
+            priceFeed: null,
+            operator: certifier.publicKey,
             certifier: certifier.publicKey,
+            delegate: null,
             owner: owner2.publicKey,
             authority: authorityPda,
             certificate: certificatePda,
             ownerActivity: ownerActivityPda,
-            treasury: treasury.publicKey,
+            certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+            treasury: treasuryPda,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([certifier])
@@ -534,6 +815,14 @@ describe("solcertify", () => {
 
     it("Vérifie qu'un certificateur ne peut pas être ajouté en double", async () => {
       const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+      const queuedChangePda = await queueChange({
+        addCertifier: {
+          certifier: certifier.publicKey,
+          displayName: "Duplicate Name",
+          physicalAddress: "Duplicate Address",
+        },
+      });
+      await new Promise(r => setTimeout(r, TIMELOCK_WAIT_MS));
 
       try {
         await program.methods
@@ -543,9 +832,11 @@ describe("solcertify", () => {
             "Duplicate Address"
           )
           .accounts({
-            admin: admin.publicKey,
+            executor: admin.publicKey,
             authority: authorityPda,
+            queuedChange: queuedChangePda,
             certifierProfile: certifierProfilePda,
+            certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
             systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([admin])
@@ -581,30 +872,34 @@ describe("solcertify", () => {
       const [certPda] = getCertificatePda(serial);
       const [ownerFnActivityPda] = getUserActivityPda(owner1.publicKey);
 
-      await program.methods.issueCertificate(serial, "Brand", "Model", { standard: {} }, new anchor.BN(100), "uri")
+      await program.methods.issueCertificate(serial, "Brand", "Model", { standard: {} }, new anchor.BN(100), "uri", sha256("uri"), { transferable: {} })
         .accounts({
+          ...noNftMintAccounts,
+          ...noTokenAccounts,
+          priceFeed: null,
+          operator: certifier.publicKey,
           certifier: certifier.publicKey,
+          delegate: null,
           owner: owner1.publicKey,
           authority: authorityPda,
           certificate: certPda,
           ownerActivity: ownerFnActivityPda,
-          treasury: treasury.publicKey,
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          treasury: treasuryPda,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([certifier])
         .rpc();
 
-      const [toActivityPda] = getUserActivityPda(owner2.publicKey);
-
       try {
         await program.methods
-          .transferCertificate()
+          .transferCertificate({ gift: {} })
           .accounts({
             from: owner1.publicKey,
             to: owner2.publicKey,
+            authority: authorityPda,
             certificate: certPda,
-            fromActivity: ownerFnActivityPda,
-            toActivity: toActivityPda,
+            pendingTransfer: getPendingTransferPda(certPda)[0],
             systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([owner1])
@@ -622,18 +917,16 @@ describe("solcertify", () => {
     it("On ne peut pas transférer un certificat sans être propriétaire", async () => {
       const serialNumber = "ROLEX-SUB-001-V5";
       const [certificatePda] = getCertificatePda(serialNumber);
-      const [fromActivityPda] = getUserActivityPda(owner2.publicKey);
-      const [toActivityPda] = getUserActivityPda(unauthorized.publicKey);
 
       try {
         await program.methods
-          .transferCertificate()
+          .transferCertificate({ sale: {} })
           .accounts({
             from: owner2.publicKey,
             to: unauthorized.publicKey,
+            authority: authorityPda,
             certificate: certificatePda,
-            fromActivity: fromActivityPda,
-            toActivity: toActivityPda,
+            pendingTransfer: getPendingTransferPda(certificatePda)[0],
             systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([owner2])
@@ -645,262 +938,3181 @@ describe("solcertify", () => {
         console.log("Erreur attendue: non-proprietaire");
       }
     });
-  });
 
-  // ==================== TESTS DEMANDES DE CERTIFICATION ====================
-  describe("Tests demandes de certification", () => {
-    const requestSerial1 = "REQUEST-TEST-001";
-    const requestSerial2 = "REQUEST-TEST-002";
-    const requestSerial3 = "REQUEST-REJECT-001";
+    // Destinataire du transfert en deux étapes, revendeur dans le test de vente
+    let transferRecipient: Keypair;
 
-    function getRequestPda(serialNumber: string): [PublicKey, number] {
-      return PublicKey.findProgramAddressSync(
-        [Buffer.from("request"), Buffer.from(serialNumber)],
-        program.programId
-      );
-    }
+    it("Transfert en deux etapes : offre, refus, annulation puis acceptation", async () => {
+      const serial = "LOCKED-CERT-001-V5";
+      const [certPda] = getCertificatePda(serial);
+      const [pendingTransferPda] = getPendingTransferPda(certPda);
+      const recipient = Keypair.generate();
+      transferRecipient = recipient;
+      await airdrop(recipient.publicKey, 1);
 
-    it("Utilisateur soumet une demande de certification", async () => {
-      const [requestPda] = getRequestPda(requestSerial1);
-      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+      // Attendre la fin du verrouillage après émission
+      await new Promise(r => setTimeout(r, 21000));
+
+      const offer = () =>
+        program.methods
+          .transferCertificate({ gift: {} })
+          .accounts({
+            from: owner1.publicKey,
+            to: recipient.publicKey,
+            authority: authorityPda,
+            certificate: certPda,
+            pendingTransfer: pendingTransferPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([owner1])
+          .rpc();
 
+      // Le destinataire refuse
+      await offer();
       await program.methods
-        .requestCertification(
-          requestSerial1,
-          "Omega",
-          "Speedmaster",
-          { premium: {} },
-          new anchor.BN(12000),
-          "ipfs://QmTestMetadata123",
-          certifier.publicKey // target_certifier - le certificateur choisi
-        )
+        .declineTransfer()
         .accounts({
-          requester: owner1.publicKey,
-          authority: authorityPda,
-          request: requestPda,
-          certifierProfile: certifierProfilePda,
-          treasury: treasury.publicKey,
-          systemProgram: anchor.web3.SystemProgram.programId,
+          recipient: recipient.publicKey,
+          initiator: owner1.publicKey,
+          certificate: certPda,
+          pendingTransfer: pendingTransferPda,
         })
-        .signers([owner1])
+        .signers([recipient])
         .rpc();
+      expect(await program.account.pendingTransfer.fetchNullable(pendingTransferPda)).to.be.null;
 
-      const request = await program.account.certificationRequest.fetch(requestPda);
-
-      expect(request.requester.toString()).to.equal(owner1.publicKey.toString());
-      expect(request.serialNumber).to.equal(requestSerial1);
-      expect(request.brand).to.equal("Omega");
-      expect(request.model).to.equal("Speedmaster");
-      expect(request.status.pending).to.exist;
-      expect(request.feePaid.toNumber()).to.be.greaterThan(0);
-      expect(request.assignedCertifier.toString()).to.equal(certifier.publicKey.toString());
-
-      // Vérifier que la charge du certificateur a été incrémentée
-      const profile = await program.account.certifierProfile.fetch(certifierProfilePda);
-      expect(profile.currentLoad).to.equal(1);
-
-      console.log("Demande de certification soumise avec succes, assignée à:", certifier.publicKey.toBase58());
-      console.log("Frais payes:", request.feePaid.toString(), "lamports");
-    });
-
-    it("Certificateur approuve une demande", async () => {
-      const [requestPda] = getRequestPda(requestSerial1);
-      const [certificatePda] = getCertificatePda(requestSerial1);
-      const [ownerActivityPda] = getUserActivityPda(owner1.publicKey);
-      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
-
-      // Recuperer le solde du certificateur avant
-      const certifierBalanceBefore = await provider.connection.getBalance(certifier.publicKey);
-
-      // Récupérer la charge avant
-      const profileBefore = await program.account.certifierProfile.fetch(certifierProfilePda);
-      const loadBefore = profileBefore.currentLoad;
-
+      // Le propriétaire annule
+      await offer();
       await program.methods
-        .approveCertification()
+        .cancelTransfer()
         .accounts({
-          certifier: certifier.publicKey,
-          authority: authorityPda,
-          request: requestPda,
-          certificate: certificatePda,
-          ownerActivity: ownerActivityPda,
-          certifierProfile: certifierProfilePda,
-          treasury: treasury.publicKey,
-          systemProgram: anchor.web3.SystemProgram.programId,
+          from: owner1.publicKey,
+          initiator: owner1.publicKey,
+          certificate: certPda,
+          pendingTransfer: pendingTransferPda,
         })
-        .signers([certifier])
+        .signers([owner1])
         .rpc();
+      expect(await program.account.pendingTransfer.fetchNullable(pendingTransferPda)).to.be.null;
 
-      // Verifier la demande mise a jour
-      const request = await program.account.certificationRequest.fetch(requestPda);
-      expect(request.status.approved).to.exist;
-      expect(request.assignedCertifier.toString()).to.equal(certifier.publicKey.toString());
+      // La correction administrative n'est pas un transfert ordinaire
+      try {
+        await program.methods
+          .transferCertificate({ adminCorrection: {} })
+          .accounts({
+            from: owner1.publicKey,
+            to: recipient.publicKey,
+            authority: authorityPda,
+            certificate: certPda,
+            pendingTransfer: pendingTransferPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([owner1])
+          .rpc();
+        expect.fail("Devrait lever une erreur InvalidTransferKind");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidTransferKind");
+      }
 
-      // Verifier le certificat cree
-      const certificate = await program.account.certificate.fetch(certificatePda);
-      expect(certificate.serialNumber).to.equal(requestSerial1);
-      expect(certificate.owner.toString()).to.equal(owner1.publicKey.toString());
-      expect(certificate.certifier.toString()).to.equal(certifier.publicKey.toString());
+      // Seul le destinataire peut accepter
+      await offer();
+      const provenancePage = await getNextProvenancePagePda(certPda);
+      const acceptAccounts = (signer: PublicKey) => ({
+        recipient: signer,
+        from: owner1.publicKey,
+        initiator: owner1.publicKey,
+        authority: authorityPda,
+        certificate: certPda,
+        pendingTransfer: pendingTransferPda,
+        fromActivity: getUserActivityPda(owner1.publicKey)[0],
+        toActivity: getUserActivityPda(signer)[0],
+        provenancePage,
+        ...noNftAccounts,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      });
+      try {
+        await program.methods
+          .acceptTransfer()
+          .accounts(acceptAccounts(unauthorized.publicKey))
+          .signers([unauthorized])
+          .rpc();
+        expect.fail("Devrait lever une erreur NotTransferRecipient");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NotTransferRecipient");
+      }
 
-      // Vérifier que la charge a été décrémentée
-      const profileAfter = await program.account.certifierProfile.fetch(certifierProfilePda);
-      expect(profileAfter.currentLoad).to.equal(loadBefore - 1);
-      expect(profileAfter.totalProcessed.toNumber()).to.be.greaterThan(0);
+      // La propriété ne change qu'à l'acceptation
+      let certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.owner.toBase58()).to.equal(owner1.publicKey.toBase58());
 
-      // Verifier que le certificateur a recu sa part (60%)
-      const certifierBalanceAfter = await provider.connection.getBalance(certifier.publicKey);
-      console.log("Certificateur balance avant:", certifierBalanceBefore);
-      console.log("Certificateur balance apres:", certifierBalanceAfter);
-      console.log("Charge actuelle du certificateur:", profileAfter.currentLoad);
+      await program.methods
+        .acceptTransfer()
+        .accounts(acceptAccounts(recipient.publicKey))
+        .signers([recipient])
+        .rpc();
 
-      console.log("Demande approuvee et certificat cree");
+      certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.owner.toBase58()).to.equal(recipient.publicKey.toBase58());
+      const recipientActivity = await program.account.userActivity.fetch(getUserActivityPda(recipient.publicKey)[0]);
+      expect(recipientActivity.certificateCount).to.equal(1);
+      expect(await program.account.pendingTransfer.fetchNullable(pendingTransferPda)).to.be.null;
+
+      // Le transfert est inscrit dans l'historique de provenance avec sa nature
+      const provenance = await fetchProvenance(certPda);
+      const last = provenance[provenance.length - 1];
+      expect(certificate.transferCount).to.equal(provenance.length);
+      expect(last.previousOwner.toBase58()).to.equal(owner1.publicKey.toBase58());
+      expect(last.newOwner.toBase58()).to.equal(recipient.publicKey.toBase58());
+      expect(last.kind).to.deep.equal({ gift: {} });
+      expect(last.transferredAt.toNumber()).to.be.greaterThan(0);
+      console.log("Transfert accepte par le destinataire");
     });
 
-    it("Echec approbation par non-certificateur", async () => {
-      // D'abord creer une nouvelle demande assignée au certificateur légitime
-      const [requestPda] = getRequestPda(requestSerial2);
-      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+    it("Vente atomique : paiement sequestre et echange propriete / fonds", async () => {
+      const serial = "LOCKED-CERT-001-V5";
+      const [certPda] = getCertificatePda(serial);
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("listing"), certPda.toBuffer()],
+        program.programId
+      );
+      const seller = transferRecipient;
+      const price = new anchor.BN(100_000_000);
 
       await program.methods
-        .requestCertification(
-          requestSerial2,
-          "Cartier",
-          "Santos",
-          { luxury: {} },
-          new anchor.BN(25000),
-          "ipfs://QmTestMetadata456",
-          certifier.publicKey // assigné au bon certificateur
-        )
+        .listCertificate(price, owner3.publicKey)
         .accounts({
-          requester: owner3.publicKey,
+          seller: seller.publicKey,
           authority: authorityPda,
-          request: requestPda,
-          certifierProfile: certifierProfilePda,
-          treasury: treasury.publicKey,
+          certificate: certPda,
+          listing: listingPda,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
-        .signers([owner3])
+        .signers([seller])
         .rpc();
 
-      // Essayer d'approuver avec un non-certificateur (devrait échouer)
-      const [certificatePda] = getCertificatePda(requestSerial2);
-      const [ownerActivityPda] = getUserActivityPda(owner3.publicKey);
-      // Note: le non-autorisé n'a pas de profile, donc on ne peut pas fournir un account valide
-      // Le test va échouer pour "account not found" ou "not assigned certifier"
+      const provenancePage = await getNextProvenancePagePda(certPda);
+      const purchaseAccounts = (buyer: PublicKey) => ({
+        buyer,
+        seller: seller.publicKey,
+        authority: authorityPda,
+        certificate: certPda,
+        listing: listingPda,
+        sellerActivity: getUserActivityPda(seller.publicKey)[0],
+        buyerActivity: getUserActivityPda(buyer)[0],
+        certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
+        treasury: treasuryPda,
+        provenancePage,
+        ...noNftAccounts,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      });
 
+      // Vente réservée à l'acheteur désigné
       try {
-        // On utilise le profile du vrai certificateur mais signe avec unauthorized
         await program.methods
-          .approveCertification()
-          .accounts({
-            certifier: unauthorized.publicKey,
-            authority: authorityPda,
-            request: requestPda,
-            certificate: certificatePda,
-            ownerActivity: ownerActivityPda,
-            certifierProfile: certifierProfilePda, // wrong certifier trying to use
-            treasury: treasury.publicKey,
-            systemProgram: anchor.web3.SystemProgram.programId,
-          })
+          .purchaseCertificate(price)
+          .accounts(purchaseAccounts(unauthorized.publicKey))
           .signers([unauthorized])
           .rpc();
-
-        expect.fail("Devrait lever une erreur");
+        expect.fail("Devrait lever une erreur NotDesignatedBuyer");
       } catch (err: any) {
-        // Peut être NotAssignedCertifier, UnauthorizedCertifier, ou ConstraintSeeds
-        expect(err).to.exist;
-        console.log("Erreur attendue: non-certificateur ne peut pas approuver");
+        expect(err.toString()).to.include("NotDesignatedBuyer");
       }
-    });
 
-    it("Certificateur rejette une demande avec remboursement", async () => {
-      // Creer une demande a rejeter
-      const [requestPda] = getRequestPda(requestSerial3);
-      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+      // Le verrouillage après acquisition est respecté
+      try {
+        await program.methods
+          .purchaseCertificate(price)
+          .accounts(purchaseAccounts(owner3.publicKey))
+          .signers([owner3])
+          .rpc();
+        expect.fail("Devrait lever une erreur CertificateLocked");
+      } catch (err: any) {
+        expect(err.toString()).to.include("CertificateLocked");
+      }
 
-      // Solde avant du demandeur
-      const requesterBalanceBefore = await provider.connection.getBalance(owner1.publicKey);
+      await new Promise(r => setTimeout(r, 21000));
 
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+      const vaultBefore = await program.account.certifierVault.fetch(getCertifierVaultPda(certifier.publicKey)[0]);
+      const treasuryBefore = await program.account.treasuryVault.fetch(treasuryPda);
       await program.methods
-        .requestCertification(
+        .purchaseCertificate(price)
+        .accounts(purchaseAccounts(owner3.publicKey))
+        .signers([owner3])
+        .rpc();
+
+      // Redevances de revente : certificateur émetteur et plateforme
+      const { config } = await program.account.certificationAuthority.fetch(authorityPda);
+      const certifierRoyalty = price.muln(config.certifierRoyaltyBps).divn(10_000);
+      const platformRoyalty = price.muln(config.platformRoyaltyBps).divn(10_000);
+      const vaultAfter = await program.account.certifierVault.fetch(getCertifierVaultPda(certifier.publicKey)[0]);
+      const treasuryAfter = await program.account.treasuryVault.fetch(treasuryPda);
+      expect(vaultAfter.lifetimeEarnings.sub(vaultBefore.lifetimeEarnings).toString()).to.equal(certifierRoyalty.toString());
+      expect(treasuryAfter.totalCollected.sub(treasuryBefore.totalCollected).toString()).to.equal(platformRoyalty.toString());
+
+      const certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.owner.toBase58()).to.equal(owner3.publicKey.toBase58());
+      const provenance = await fetchProvenance(certPda);
+      const sale = provenance[provenance.length - 1];
+      expect(sale.previousOwner.toBase58()).to.equal(seller.publicKey.toBase58());
+      expect(sale.kind).to.deep.equal({ sale: {} });
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      const proceeds = price.sub(certifierRoyalty).sub(platformRoyalty);
+      expect(sellerBalanceAfter - sellerBalanceBefore).to.be.at.least(proceeds.toNumber());
+      expect(certificate.lastSalePrice.toString()).to.equal(price.toString());
+      expect(certificate.totalRoyaltiesPaid.toString()).to.equal(certifierRoyalty.add(platformRoyalty).toString());
+      expect(await program.account.listing.fetchNullable(listingPda)).to.be.null;
+      console.log("Vente executee:", price.toString(), "lamports");
+    });
+
+    it("Un delegue approuve propose le transfert pour le proprietaire", async () => {
+      const serial = "LOCKED-CERT-001-V5";
+      const [certPda] = getCertificatePda(serial);
+      const [pendingTransferPda] = getPendingTransferPda(certPda);
+      const dealer = Keypair.generate();
+      const buyer = Keypair.generate();
+      await airdrop(dealer.publicKey, 1);
+      await airdrop(buyer.publicKey, 1);
+
+      const delegateAccounts = { owner: owner3.publicKey, certificate: certPda };
+      const offerByDealer = () =>
+        program.methods
+          .transferCertificate({ sale: {} })
+          .accounts({
+            from: dealer.publicKey,
+            to: buyer.publicKey,
+            authority: authorityPda,
+            certificate: certPda,
+            pendingTransfer: pendingTransferPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([dealer])
+          .rpc();
+
+      // Une délégation révoquée ne permet plus de transférer
+      await program.methods
+        .approveDelegate(dealer.publicKey, null)
+        .accounts(delegateAccounts)
+        .signers([owner3])
+        .rpc();
+      await program.methods.revokeDelegate().accounts(delegateAccounts).signers([owner3]).rpc();
+      try {
+        await offerByDealer();
+        expect.fail("Devrait lever une erreur NotOwner");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NotOwner");
+      }
+
+      const now = Math.floor(Date.now() / 1000);
+      await program.methods
+        .approveDelegate(dealer.publicKey, new anchor.BN(now + 3600))
+        .accounts(delegateAccounts)
+        .signers([owner3])
+        .rpc();
+
+      // Attendre la fin du verrouillage après la vente
+      await new Promise(r => setTimeout(r, 21000));
+      await offerByDealer();
+
+      const pending = await program.account.pendingTransfer.fetch(pendingTransferPda);
+      expect(pending.from.toBase58()).to.equal(owner3.publicKey.toBase58());
+      expect(pending.initiator.toBase58()).to.equal(dealer.publicKey.toBase58());
+
+      await program.methods
+        .acceptTransfer()
+        .accounts({
+          recipient: buyer.publicKey,
+          from: owner3.publicKey,
+          initiator: dealer.publicKey,
+          authority: authorityPda,
+          certificate: certPda,
+          pendingTransfer: pendingTransferPda,
+          fromActivity: getUserActivityPda(owner3.publicKey)[0],
+          toActivity: getUserActivityPda(buyer.publicKey)[0],
+          provenancePage: await getNextProvenancePagePda(certPda),
+          ...noNftAccounts,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      // Le véritable ancien propriétaire est inscrit dans l'historique, la délégation est levée
+      const certificate = await program.account.certificate.fetch(certPda);
+      const history = (await fetchProvenance(certPda)).map((e: any) => e.previousOwner.toBase58());
+      expect(certificate.owner.toBase58()).to.equal(buyer.publicKey.toBase58());
+      expect(history[history.length - 1]).to.equal(owner3.publicKey.toBase58());
+      expect(history).to.not.include(dealer.publicKey.toBase58());
+      expect(certificate.transferDelegate).to.be.null;
+      console.log("Transfert par delegue accepte");
+    });
+
+    it("L'admin corrige le proprietaire, inscrit comme correction administrative", async () => {
+      const serial = "LOCKED-CERT-001-V5";
+      const [certPda] = getCertificatePda(serial);
+      const { owner: wrongOwner } = await program.account.certificate.fetch(certPda);
+      const provenancePage = await getNextProvenancePagePda(certPda);
+
+      const correct = (signer: Keypair) =>
+        program.methods
+          .correctOwner(owner3.publicKey)
+          .accounts({
+            admin: signer.publicKey,
+            authority: authorityPda,
+            certificate: certPda,
+            fromActivity: getUserActivityPda(wrongOwner)[0],
+            toActivity: getUserActivityPda(owner3.publicKey)[0],
+            provenancePage,
+            ...noNftAccounts,
+            proposal: null,
+            operatorRole: null,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([signer])
+          .rpc();
+
+      try {
+        await correct(unauthorized);
+        expect.fail("Devrait lever une erreur UnauthorizedCertifier");
+      } catch (err: any) {
+        expect(err.toString()).to.include("UnauthorizedCertifier");
+      }
+
+      await correct(admin);
+
+      // Pas de verrouillage après une correction, l'historique reste complet
+      const certificate = await program.account.certificate.fetch(certPda);
+      const provenance = await fetchProvenance(certPda);
+      const last = provenance[provenance.length - 1];
+      expect(certificate.owner.toBase58()).to.equal(owner3.publicKey.toBase58());
+      expect(provenance.length).to.equal(certificate.transferCount);
+      expect(last.previousOwner.toBase58()).to.equal(wrongOwner.toBase58());
+      expect(last.kind).to.deep.equal({ adminCorrection: {} });
+      console.log("Proprietaire corrige, historique:", provenance.length, "entrees");
+    });
+
+    it("Le nouveau proprietaire peut annuler l'offre caduque de l'ancien proprietaire", async () => {
+      const previousOwner = Keypair.generate();
+      const staleRecipient = Keypair.generate();
+      const newOwner = Keypair.generate();
+      const nextRecipient = Keypair.generate();
+      await airdrop(previousOwner.publicKey, 1);
+      await airdrop(newOwner.publicKey, 1);
+      const certPda = await issueCertificateTo("STALE-OFFER-001", { standard: {} }, previousOwner);
+      const [pendingTransferPda] = getPendingTransferPda(certPda);
+
+      const offer = (from: Keypair, to: PublicKey) =>
+        program.methods
+          .transferCertificate({ gift: {} })
+          .accounts({
+            from: from.publicKey,
+            to,
+            authority: authorityPda,
+            certificate: certPda,
+            pendingTransfer: pendingTransferPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([from])
+          .rpc();
+
+      // Offre de l'ancien propriétaire, puis changement de propriétaire hors de cette offre
+      await new Promise(r => setTimeout(r, 21000));
+      await offer(previousOwner, staleRecipient.publicKey);
+      await program.methods
+        .correctOwner(newOwner.publicKey)
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          certificate: certPda,
+          fromActivity: getUserActivityPda(previousOwner.publicKey)[0],
+          toActivity: getUserActivityPda(newOwner.publicKey)[0],
+          provenancePage: await getNextProvenancePagePda(certPda),
+          ...noNftAccounts,
+          proposal: null,
+          operatorRole: null,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      // L'offre caduque occupe le PDA : aucune nouvelle offre possible
+      try {
+        await offer(newOwner, nextRecipient.publicKey);
+        expect.fail("L'offre caduque devrait bloquer une nouvelle offre");
+      } catch (err: any) {
+        expect(err.toString()).to.include("already in use");
+      }
+
+      // Le propriétaire actuel l'annule (loyer rendu à l'initiateur) puis propose à son tour
+      await program.methods
+        .cancelTransfer()
+        .accounts({
+          from: newOwner.publicKey,
+          initiator: previousOwner.publicKey,
+          certificate: certPda,
+          pendingTransfer: pendingTransferPda,
+        })
+        .signers([newOwner])
+        .rpc();
+      expect(await program.account.pendingTransfer.fetchNullable(pendingTransferPda)).to.be.null;
+
+      await offer(newOwner, nextRecipient.publicKey);
+      const pending = await program.account.pendingTransfer.fetch(pendingTransferPda);
+      expect(pending.from.toBase58()).to.equal(newOwner.publicKey.toBase58());
+      expect(pending.to.toBase58()).to.equal(nextRecipient.publicKey.toBase58());
+    });
+
+    it("Le vendeur retire sa vente et le nouveau proprietaire ferme une vente caduque", async () => {
+      const previousOwner = Keypair.generate();
+      const newOwner = Keypair.generate();
+      await airdrop(previousOwner.publicKey, 1);
+      await airdrop(newOwner.publicKey, 1);
+      const certPda = await issueCertificateTo("STALE-LISTING-001", { standard: {} }, previousOwner);
+      const [listingPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("listing"), certPda.toBuffer()],
+        program.programId
+      );
+      const price = new anchor.BN(LAMPORTS_PER_SOL / 10);
+
+      const list = (seller: Keypair) =>
+        program.methods
+          .listCertificate(price, null)
+          .accounts({
+            seller: seller.publicKey,
+            authority: authorityPda,
+            certificate: certPda,
+            listing: listingPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([seller])
+          .rpc();
+      const cancel = (closer: Keypair, seller: PublicKey) =>
+        program.methods
+          .cancelListing()
+          .accounts({
+            closer: closer.publicKey,
+            seller,
+            certificate: certPda,
+            listing: listingPda,
+          })
+          .signers([closer])
+          .rpc();
+
+      // Le vendeur retire sa vente ; un tiers ne peut pas la fermer
+      await list(previousOwner);
+      try {
+        await cancel(unauthorized, previousOwner.publicKey);
+        expect.fail("Devrait lever une erreur NotOwner");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NotOwner");
+      }
+      await cancel(previousOwner, previousOwner.publicKey);
+      expect(await program.account.listing.fetchNullable(listingPda)).to.be.null;
+
+      // Remise en vente, puis changement de propriétaire hors de cette vente
+      await list(previousOwner);
+      await program.methods
+        .correctOwner(newOwner.publicKey)
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          certificate: certPda,
+          fromActivity: getUserActivityPda(previousOwner.publicKey)[0],
+          toActivity: getUserActivityPda(newOwner.publicKey)[0],
+          provenancePage: await getNextProvenancePagePda(certPda),
+          ...noNftAccounts,
+          proposal: null,
+          operatorRole: null,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      // La vente caduque occupe le PDA : aucune remise en vente possible
+      try {
+        await list(newOwner);
+        expect.fail("La vente caduque devrait bloquer une nouvelle mise en vente");
+      } catch (err: any) {
+        expect(err.toString()).to.include("already in use");
+      }
+
+      // Le propriétaire actuel la ferme (loyer rendu à l'ancien vendeur) puis met en vente
+      const sellerBalanceBefore = await provider.connection.getBalance(previousOwner.publicKey);
+      await cancel(newOwner, previousOwner.publicKey);
+      expect(await provider.connection.getBalance(previousOwner.publicKey)).to.be.greaterThan(sellerBalanceBefore);
+
+      await list(newOwner);
+      const listing = await program.account.listing.fetch(listingPda);
+      expect(listing.seller.toBase58()).to.equal(newOwner.publicKey.toBase58());
+    });
+  });
+
+  // ==================== TESTS POLITIQUE DE TRANSFERABILITE ====================
+  describe("Tests politique de transferabilite", () => {
+    const offer = (certPda: PublicKey, from: Keypair, to: PublicKey) =>
+      program.methods
+        .transferCertificate({ gift: {} })
+        .accounts({
+          from: from.publicKey,
+          to,
+          authority: authorityPda,
+          certificate: certPda,
+          pendingTransfer: getPendingTransferPda(certPda)[0],
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([from])
+        .rpc();
+
+    it("Un certificat lie au proprietaire ne peut etre ni propose ni mis en vente", async () => {
+      const owner = Keypair.generate();
+      await airdrop(owner.publicKey, 1);
+      const certPda = await issueCertificateTo("SOULBOUND-001", { standard: {} }, owner, 5000, { soulbound: {} });
+      expect((await program.account.certificate.fetch(certPda)).transferPolicy).to.deep.equal({ soulbound: {} });
+
+      try {
+        await offer(certPda, owner, unauthorized.publicKey);
+        expect.fail("Devrait lever une erreur CertificateSoulbound");
+      } catch (err: any) {
+        expect(err.toString()).to.include("CertificateSoulbound");
+      }
+
+      const [listingPda] = PublicKey.findProgramAddressSync([Buffer.from("listing"), certPda.toBuffer()], program.programId);
+      try {
+        await program.methods
+          .listCertificate(new anchor.BN(LAMPORTS_PER_SOL), null)
+          .accounts({
+            seller: owner.publicKey,
+            authority: authorityPda,
+            certificate: certPda,
+            listing: listingPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([owner])
+          .rpc();
+        expect.fail("Devrait lever une erreur CertificateSoulbound");
+      } catch (err: any) {
+        expect(err.toString()).to.include("CertificateSoulbound");
+      }
+    });
+
+    it("Un transfert sous approbation n'est acceptable qu'apres l'accord du certificateur emetteur", async () => {
+      const owner = Keypair.generate();
+      const recipient = Keypair.generate();
+      await airdrop(owner.publicKey, 1);
+      await airdrop(recipient.publicKey, 1);
+      const certPda = await issueCertificateTo("APPROVAL-001", { standard: {} }, owner, 5000, { certifierApproval: {} });
+      const [pendingTransferPda] = getPendingTransferPda(certPda);
+
+      // Attendre la fin du verrouillage post-émission
+      await new Promise(r => setTimeout(r, 21000));
+      await offer(certPda, owner, recipient.publicKey);
+
+      const accept = async () =>
+        program.methods
+          .acceptTransfer()
+          .accounts({
+            recipient: recipient.publicKey,
+            from: owner.publicKey,
+            initiator: owner.publicKey,
+            authority: authorityPda,
+            certificate: certPda,
+            pendingTransfer: pendingTransferPda,
+            fromActivity: getUserActivityPda(owner.publicKey)[0],
+            toActivity: getUserActivityPda(recipient.publicKey)[0],
+            provenancePage: await getNextProvenancePagePda(certPda),
+            ...noNftAccounts,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([recipient])
+          .rpc();
+
+      try {
+        await accept();
+        expect.fail("Devrait lever une erreur TransferApprovalRequired");
+      } catch (err: any) {
+        expect(err.toString()).to.include("TransferApprovalRequired");
+      }
+
+      const approve = (signer: Keypair, certifierKey: PublicKey) =>
+        program.methods
+          .approveTransfer()
+          .accounts({
+            operator: signer.publicKey,
+            certifier: certifierKey,
+            authority: authorityPda,
+            certifierProfile: getCertifierProfilePda(certifierKey)[0],
+            delegate: null,
+            certificate: certPda,
+            pendingTransfer: pendingTransferPda,
+          })
+          .signers([signer])
+          .rpc();
+
+      // Seul le certificateur émetteur peut approuver
+      try {
+        await approve(certifier2, certifier2.publicKey);
+        expect.fail("Devrait lever une erreur NotIssuingCertifier");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NotIssuingCertifier");
+      }
+
+      await approve(certifier, certifier.publicKey);
+      expect((await program.account.pendingTransfer.fetch(pendingTransferPda)).certifierApproved).to.be.true;
+
+      await accept();
+      const certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.owner.toBase58()).to.equal(recipient.publicKey.toBase58());
+      console.log("Transfert approuve par le certificateur puis accepte");
+    });
+  });
+
+  // ==================== TESTS JETON NFT ====================
+  describe("Tests jeton NFT", () => {
+    const getCertificateMintPda = (certificate: PublicKey) =>
+      PublicKey.findProgramAddressSync([Buffer.from("certificate_mint"), certificate.toBuffer()], program.programId)[0];
+    const getNftAccount = (mint: PublicKey, owner: PublicKey) =>
+      getAssociatedTokenAddressSync(mint, owner, false, TOKEN_2022_PROGRAM_ID);
+
+    // Émet un certificat Standard avec son jeton NFT dans la même instruction
+    async function issueWithNft(serial: string, owner: Keypair): Promise<[PublicKey, PublicKey, PublicKey]> {
+      const [certPda] = getCertificatePda(serial);
+      const mint = getCertificateMintPda(certPda);
+      const ownerNftAccount = getNftAccount(mint, owner.publicKey);
+      await program.methods
+        .issueCertificate(serial, "Omega", "Speedmaster", { standard: {} }, new anchor.BN(6000), "ipfs://QmSpeedmaster", sha256("ipfs://QmSpeedmaster"), { transferable: {} })
+        .accounts({
+          ...noTokenAccounts,
+          priceFeed: null,
+          operator: certifier.publicKey,
+          certifier: certifier.publicKey,
+          delegate: null,
+          owner: owner.publicKey,
+          authority: authorityPda,
+          certificate: certPda,
+          ownerActivity: getUserActivityPda(owner.publicKey)[0],
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          treasury: treasuryPda,
+          nftMint: mint,
+          ownerNftAccount,
+          nftTokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([certifier])
+        .rpc();
+      return [certPda, mint, ownerNftAccount];
+    }
+
+    it("Le proprietaire emet le jeton NFT, gele et synchronise avec le proprietaire", async () => {
+      const owner = Keypair.generate();
+      const newOwner = Keypair.generate();
+      await airdrop(owner.publicKey, 2);
+      await airdrop(newOwner.publicKey, 1);
+      const certPda = await issueCertificateTo("NFT-CERT-001", { premium: {} }, owner);
+      const mint = getCertificateMintPda(certPda);
+      const ownerNftAccount = getNftAccount(mint, owner.publicKey);
+
+      const mintNft = () =>
+        program.methods
+          .mintCertificateNft()
+          .accounts({
+            owner: owner.publicKey,
+            authority: authorityPda,
+            certificate: certPda,
+            mint,
+            ownerNftAccount,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([owner])
+          .rpc();
+      await mintNft();
+
+      let certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.nftMint.toBase58()).to.equal(mint.toBase58());
+      let tokenAccount = await getAccount(provider.connection, ownerNftAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(tokenAccount.amount)).to.equal(1);
+      expect(tokenAccount.isFrozen).to.be.true;
+
+      // Un seul jeton par certificat
+      try {
+        await mintNft();
+        expect.fail("Le second mint devrait echouer");
+      } catch (err: any) {
+        expect(err.toString()).to.not.include("Le second mint devrait echouer");
+      }
+
+      // Le jeton ne circule pas de portefeuille a portefeuille
+      const newOwnerNftAccount = getNftAccount(mint, newOwner.publicKey);
+      const createNewOwnerAccount = createAssociatedTokenAccountIdempotentInstruction(
+        newOwner.publicKey, newOwnerNftAccount, newOwner.publicKey, mint, TOKEN_2022_PROGRAM_ID
+      );
+      try {
+        const tx = new anchor.web3.Transaction().add(
+          createAssociatedTokenAccountIdempotentInstruction(owner.publicKey, newOwnerNftAccount, newOwner.publicKey, mint, TOKEN_2022_PROGRAM_ID),
+          createTransferCheckedInstruction(ownerNftAccount, mint, newOwnerNftAccount, owner.publicKey, 1, 0, [], TOKEN_2022_PROGRAM_ID)
+        );
+        await provider.sendAndConfirm(tx, [owner]);
+        expect.fail("Le transfert direct du jeton devrait echouer");
+      } catch (err: any) {
+        expect(err.toString()).to.not.include("Le transfert direct");
+      }
+
+      // Un changement de proprietaire par le programme exige les comptes du jeton
+      const correctAccounts = {
+        admin: admin.publicKey,
+        authority: authorityPda,
+        certificate: certPda,
+        fromActivity: getUserActivityPda(owner.publicKey)[0],
+        toActivity: getUserActivityPda(newOwner.publicKey)[0],
+        provenancePage: await getNextProvenancePagePda(certPda),
+        proposal: null,
+        operatorRole: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      };
+      try {
+        await program.methods
+          .correctOwner(newOwner.publicKey)
+          .accounts({ ...correctAccounts, ...noNftAccounts })
+          .signers([admin])
+          .rpc();
+        expect.fail("Devrait lever une erreur NftAccountsMissing");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NftAccountsMissing");
+      }
+
+      // Le jeton suit le certificat chez le nouveau proprietaire
+      await program.methods
+        .correctOwner(newOwner.publicKey)
+        .accounts({
+          ...correctAccounts,
+          nftMint: mint,
+          fromNftAccount: ownerNftAccount,
+          toNftAccount: newOwnerNftAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .preInstructions([createNewOwnerAccount])
+        .signers([admin, newOwner])
+        .rpc();
+
+      certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.owner.toBase58()).to.equal(newOwner.publicKey.toBase58());
+      tokenAccount = await getAccount(provider.connection, newOwnerNftAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(tokenAccount.amount)).to.equal(1);
+      expect(tokenAccount.isFrozen).to.be.true;
+      const previous = await getAccount(provider.connection, ownerNftAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(previous.amount)).to.equal(0);
+      console.log("Jeton NFT synchronise avec le proprietaire:", mint.toBase58());
+    });
+
+    it("Le jeton NFT emis a l'emission est brule au retrait du certificat", async () => {
+      const owner = Keypair.generate();
+      await airdrop(owner.publicKey, 1);
+      const [activityPda] = getUserActivityPda(owner.publicKey);
+
+      // Le jeton est émis dans la même instruction que le certificat
+      const [certPda, mint, ownerNftAccount] = await issueWithNft("NFT-CERT-002", owner);
+
+      const certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.nftMint.toBase58()).to.equal(mint.toBase58());
+      let tokenAccount = await getAccount(provider.connection, ownerNftAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(tokenAccount.amount)).to.equal(1);
+      expect(tokenAccount.isFrozen).to.be.true;
+
+      const retire = (nftAccounts: any) =>
+        program.methods
+          .retireCertificate({ destroyed: {} })
+          .accounts({
+            owner: owner.publicKey,
+            authority: authorityPda,
+            certificate: certPda,
+            ownerActivity: activityPda,
+            ...nftAccounts,
+          })
+          .signers([owner])
+          .rpc();
+
+      // Le retrait exige les comptes du jeton pour le brûler
+      try {
+        await retire({ nftMint: null, ownerNftAccount: null, tokenProgram: null });
+        expect.fail("Devrait lever une erreur NftAccountsMissing");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NftAccountsMissing");
+      }
+
+      await retire({ nftMint: mint, ownerNftAccount, tokenProgram: TOKEN_2022_PROGRAM_ID });
+
+      expect((await program.account.certificate.fetch(certPda)).status).to.deep.equal({ retired: {} });
+      tokenAccount = await getAccount(provider.connection, ownerNftAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(tokenAccount.amount)).to.equal(0);
+      const mintInfo = await getMint(provider.connection, mint, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(mintInfo.supply)).to.equal(0);
+      console.log("Jeton NFT brule au retrait:", mint.toBase58());
+    });
+
+    it("L'URI des metadonnees du jeton NFT suit la version publiee du certificat", async () => {
+      const owner = Keypair.generate();
+      await airdrop(owner.publicKey, 1);
+      const [certPda, mint] = await issueWithNft("NFT-CERT-003", owner);
+      const newUri = "ipfs://QmSpeedmasterRevisionAvecHistoriqueDeService";
+
+      const update = (nftAccounts: any) =>
+        program.methods
+          .updateMetadata(newUri, sha256(newUri))
+          .accounts({
+            operator: certifier.publicKey,
+            certifier: certifier.publicKey,
+            authority: authorityPda,
+            certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+            delegate: null,
+            certificate: certPda,
+            metadataVersion: getMetadataVersionPda(certPda, 0),
+            ...nftAccounts,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([certifier])
+          .rpc();
+
+      // Le mint doit être fourni pour garder le jeton synchronisé
+      try {
+        await update({ nftMint: null, tokenProgram: null });
+        expect.fail("Devrait lever une erreur NftAccountsMissing");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NftAccountsMissing");
+      }
+
+      await update({ nftMint: mint, tokenProgram: TOKEN_2022_PROGRAM_ID });
+
+      const certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.metadataUri).to.equal(newUri);
+      const tokenMetadata = await getTokenMetadata(provider.connection, mint, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(tokenMetadata!.uri).to.equal(newUri);
+    });
+  });
+
+  // ==================== TESTS FRAIS EN JETONS SPL ====================
+  describe("Tests frais en jetons SPL", () => {
+    const getFeeMintPda = (mint: PublicKey) =>
+      PublicKey.findProgramAddressSync([Buffer.from("fee_mint"), mint.toBuffer()], program.programId)[0];
+    const tokenAccount = async (mint: PublicKey, owner: PublicKey) =>
+      (await getOrCreateAssociatedTokenAccount(provider.connection, admin, mint, owner, true)).address;
+    const balance = async (account: PublicKey) => Number((await getAccount(provider.connection, account)).amount);
+
+    // Frais par gamme en unités du jeton (6 décimales)
+    const fees = { standard: 50_000_000, premium: 100_000_000, luxury: 200_000_000, exceptional: 500_000_000 };
+    let usdc: PublicKey;
+    let requester: Keypair;
+    let requesterTokens: PublicKey;
+
+    // Soumet une demande réglée en jetons, séquestre pré-créé pour le PDA de la demande
+    async function requestWithTokens(serial: string, paymentMint: PublicKey): Promise<[PublicKey, PublicKey]> {
+      const [requestPda] = getRequestPda(serial);
+      const escrow = await tokenAccount(paymentMint, requestPda);
+      await program.methods
+        .requestCertification(serial, "Rolex", "Submariner", { premium: {} }, new anchor.BN(12000), "ipfs://QmUsdc", sha256("ipfs://QmUsdc"), { transferable: {} }, certifier.publicKey)
+        .accounts({
+          requester: requester.publicKey,
+          authority: authorityPda,
+          request: requestPda,
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          feeMint: getFeeMintPda(usdc),
+          paymentMint,
+          payerTokenAccount: requesterTokens,
+          escrowTokenAccount: escrow,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          priceFeed: null,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([requester])
+        .rpc();
+      return [requestPda, escrow];
+    }
+
+    before(async () => {
+      usdc = await createMint(provider.connection, admin, admin.publicKey, null, 6);
+      requester = Keypair.generate();
+      await airdrop(requester.publicKey, 2);
+      requesterTokens = await tokenAccount(usdc, requester.publicKey);
+      await mintTo(provider.connection, admin, usdc, requesterTokens, admin, 1_000_000_000);
+    });
+
+    it("Le jeton est ajoute a la liste blanche apres le preavis", async () => {
+      const change = {
+        mint: usdc,
+        standardFee: new anchor.BN(fees.standard),
+        premiumFee: new anchor.BN(fees.premium),
+        luxuryFee: new anchor.BN(fees.luxury),
+        exceptionalFee: new anchor.BN(fees.exceptional),
+        enabled: true,
+      };
+      const queuedChangePda = await queueChange({ setFeeMint: change });
+      await new Promise((resolve) => setTimeout(resolve, TIMELOCK_WAIT_MS));
+
+      await program.methods
+        .setFeeMint(usdc, change.standardFee, change.premiumFee, change.luxuryFee, change.exceptionalFee, true)
+        .accounts({
+          executor: unauthorized.publicKey,
+          authority: authorityPda,
+          paymentMint: usdc,
+          feeMint: getFeeMintPda(usdc),
+          queuedChange: queuedChangePda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([unauthorized])
+        .rpc();
+
+      const feeMint = await program.account.feeMint.fetch(getFeeMintPda(usdc));
+      expect(feeMint.mint.toBase58()).to.equal(usdc.toBase58());
+      expect(feeMint.premiumFee.toNumber()).to.equal(fees.premium);
+      expect(feeMint.enabled).to.be.true;
+    });
+
+    it("Un jeton hors liste blanche est refuse", async () => {
+      const otherMint = await createMint(provider.connection, admin, admin.publicKey, null, 6);
+      try {
+        await requestWithTokens("USDC-REQ-000", otherMint);
+        expect.fail("Le paiement avec un jeton non accepte devrait echouer");
+      } catch (err: any) {
+        expect(err.toString()).to.include("FeeMintNotAccepted");
+      }
+    });
+
+    it("Les frais sont sequestres puis repartis a l'approbation", async () => {
+      const [requestPda, escrow] = await requestWithTokens("USDC-REQ-001", usdc);
+      const request = await program.account.certificationRequest.fetch(requestPda);
+      expect(request.feeMint.toBase58()).to.equal(usdc.toBase58());
+      expect(request.feePaid.toNumber()).to.equal(fees.premium);
+      expect(await balance(escrow)).to.equal(fees.premium);
+
+      const [vaultPda] = getCertifierVaultPda(certifier.publicKey);
+      const vaultTokens = await tokenAccount(usdc, vaultPda);
+      const treasuryTokens = await tokenAccount(usdc, treasuryPda);
+
+      await program.methods
+        .approveCertification()
+        .accounts({
+          ...noNftMintAccounts,
+          ...noTokenAccounts,
+          operator: certifier.publicKey,
+          certifier: certifier.publicKey,
+          delegate: null,
+          authority: authorityPda,
+          request: requestPda,
+          certificate: getCertificatePda("USDC-REQ-001")[0],
+          ownerActivity: getUserActivityPda(requester.publicKey)[0],
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          certifierVault: vaultPda,
+          treasury: treasuryPda,
+          requester: requester.publicKey,
+          paymentMint: usdc,
+          escrowTokenAccount: escrow,
+          certifierTokenAccount: vaultTokens,
+          treasuryTokenAccount: treasuryTokens,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([certifier])
+        .rpc();
+
+      const certifierShare = (fees.premium * 60) / 100;
+      expect(await balance(vaultTokens)).to.equal(certifierShare);
+      expect(await balance(treasuryTokens)).to.equal(fees.premium - certifierShare);
+      // Le séquestre est fermé, son loyer revient au demandeur
+      expect(await provider.connection.getAccountInfo(escrow)).to.be.null;
+    });
+
+    it("Les frais sequestres sont rembourses au rejet", async () => {
+      const before = await balance(requesterTokens);
+      const [requestPda, escrow] = await requestWithTokens("USDC-REQ-002", usdc);
+      expect(await balance(requesterTokens)).to.equal(before - fees.premium);
+
+      await program.methods
+        .rejectCertification({ insufficientDocumentation: {} }, "Photos illisibles")
+        .accounts({
+          ...noTokenAccounts,
+          operator: certifier.publicKey,
+          certifier: certifier.publicKey,
+          delegate: null,
+          authority: authorityPda,
+          request: requestPda,
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          requester: requester.publicKey,
+          certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
+          treasury: treasuryPda,
+          paymentMint: usdc,
+          escrowTokenAccount: escrow,
+          requesterTokenAccount: requesterTokens,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([certifier])
+        .rpc();
+
+      expect(await balance(requesterTokens)).to.equal(before);
+      expect(await provider.connection.getAccountInfo(escrow)).to.be.null;
+    });
+
+    it("Le certificateur retire ses gains en jetons vers son adresse de paiement", async () => {
+      const [vaultPda] = getCertifierVaultPda(certifier.publicKey);
+      const vault = await program.account.certifierVault.fetch(vaultPda);
+      const vaultTokens = await tokenAccount(usdc, vaultPda);
+      const payoutTokens = await tokenAccount(usdc, vault.payoutAddress);
+      const earned = await balance(vaultTokens);
+      const payoutBefore = await balance(payoutTokens);
+
+      await program.methods
+        .withdrawTokenEarnings(new anchor.BN(earned))
+        .accounts({
+          certifier: certifier.publicKey,
+          certifierVault: vaultPda,
+          paymentMint: usdc,
+          vaultTokenAccount: vaultTokens,
+          payoutTokenAccount: payoutTokens,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([certifier])
+        .rpc();
+
+      expect(await balance(vaultTokens)).to.equal(0);
+      expect(await balance(payoutTokens)).to.equal(payoutBefore + earned);
+    });
+
+    it("L'admin retire les jetons de la tresorerie", async () => {
+      const treasuryTokens = await tokenAccount(usdc, treasuryPda);
+      const destination = await tokenAccount(usdc, admin.publicKey);
+      const amount = await balance(treasuryTokens);
+
+      await program.methods
+        .withdrawTreasuryTokens(new anchor.BN(amount))
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          treasury: treasuryPda,
+          paymentMint: usdc,
+          treasuryTokenAccount: treasuryTokens,
+          destination,
+          proposal: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      expect(await balance(treasuryTokens)).to.equal(0);
+      expect(await balance(destination)).to.equal(amount);
+    });
+  });
+
+  // ==================== TESTS FRAIS EN EUROS ====================
+  describe("Tests frais en euros", () => {
+    const [priceFeedPda] = PublicKey.findProgramAddressSync([Buffer.from("price_feed")], program.programId);
+    const oracle = Keypair.generate();
+    const requester = Keypair.generate();
+    const SOL_PRICE_EUR_CENTS = 15_000; // 1 SOL = 150 EUR
+    const PREMIUM_FEE_EUR_CENTS = 5_000; // 50 EUR
+    let initialConfig: any;
+
+    const setOracle = (maxStaleness: number) =>
+      program.methods
+        .setPriceOracle(oracle.publicKey, new anchor.BN(maxStaleness))
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          priceFeed: priceFeedPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          proposal: null,
+        })
+        .signers([admin])
+        .rpc();
+
+    const publishPrice = (signer: Keypair, solPriceEurCents: number) =>
+      program.methods
+        .updatePrice(new anchor.BN(solPriceEurCents))
+        .accounts({ oracle: signer.publicKey, priceFeed: priceFeedPda })
+        .signers([signer])
+        .rpc();
+
+    const applyConfig = async (config: any) => {
+      const queuedChangePda = await queueChange({ updateConfig: { config } });
+      await new Promise((resolve) => setTimeout(resolve, TIMELOCK_WAIT_MS));
+      await program.methods
+        .updateConfig(config)
+        .accounts({ executor: admin.publicKey, authority: authorityPda, queuedChange: queuedChangePda })
+        .signers([admin])
+        .rpc();
+    };
+
+    const request = (serial: string, priceFeed: PublicKey | null) =>
+      program.methods
+        .requestCertification(serial, "Cartier", "Santos", { premium: {} }, new anchor.BN(8000), "ipfs://QmSantos", sha256("ipfs://QmSantos"), { transferable: {} }, certifier.publicKey)
+        .accounts({
+          ...noTokenAccounts,
+          requester: requester.publicKey,
+          authority: authorityPda,
+          request: getRequestPda(serial)[0],
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          priceFeed,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([requester])
+        .rpc();
+
+    const reject = (serial: string) =>
+      program.methods
+        .rejectCertification({ insufficientDocumentation: {} }, "Demande de test")
+        .accounts({
+          ...noTokenAccounts,
+          operator: certifier.publicKey,
+          certifier: certifier.publicKey,
+          delegate: null,
+          authority: authorityPda,
+          request: getRequestPda(serial)[0],
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          requester: requester.publicKey,
+          certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
+          treasury: treasuryPda,
+        })
+        .signers([certifier])
+        .rpc();
+
+    before(async () => {
+      await airdrop(requester.publicKey, 2);
+      await airdrop(oracle.publicKey, 1);
+      initialConfig = (await program.account.certificationAuthority.fetch(authorityPda)).config;
+    });
+
+    after(async () => {
+      // Restaurer les frais en lamports pour les tests suivants
+      await applyConfig(initialConfig);
+    });
+
+    it("Seul l'oracle designe peut publier le cours", async () => {
+      await setOracle(60);
+
+      try {
+        await publishPrice(unauthorized, SOL_PRICE_EUR_CENTS);
+        expect.fail("Devrait lever une erreur UnauthorizedOracle");
+      } catch (err: any) {
+        expect(err.toString()).to.include("UnauthorizedOracle");
+      }
+
+      await publishPrice(oracle, SOL_PRICE_EUR_CENTS);
+      const priceFeed = await program.account.priceFeed.fetch(priceFeedPda);
+      expect(priceFeed.oracle.toBase58()).to.equal(oracle.publicKey.toBase58());
+      expect(priceFeed.solPriceEurCents.toNumber()).to.equal(SOL_PRICE_EUR_CENTS);
+      expect(priceFeed.updatedAt.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("Les frais en euros sont convertis en lamports au cours de l'oracle", async () => {
+      await applyConfig({
+        ...initialConfig,
+        premiumFee: new anchor.BN(PREMIUM_FEE_EUR_CENTS),
+        feeCurrency: { eurCents: {} },
+      });
+      await publishPrice(oracle, SOL_PRICE_EUR_CENTS);
+
+      // Sans cours, la conversion est impossible
+      try {
+        await request("EUR-REQ-000", null);
+        expect.fail("Devrait lever une erreur PriceFeedRequired");
+      } catch (err: any) {
+        expect(err.toString()).to.include("PriceFeedRequired");
+      }
+
+      await request("EUR-REQ-001", priceFeedPda);
+      const created = await program.account.certificationRequest.fetch(getRequestPda("EUR-REQ-001")[0]);
+      const expected = Math.floor((PREMIUM_FEE_EUR_CENTS * LAMPORTS_PER_SOL) / SOL_PRICE_EUR_CENTS);
+      expect(created.feePaid.toNumber()).to.equal(expected);
+      console.log("50 EUR a 150 EUR/SOL =", created.feePaid.toString(), "lamports");
+
+      await reject("EUR-REQ-001");
+    });
+
+    it("Un cours perime est refuse", async () => {
+      await setOracle(1);
+      await publishPrice(oracle, SOL_PRICE_EUR_CENTS);
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      try {
+        await request("EUR-REQ-002", priceFeedPda);
+        expect.fail("Devrait lever une erreur PriceFeedStale");
+      } catch (err: any) {
+        expect(err.toString()).to.include("PriceFeedStale");
+      }
+      await setOracle(60);
+    });
+  });
+
+  // ==================== TESTS DEVIS ====================
+  describe("Tests devis", () => {
+    const requester = Keypair.generate();
+    let certifierProfilePda: PublicKey;
+    const QUOTE_AMOUNT = 0.3 * LAMPORTS_PER_SOL;
+
+    const requestQuote = (serial: string) =>
+      program.methods
+        .requestQuote(serial, "A. Lange & Söhne", "Datograph", { exceptional: {} }, new anchor.BN(900000), "ipfs://QmDatograph", sha256("ipfs://QmDatograph"), { transferable: {} }, certifier.publicKey)
+        .accounts({
+          requester: requester.publicKey,
+          authority: authorityPda,
+          request: getRequestPda(serial)[0],
+          certifierProfile: certifierProfilePda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([requester])
+        .rpc();
+
+    const postQuote = (serial: string, amount: number, expiresAt: number) =>
+      program.methods
+        .postQuote(new anchor.BN(amount), new anchor.BN(expiresAt))
+        .accounts({
+          operator: certifier.publicKey,
+          certifier: certifier.publicKey,
+          request: getRequestPda(serial)[0],
+          certifierProfile: certifierProfilePda,
+          delegate: null,
+        })
+        .signers([certifier])
+        .rpc();
+
+    const acceptQuote = (serial: string) =>
+      program.methods
+        .acceptQuote()
+        .accounts({
+          requester: requester.publicKey,
+          authority: authorityPda,
+          request: getRequestPda(serial)[0],
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([requester])
+        .rpc();
+
+    const cancelRequest = (serial: string) =>
+      program.methods
+        .cancelRequest()
+        .accounts({
+          requester: requester.publicKey,
+          request: getRequestPda(serial)[0],
+          certifierProfile: certifierProfilePda,
+        })
+        .signers([requester])
+        .rpc();
+
+    const nowSeconds = () => Math.floor(Date.now() / 1000);
+
+    before(async () => {
+      [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+      await airdrop(requester.publicKey, 2);
+    });
+
+    it("Le demandeur accepte le devis puis la demande suit le flux normal", async () => {
+      const serial = "QUOTE-REQ-001";
+      const [requestPda] = getRequestPda(serial);
+      const profileBefore = await program.account.certifierProfile.fetch(certifierProfilePda);
+
+      await requestQuote(serial);
+      let request = await program.account.certificationRequest.fetch(requestPda);
+      expect(request.status).to.deep.equal({ awaitingQuote: {} });
+      expect(request.feePaid.toNumber()).to.equal(0);
+      let profile = await program.account.certifierProfile.fetch(certifierProfilePda);
+      expect(profile.currentLoad).to.equal(profileBefore.currentLoad + 1);
+
+      // Pas de devis publié : rien à accepter
+      try {
+        await acceptQuote(serial);
+        expect.fail("Devrait lever une erreur NoQuoteToAccept");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NoQuoteToAccept");
+      }
+
+      await postQuote(serial, QUOTE_AMOUNT, nowSeconds() + 3600);
+      request = await program.account.certificationRequest.fetch(requestPda);
+      expect(request.status).to.deep.equal({ quoted: {} });
+      expect(request.quoteAmount.toNumber()).to.equal(QUOTE_AMOUNT);
+
+      const balanceBefore = await provider.connection.getBalance(requestPda);
+      await acceptQuote(serial);
+      request = await program.account.certificationRequest.fetch(requestPda);
+      expect(request.status.pending).to.exist;
+      expect(request.feePaid.toNumber()).to.equal(QUOTE_AMOUNT);
+      expect(await provider.connection.getBalance(requestPda)).to.equal(balanceBefore + QUOTE_AMOUNT);
+
+      const [vaultPda] = getCertifierVaultPda(certifier.publicKey);
+      const vaultBefore = await program.account.certifierVault.fetch(vaultPda);
+      await program.methods
+        .approveCertification()
+        .accounts({
+          ...noNftMintAccounts,
+          ...noTokenAccounts,
+          requester: null,
+          operator: certifier.publicKey,
+          certifier: certifier.publicKey,
+          delegate: null,
+          authority: authorityPda,
+          request: requestPda,
+          certificate: getCertificatePda(serial)[0],
+          ownerActivity: getUserActivityPda(requester.publicKey)[0],
+          certifierProfile: certifierProfilePda,
+          certifierVault: vaultPda,
+          treasury: treasuryPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([certifier])
+        .rpc();
+
+      // La part du certificateur est calculée sur le montant du devis
+      const vaultAfter = await program.account.certifierVault.fetch(vaultPda);
+      expect(vaultAfter.lifetimeEarnings.toNumber() - vaultBefore.lifetimeEarnings.toNumber()).to.equal((QUOTE_AMOUNT * 60) / 100);
+      profile = await program.account.certifierProfile.fetch(certifierProfilePda);
+      expect(profile.currentLoad).to.equal(profileBefore.currentLoad);
+    });
+
+    it("Le demandeur peut annuler une demande non payee puis redemander le meme numero", async () => {
+      const serial = "QUOTE-REQ-002";
+      const [requestPda] = getRequestPda(serial);
+      const profileBefore = await program.account.certifierProfile.fetch(certifierProfilePda);
+      await requestQuote(serial);
+      await postQuote(serial, QUOTE_AMOUNT, nowSeconds() + 3600);
+
+      const requesterBefore = await provider.connection.getBalance(requester.publicKey);
+      const rent = await provider.connection.getBalance(requestPda);
+      await cancelRequest(serial);
+
+      // La demande est fermée et son loyer rendu au demandeur
+      expect(await program.account.certificationRequest.fetchNullable(requestPda)).to.be.null;
+      expect(await provider.connection.getBalance(requester.publicKey)).to.be.greaterThan(requesterBefore + rent - 10_000);
+      const profile = await program.account.certifierProfile.fetch(certifierProfilePda);
+      expect(profile.currentLoad).to.equal(profileBefore.currentLoad);
+      expect(profile.totalCancelled.toNumber()).to.equal(profileBefore.totalCancelled.toNumber() + 1);
+
+      // Le numéro de série est libéré pour une nouvelle demande
+      await requestQuote(serial);
+      const request = await program.account.certificationRequest.fetch(requestPda);
+      expect(request.status).to.deep.equal({ awaitingQuote: {} });
+      await cancelRequest(serial);
+    });
+
+    it("Un devis expire ne peut plus etre accepte et libere le certificateur", async () => {
+      const serial = "QUOTE-REQ-003";
+      const profileBefore = await program.account.certifierProfile.fetch(certifierProfilePda);
+      await requestQuote(serial);
+      await postQuote(serial, QUOTE_AMOUNT, nowSeconds() + 2);
+      await new Promise((resolve) => setTimeout(resolve, 4000));
+
+      try {
+        await acceptQuote(serial);
+        expect.fail("Devrait lever une erreur QuoteExpired");
+      } catch (err: any) {
+        expect(err.toString()).to.include("QuoteExpired");
+      }
+
+      await program.methods
+        .expireQuote()
+        .accounts({
+          caller: unauthorized.publicKey,
+          requester: requester.publicKey,
+          request: getRequestPda(serial)[0],
+          certifierProfile: certifierProfilePda,
+        })
+        .signers([unauthorized])
+        .rpc();
+
+      expect(await program.account.certificationRequest.fetchNullable(getRequestPda(serial)[0])).to.be.null;
+      const profile = await program.account.certifierProfile.fetch(certifierProfilePda);
+      expect(profile.currentLoad).to.equal(profileBefore.currentLoad);
+      expect(profile.totalExpired.toNumber()).to.equal(profileBefore.totalExpired.toNumber() + 1);
+    });
+  });
+
+  // ==================== TESTS FRAIS D'INSPECTION ====================
+  describe("Tests frais d'inspection", () => {
+    const requester = Keypair.generate();
+
+    const request = (serial: string) =>
+      program.methods
+        .requestCertification(serial, "Hublot", "Big Bang", { premium: {} }, new anchor.BN(15000), "ipfs://QmBigBang", sha256("ipfs://QmBigBang"), { transferable: {} }, certifier.publicKey)
+        .accounts({
+          ...noTokenAccounts,
+          requester: requester.publicKey,
+          authority: authorityPda,
+          request: getRequestPda(serial)[0],
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          priceFeed: null,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([requester])
+        .rpc();
+
+    const reject = (serial: string, code: any, reason: string) =>
+      program.methods
+        .rejectCertification(code, reason)
+        .accounts({
+          ...noTokenAccounts,
+          operator: certifier.publicKey,
+          certifier: certifier.publicKey,
+          delegate: null,
+          authority: authorityPda,
+          request: getRequestPda(serial)[0],
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          requester: requester.publicKey,
+          certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
+          treasury: treasuryPda,
+        })
+        .signers([certifier])
+        .rpc();
+
+    before(async () => {
+      await airdrop(requester.publicKey, 2);
+    });
+
+    it("Une contrefacon detectee a l'inspection retient les frais d'inspection", async () => {
+      const serial = "INSPECT-REQ-001";
+      await request(serial);
+      const { config } = await program.account.certificationAuthority.fetch(authorityPda);
+      const feePaid = (await program.account.certificationRequest.fetch(getRequestPda(serial)[0])).feePaid.toNumber();
+      const inspectionFee = Math.floor((feePaid * config.inspectionFeePercent) / 100);
+      const certifierShare = Math.floor((inspectionFee * config.certifierSharePercent) / 100);
+
+      const [vaultPda] = getCertifierVaultPda(certifier.publicKey);
+      const vaultBefore = await program.account.certifierVault.fetch(vaultPda);
+      const treasuryBefore = await program.account.treasuryVault.fetch(treasuryPda);
+      const requesterBefore = await provider.connection.getBalance(requester.publicKey);
+
+      await reject(serial, { counterfeit: {} }, "Mouvement non conforme au calibre annonce");
+
+      const rejected = await program.account.certificationRequest.fetch(getRequestPda(serial)[0]);
+      expect(rejected.status.rejected).to.exist;
+      expect(rejected.rejectionCode).to.deep.equal({ counterfeit: {} });
+      expect(rejected.inspectionFee.toNumber()).to.equal(inspectionFee);
+      expect(inspectionFee).to.be.greaterThan(0);
+
+      expect(await provider.connection.getBalance(requester.publicKey)).to.equal(requesterBefore + feePaid - inspectionFee);
+      const vaultAfter = await program.account.certifierVault.fetch(vaultPda);
+      expect(vaultAfter.lifetimeEarnings.toNumber() - vaultBefore.lifetimeEarnings.toNumber()).to.equal(certifierShare);
+      const treasuryAfter = await program.account.treasuryVault.fetch(treasuryPda);
+      expect(treasuryAfter.totalCollected.toNumber() - treasuryBefore.totalCollected.toNumber()).to.equal(inspectionFee - certifierShare);
+    });
+
+    it("Un rejet sans inspection rembourse integralement les frais", async () => {
+      const serial = "INSPECT-REQ-002";
+      await request(serial);
+      const feePaid = (await program.account.certificationRequest.fetch(getRequestPda(serial)[0])).feePaid.toNumber();
+      const requesterBefore = await provider.connection.getBalance(requester.publicKey);
+
+      await reject(serial, { watchNotReceived: {} }, "Montre jamais deposee");
+
+      const rejected = await program.account.certificationRequest.fetch(getRequestPda(serial)[0]);
+      expect(rejected.rejectionCode).to.deep.equal({ watchNotReceived: {} });
+      expect(rejected.inspectionFee.toNumber()).to.equal(0);
+      expect(await provider.connection.getBalance(requester.publicKey)).to.equal(requesterBefore + feePaid);
+    });
+  });
+
+  // ==================== TESTS RETRAIT DES CERTIFICATS ====================
+  describe("Tests retrait des certificats", () => {
+    const retire = (certPda: PublicKey, owner: Keypair, reason: any) =>
+      program.methods
+        .retireCertificate(reason)
+        .accounts({
+          owner: owner.publicKey,
+          authority: authorityPda,
+          certificate: certPda,
+          ownerActivity: getUserActivityPda(owner.publicKey)[0],
+          nftMint: null,
+          ownerNftAccount: null,
+          tokenProgram: null,
+        })
+        .signers([owner])
+        .rpc();
+
+    it("Retrait immediat d'un certificat Standard : emplacement libere, transferts bloques", async () => {
+      const owner = Keypair.generate();
+      await airdrop(owner.publicKey, 1);
+      const certPda = await issueCertificateTo("RETIRE-STD-001", { standard: {} }, owner);
+      const [activityPda] = getUserActivityPda(owner.publicKey);
+      expect((await program.account.userActivity.fetch(activityPda)).certificateCount).to.equal(1);
+
+      try {
+        await retire(certPda, unauthorized, { destroyed: {} });
+        expect.fail("Devrait lever une erreur NotOwner");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NotOwner");
+      }
+
+      await retire(certPda, owner, { destroyed: {} });
+
+      const certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.status).to.deep.equal({ retired: {} });
+      expect(certificate.retirementReason).to.deep.equal({ destroyed: {} });
+      expect(certificate.retiredAt.toNumber()).to.be.greaterThan(0);
+      expect((await program.account.userActivity.fetch(activityPda)).certificateCount).to.equal(0);
+
+      // Plus aucun transfert possible
+      try {
+        await program.methods
+          .transferCertificate({ gift: {} })
+          .accounts({
+            from: owner.publicKey,
+            to: owner2.publicKey,
+            authority: authorityPda,
+            certificate: certPda,
+            pendingTransfer: getPendingTransferPda(certPda)[0],
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([owner])
+          .rpc();
+        expect.fail("Devrait lever une erreur CertificateRetired");
+      } catch (err: any) {
+        expect(err.toString()).to.include("CertificateRetired");
+      }
+
+      // Récupération du loyer : le compte est compacté mais reste lisible
+      const sizeBefore = (await provider.connection.getAccountInfo(certPda))!.data.length;
+      const balanceBefore = await provider.connection.getBalance(owner.publicKey);
+      await program.methods
+        .reclaimCertificateRent()
+        .accounts({ owner: owner.publicKey, certificate: certPda })
+        .signers([owner])
+        .rpc();
+      const sizeAfter = (await provider.connection.getAccountInfo(certPda))!.data.length;
+      expect(sizeAfter).to.be.lessThan(sizeBefore);
+      expect(await provider.connection.getBalance(owner.publicKey)).to.be.greaterThan(balanceBefore);
+      const compacted = await program.account.certificate.fetch(certPda);
+      expect(compacted.serialNumber).to.equal("RETIRE-STD-001");
+      expect(compacted.status).to.deep.equal({ retired: {} });
+      console.log("Certificat retire, loyer recupere:", sizeBefore - sizeAfter, "octets");
+    });
+
+    it("Retrait d'un certificat Exceptional soumis a confirmation de l'admin", async () => {
+      const owner = Keypair.generate();
+      await airdrop(owner.publicKey, 1);
+      const certPda = await issueCertificateTo("RETIRE-EXC-001", { exceptional: {} }, owner);
+      const [activityPda] = getUserActivityPda(owner.publicKey);
+
+      const confirm = (approved: boolean) =>
+        program.methods
+          .confirmRetirement(approved)
+          .accounts({
+            admin: admin.publicKey,
+            authority: authorityPda,
+            certificate: certPda,
+            ownerActivity: activityPda,
+            proposal: null,
+            nftMint: null,
+            ownerNftAccount: null,
+            tokenProgram: null,
+          })
+          .signers([admin])
+          .rpc();
+
+      // Refus : le certificat est remis en circulation
+      await retire(certPda, owner, { exported: {} });
+      let certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.status).to.deep.equal({ retirementPending: {} });
+      expect((await program.account.userActivity.fetch(activityPda)).certificateCount).to.equal(1);
+      await confirm(false);
+      certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.status).to.deep.equal({ active: {} });
+      expect(certificate.retirementReason).to.be.null;
+
+      // Le loyer ne peut être récupéré qu'après un retrait définitif
+      try {
+        await program.methods
+          .reclaimCertificateRent()
+          .accounts({ owner: owner.publicKey, certificate: certPda })
+          .signers([owner])
+          .rpc();
+        expect.fail("Devrait lever une erreur CertificateNotRetired");
+      } catch (err: any) {
+        expect(err.toString()).to.include("CertificateNotRetired");
+      }
+
+      // Confirmation : retrait définitif
+      await retire(certPda, owner, { partedOut: {} });
+      await confirm(true);
+      certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.status).to.deep.equal({ retired: {} });
+      expect((await program.account.userActivity.fetch(activityPda)).certificateCount).to.equal(0);
+
+      try {
+        await confirm(true);
+        expect.fail("Devrait lever une erreur RetirementNotPending");
+      } catch (err: any) {
+        expect(err.toString()).to.include("RetirementNotPending");
+      }
+      console.log("Retrait Exceptional confirme par l'admin");
+    });
+  });
+
+  // ==================== TESTS CARNET D'ENTRETIEN ====================
+  describe("Tests carnet d'entretien", () => {
+    const getServiceRecordPda = (certificate: PublicKey, index: number) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("service_record"), certificate.toBuffer(), new anchor.BN(index).toArrayLike(Buffer, "le", 4)],
+        program.programId
+      )[0];
+
+    const addService = async (operator: Keypair, serial: string, servicedAt: number) => {
+      const [certPda] = getCertificatePda(serial);
+      const { serviceCount } = await program.account.certificate.fetch(certPda);
+      await program.methods
+        .addServiceRecord(
+          new anchor.BN(servicedAt),
+          "Revision complete du mouvement",
+          "Joints, couronne",
+          "ipfs://QmServiceInvoice001"
+        )
+        .accounts({
+          operator: operator.publicKey,
+          certifier: certifier.publicKey,
+          delegate: null,
+          authority: authorityPda,
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          certificate: certPda,
+          serviceRecord: getServiceRecordPda(certPda, serviceCount),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([operator])
+        .rpc();
+      return getServiceRecordPda(certPda, serviceCount);
+    };
+
+    it("Un certificateur agree inscrit un entretien lisible par tous", async () => {
+      const serial = "ROLEX-SUB-001-V5";
+      const [certPda] = getCertificatePda(serial);
+      const servicedAt = Math.floor(Date.now() / 1000) - 86400;
+
+      try {
+        await addService(unauthorized, serial, servicedAt);
+        expect.fail("Devrait lever une erreur DelegateNotAuthorized");
+      } catch (err: any) {
+        expect(err.toString()).to.include("DelegateNotAuthorized");
+      }
+
+      try {
+        await addService(certifier, serial, Math.floor(Date.now() / 1000) + 86400);
+        expect.fail("Devrait lever une erreur InvalidServiceDate");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidServiceDate");
+      }
+
+      const recordPda = await addService(certifier, serial, servicedAt);
+      const record = await program.account.serviceRecord.fetch(recordPda);
+      expect(record.certificate.toBase58()).to.equal(certPda.toBase58());
+      expect(record.index).to.equal(0);
+      expect(record.certifier.toBase58()).to.equal(certifier.publicKey.toBase58());
+      expect(record.servicedAt.toNumber()).to.equal(servicedAt);
+      expect(record.partsReplaced).to.equal("Joints, couronne");
+      expect(record.evidenceUri).to.equal("ipfs://QmServiceInvoice001");
+
+      // Résumé sur le certificat
+      const certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.serviceCount).to.equal(1);
+      expect(certificate.lastServicedAt.toNumber()).to.equal(servicedAt);
+      console.log("Entretien enregistre pour", serial);
+    });
+
+    it("On ne peut pas enregistrer d'entretien sur un certificat retire", async () => {
+      try {
+        await addService(certifier, "RETIRE-STD-001", Math.floor(Date.now() / 1000) - 60);
+        expect.fail("Devrait lever une erreur CertificateRetired");
+      } catch (err: any) {
+        expect(err.toString()).to.include("CertificateRetired");
+      }
+    });
+  });
+
+  // ==================== TESTS REEVALUATION ====================
+  describe("Tests reevaluation", () => {
+    const getAppraisalRequestPda = (certificate: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("appraisal_request"), certificate.toBuffer()],
+        program.programId
+      )[0];
+
+    const getAppraisalRecordPda = (certificate: PublicKey, index: number) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("appraisal"), certificate.toBuffer(), new anchor.BN(index).toArrayLike(Buffer, "le", 4)],
+        program.programId
+      )[0];
+
+    it("Reevaluation avec montee en gamme : difference de frais payee, compteurs ajustes", async () => {
+      const owner = Keypair.generate();
+      await airdrop(owner.publicKey, 2);
+      const certPda = await issueCertificateTo("APPRAISAL-001", { standard: {} }, owner, 4000);
+      const appraisalRequestPda = getAppraisalRequestPda(certPda);
+      const deposit = new anchor.BN(300_000_000);
+
+      await program.methods
+        .requestAppraisal(certifier.publicKey, deposit)
+        .accounts({
+          owner: owner.publicKey,
+          authority: authorityPda,
+          certificate: certPda,
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          appraisalRequest: appraisalRequestPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+
+      const complete = (signer: Keypair, newValue: number, newType: any) =>
+        program.methods
+          .completeAppraisal(new anchor.BN(newValue), newType)
+          .accounts({
+            operator: signer.publicKey,
+            certifier: certifier.publicKey,
+            owner: owner.publicKey,
+            authority: authorityPda,
+            certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+            delegate: null,
+            certificate: certPda,
+            appraisalRequest: appraisalRequestPda,
+            appraisalRecord: getAppraisalRecordPda(certPda, 0),
+            certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
+            treasury: treasuryPda,
+            priceFeed: null,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([signer])
+          .rpc();
+
+      // Le dépôt ne couvre pas un passage en Exceptional
+      try {
+        await complete(certifier, 150000, { exceptional: {} });
+        expect.fail("Devrait lever une erreur AppraisalDepositTooLow");
+      } catch (err: any) {
+        expect(err.toString()).to.include("AppraisalDepositTooLow");
+      }
+
+      const authorityBefore = await program.account.certificationAuthority.fetch(authorityPda);
+      const vaultBefore = await program.account.certifierVault.fetch(getCertifierVaultPda(certifier.publicKey)[0]);
+      const ownerBalanceBefore = await provider.connection.getBalance(owner.publicKey);
+      await complete(certifier, 30000, { luxury: {} });
+
+      const { config } = authorityBefore;
+      const feeDifference = config.luxuryFee.sub(config.standardFee);
+      const certifierShare = feeDifference.muln(config.certifierSharePercent).divn(100);
+
+      const certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.estimatedValue.toNumber()).to.equal(30000);
+      expect(certificate.certType).to.deep.equal({ luxury: {} });
+      expect(certificate.appraisalCount).to.equal(1);
+
+      const authorityAfter = await program.account.certificationAuthority.fetch(authorityPda);
+      expect(authorityAfter.standardCount.toNumber()).to.equal(authorityBefore.standardCount.toNumber() - 1);
+      expect(authorityAfter.luxuryCount.toNumber()).to.equal(authorityBefore.luxuryCount.toNumber() + 1);
+      expect(authorityAfter.totalIssued.toString()).to.equal(authorityBefore.totalIssued.toString());
+
+      const vaultAfter = await program.account.certifierVault.fetch(getCertifierVaultPda(certifier.publicKey)[0]);
+      expect(vaultAfter.lifetimeEarnings.sub(vaultBefore.lifetimeEarnings).toString()).to.equal(certifierShare.toString());
+
+      // Le reliquat du dépôt revient au propriétaire
+      const refund = deposit.sub(feeDifference).toNumber();
+      expect(await provider.connection.getBalance(owner.publicKey) - ownerBalanceBefore).to.be.at.least(refund);
+      expect(await program.account.appraisalRequest.fetchNullable(appraisalRequestPda)).to.be.null;
+
+      const record = await program.account.appraisalRecord.fetch(getAppraisalRecordPda(certPda, 0));
+      expect(record.previousValue.toNumber()).to.equal(4000);
+      expect(record.newValue.toNumber()).to.equal(30000);
+      expect(record.previousType).to.deep.equal({ standard: {} });
+      expect(record.newType).to.deep.equal({ luxury: {} });
+      expect(record.feePaid.toString()).to.equal(feeDifference.toString());
+      console.log("Reevaluation Standard -> Luxury, difference:", feeDifference.toString(), "lamports");
+    });
+
+    it("Le proprietaire peut annuler sa demande et recuperer son depot", async () => {
+      const owner = Keypair.generate();
+      await airdrop(owner.publicKey, 2);
+      const certPda = await issueCertificateTo("APPRAISAL-002", { premium: {} }, owner, 9000);
+      const appraisalRequestPda = getAppraisalRequestPda(certPda);
+      const requestAccounts = (signer: PublicKey) => ({
+        owner: signer,
+        authority: authorityPda,
+        certificate: certPda,
+        certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+        appraisalRequest: appraisalRequestPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      });
+
+      // Seul le propriétaire peut demander une réévaluation
+      try {
+        await program.methods
+          .requestAppraisal(certifier.publicKey, new anchor.BN(0))
+          .accounts(requestAccounts(unauthorized.publicKey))
+          .signers([unauthorized])
+          .rpc();
+        expect.fail("Devrait lever une erreur NotOwner");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NotOwner");
+      }
+
+      const balanceBefore = await provider.connection.getBalance(owner.publicKey);
+      await program.methods
+        .requestAppraisal(certifier.publicKey, new anchor.BN(200_000_000))
+        .accounts(requestAccounts(owner.publicKey))
+        .signers([owner])
+        .rpc();
+      await program.methods
+        .cancelAppraisal()
+        .accounts({ owner: owner.publicKey, certificate: certPda, appraisalRequest: appraisalRequestPda })
+        .signers([owner])
+        .rpc();
+
+      // Dépôt et loyer restitués (aux frais de transaction près)
+      expect(await program.account.appraisalRequest.fetchNullable(appraisalRequestPda)).to.be.null;
+      expect(balanceBefore - await provider.connection.getBalance(owner.publicKey)).to.be.lessThan(100_000);
+      const certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.certType).to.deep.equal({ premium: {} });
+      console.log("Demande de reevaluation annulee, depot restitue");
+    });
+  });
+
+  // ==================== TESTS METADONNEES VERSIONNEES ====================
+  describe("Tests metadonnees versionnees", () => {
+    it("Le certificateur emetteur publie une nouvelle version, l'ancienne est archivee", async () => {
+      const serial = "ROLEX-SUB-001-V5";
+      const [certPda] = getCertificatePda(serial);
+      const before = await program.account.certificate.fetch(certPda);
+      const newUri = "ipfs://QmRolexSubmariner001-v1";
+
+      const update = (signer: Keypair, certifierKey: PublicKey) =>
+        program.methods
+          .updateMetadata(newUri, sha256(newUri))
+          .accounts({
+            operator: signer.publicKey,
+            certifier: certifierKey,
+            authority: authorityPda,
+            certifierProfile: getCertifierProfilePda(certifierKey)[0],
+            delegate: null,
+            certificate: certPda,
+            metadataVersion: getMetadataVersionPda(certPda, before.metadataVersion),
+            nftMint: null,
+            tokenProgram: null,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([signer])
+          .rpc();
+
+      // Un autre certificateur agréé ne peut pas modifier les métadonnées
+      try {
+        await update(certifier2, certifier2.publicKey);
+        expect.fail("Devrait lever une erreur NotIssuingCertifier");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NotIssuingCertifier");
+      }
+
+      await update(certifier, certifier.publicKey);
+
+      const certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.metadataUri).to.equal(newUri);
+      expect(certificate.metadataHash).to.deep.equal(sha256(newUri));
+      expect(certificate.metadataVersion).to.equal(before.metadataVersion + 1);
+
+      const archive = await program.account.metadataVersion.fetch(getMetadataVersionPda(certPda, before.metadataVersion));
+      expect(archive.version).to.equal(before.metadataVersion);
+      expect(archive.metadataUri).to.equal(before.metadataUri);
+      expect(archive.metadataHash).to.deep.equal(before.metadataHash);
+      expect(archive.supersededBy.toBase58()).to.equal(certifier.publicKey.toBase58());
+      console.log("Metadonnees publiees en version", certificate.metadataVersion);
+    });
+  });
+
+  // ==================== TESTS VALIDITE ET RECERTIFICATION ====================
+  describe("Tests validite et recertification", () => {
+    const recertify = (certPda: PublicKey, owner: Keypair, operator: Keypair) =>
+      program.methods
+        .recertify()
+        .accounts({
+          owner: owner.publicKey,
+          operator: operator.publicKey,
+          certifier: certifier.publicKey,
+          authority: authorityPda,
+          certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          delegate: null,
+          certificate: certPda,
+          certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
+          treasury: treasuryPda,
+          priceFeed: null,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([owner, operator])
+        .rpc();
+
+    it("La validite suit la politique de la gamme et la recertification la renouvelle", async () => {
+      const owner = Keypair.generate();
+      await airdrop(owner.publicKey, 2);
+      const certPda = await issueCertificateTo("VALIDITY-LUX-001", { luxury: {} }, owner);
+      const { config } = await program.account.certificationAuthority.fetch(authorityPda);
+
+      const issued = await program.account.certificate.fetch(certPda);
+      expect(issued.validUntil.toNumber()).to.equal(issued.createdAt.toNumber() + config.luxuryValidity.toNumber());
+      expect(issued.lastCertifiedBy.toBase58()).to.equal(certifier.publicKey.toBase58());
+
+      // Seul un certificateur agréé (ou son délégué) peut recertifier
+      try {
+        await recertify(certPda, owner, unauthorized);
+        expect.fail("Devrait lever une erreur DelegateNotAuthorized");
+      } catch (err: any) {
+        expect(err.toString()).to.include("DelegateNotAuthorized");
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 1500));
+      const treasuryBefore = (await program.account.treasuryVault.fetch(treasuryPda)).totalCollected.toNumber();
+      await recertify(certPda, owner, certifier);
+
+      const renewed = await program.account.certificate.fetch(certPda);
+      expect(renewed.lastCertifiedAt.toNumber()).to.be.greaterThan(issued.lastCertifiedAt.toNumber());
+      expect(renewed.validUntil.toNumber()).to.equal(renewed.lastCertifiedAt.toNumber() + config.luxuryValidity.toNumber());
+      expect(renewed.owner.toBase58()).to.equal(owner.publicKey.toBase58());
+
+      const fee = Math.floor((config.luxuryFee.toNumber() * config.recertificationFeePercent) / 100);
+      const certifierShare = Math.floor((fee * config.certifierSharePercent) / 100);
+      const treasuryAfter = (await program.account.treasuryVault.fetch(treasuryPda)).totalCollected.toNumber();
+      expect(treasuryAfter - treasuryBefore).to.equal(fee - certifierShare);
+      console.log("Certificat recertifie jusqu'au", new Date(renewed.validUntil.toNumber() * 1000).toISOString());
+    });
+
+    it("Un certificat sans periode de validite ne se recertifie pas", async () => {
+      const owner = Keypair.generate();
+      await airdrop(owner.publicKey, 1);
+      const certPda = await issueCertificateTo("VALIDITY-STD-001", { standard: {} }, owner);
+
+      const certificate = await program.account.certificate.fetch(certPda);
+      expect(certificate.validUntil.toNumber()).to.equal(0);
+
+      try {
+        await recertify(certPda, owner, certifier);
+        expect.fail("Devrait lever une erreur ValidityNotApplicable");
+      } catch (err: any) {
+        expect(err.toString()).to.include("ValidityNotApplicable");
+      }
+    });
+  });
+
+  // ==================== TESTS DEMANDES DE CERTIFICATION ====================
+  describe("Tests demandes de certification", () => {
+    const requestSerial1 = "REQUEST-TEST-001";
+    const requestSerial2 = "REQUEST-TEST-002";
+    const requestSerial3 = "REQUEST-REJECT-001";
+
+    function getRequestPda(serialNumber: string): [PublicKey, number] {
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("request"), Buffer.from(serialNumber)],
+        program.programId
+      );
+    }
+
+    it("Utilisateur soumet une demande de certification", async () => {
+      const [requestPda] = getRequestPda(requestSerial1);
+      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+
+      await program.methods
+        .requestCertification(
+          requestSerial1,
+          "Omega",
+          "Speedmaster",
+          { premium: {} },
+          new anchor.BN(12000),
+          "ipfs://QmTestMetadata123",
+          sha256("ipfs://QmTestMetadata123"),
+          { transferable: {} },
+          certifier.publicKey // target_certifier - le certificateur choisi
+        )
+        .accounts({
+          ...noTokenAccounts,
+          priceFeed: null,
+          requester: owner1.publicKey,
+          authority: authorityPda,
+          request: requestPda,
+          certifierProfile: certifierProfilePda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([owner1])
+        .rpc();
+
+      const request = await program.account.certificationRequest.fetch(requestPda);
+
+      expect(request.requester.toString()).to.equal(owner1.publicKey.toString());
+      expect(request.serialNumber).to.equal(requestSerial1);
+      expect(request.brand).to.equal("Omega");
+      expect(request.model).to.equal("Speedmaster");
+      expect(request.status.pending).to.exist;
+      expect(request.feePaid.toNumber()).to.be.greaterThan(0);
+      expect(request.assignedCertifier.toString()).to.equal(certifier.publicKey.toString());
+
+      // Vérifier que la charge du certificateur a été incrémentée
+      const profile = await program.account.certifierProfile.fetch(certifierProfilePda);
+      expect(profile.currentLoad).to.equal(1);
+
+      console.log("Demande de certification soumise avec succes, assignée à:", certifier.publicKey.toBase58());
+      console.log("Frais payes:", request.feePaid.toString(), "lamports");
+    });
+
+    it("Certificateur approuve une demande", async () => {
+      const [requestPda] = getRequestPda(requestSerial1);
+      const [certificatePda] = getCertificatePda(requestSerial1);
+      const [ownerActivityPda] = getUserActivityPda(owner1.publicKey);
+      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+
+      // Recuperer le solde du certificateur avant
+      const certifierBalanceBefore = await provider.connection.getBalance(certifier.publicKey);
+      const vaultBefore = await program.account.certifierVault.fetch(getCertifierVaultPda(certifier.publicKey)[0]);
+
+      // Récupérer la charge avant
+      const profileBefore = await program.account.certifierProfile.fetch(certifierProfilePda);
+      const loadBefore = profileBefore.currentLoad;
+
+      await program.methods
+        .approveCertification()
+        .accounts({
+          ...noNftMintAccounts,
+          ...noTokenAccounts,
+          requester: null,
+          operator: certifier.publicKey,
+          certifier: certifier.publicKey,
+          delegate: null,
+          authority: authorityPda,
+          request: requestPda,
+          certificate: certificatePda,
+          ownerActivity: ownerActivityPda,
+          certifierProfile: certifierProfilePda,
+          certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
+          treasury: treasuryPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([certifier])
+        .rpc();
+
+      // Verifier la demande mise a jour
+      const request = await program.account.certificationRequest.fetch(requestPda);
+      expect(request.status.approved).to.exist;
+      expect(request.assignedCertifier.toString()).to.equal(certifier.publicKey.toString());
+
+      // Verifier le certificat cree
+      const certificate = await program.account.certificate.fetch(certificatePda);
+      expect(certificate.serialNumber).to.equal(requestSerial1);
+      expect(certificate.owner.toString()).to.equal(owner1.publicKey.toString());
+      expect(certificate.certifier.toString()).to.equal(certifier.publicKey.toString());
+
+      // Vérifier que la charge a été décrémentée
+      const profileAfter = await program.account.certifierProfile.fetch(certifierProfilePda);
+      expect(profileAfter.currentLoad).to.equal(loadBefore - 1);
+      expect(profileAfter.totalProcessed.toNumber()).to.be.greaterThan(0);
+      expect(profileAfter.totalApproved.toNumber()).to.equal(profileBefore.totalApproved.toNumber() + 1);
+      expect(profileAfter.totalLamportsEarned.toNumber()).to.be.greaterThan(profileBefore.totalLamportsEarned.toNumber());
+
+      // Verifier que le coffre du certificateur a recu sa part (60%)
+      const vaultAfter = await program.account.certifierVault.fetch(getCertifierVaultPda(certifier.publicKey)[0]);
+      expect(vaultAfter.lifetimeEarnings.toNumber() - vaultBefore.lifetimeEarnings.toNumber())
+        .to.equal(Math.floor(request.feePaid.toNumber() * 60 / 100));
+      const certifierBalanceAfter = await provider.connection.getBalance(certifier.publicKey);
+      console.log("Certificateur balance avant:", certifierBalanceBefore);
+      console.log("Certificateur balance apres:", certifierBalanceAfter);
+      console.log("Charge actuelle du certificateur:", profileAfter.currentLoad);
+
+      console.log("Demande approuvee et certificat cree");
+    });
+
+    it("Echec approbation par non-certificateur", async () => {
+      // D'abord creer une nouvelle demande assignée au certificateur légitime
+      const [requestPda] = getRequestPda(requestSerial2);
+      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+
+      await program.methods
+        .requestCertification(
+          requestSerial2,
+          "Cartier",
+          "Santos",
+          { luxury: {} },
+          new anchor.BN(25000),
+          "ipfs://QmTestMetadata456",
+          sha256("ipfs://QmTestMetadata456"),
+          { transferable: {} },
+          certifier.publicKey // assigné au bon certificateur
+        )
+        .accounts({
+          ...noTokenAccounts,
+          priceFeed: null,
+          requester: owner3.publicKey,
+          authority: authorityPda,
+          request: requestPda,
+          certifierProfile: certifierProfilePda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([owner3])
+        .rpc();
+
+      // Essayer d'approuver avec un non-certificateur (devrait échouer)
+      const [certificatePda] = getCertificatePda(requestSerial2);
+      const [ownerActivityPda] = getUserActivityPda(owner3.publicKey);
+      // Note: le non-autorisé n'a pas de profile, donc on ne peut pas fournir un account valide
+      // Le test va échouer pour "account not found" ou "not assigned certifier"
+
+      try {
+        // On utilise le profile du vrai certificateur mais signe avec unauthorized
+        await program.methods
+          .approveCertification()
+          .accounts({
+            ...noNftMintAccounts,
+            ...noTokenAccounts,
+            requester: null,
+            operator: unauthorized.publicKey,
+            certifier: unauthorized.publicKey,
+            delegate: null,
+            authority: authorityPda,
+            request: requestPda,
+            certificate: certificatePda,
+            ownerActivity: ownerActivityPda,
+            certifierProfile: certifierProfilePda, // wrong certifier trying to use
+            certifierVault: getCertifierVaultPda(unauthorized.publicKey)[0],
+            treasury: treasuryPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([unauthorized])
+          .rpc();
+
+        expect.fail("Devrait lever une erreur");
+      } catch (err: any) {
+        // Peut être NotAssignedCertifier, UnauthorizedCertifier, ou ConstraintSeeds
+        expect(err).to.exist;
+        console.log("Erreur attendue: non-certificateur ne peut pas approuver");
+      }
+    });
+
+    it("Certificateur rejette une demande avec remboursement", async () => {
+      // Creer une demande a rejeter
+      const [requestPda] = getRequestPda(requestSerial3);
+      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+
+      // Solde avant du demandeur
+      const requesterBalanceBefore = await provider.connection.getBalance(owner1.publicKey);
+
+      await program.methods
+        .requestCertification(
           requestSerial3,
           "Fake",
           "Watch",
           { standard: {} },
-          new anchor.BN(1000),
-          "ipfs://QmFakeWatch",
-          certifier.publicKey // target_certifier
+          new anchor.BN(1000),
+          "ipfs://QmFakeWatch",
+          sha256("ipfs://QmFakeWatch"),
+          { transferable: {} },
+          certifier.publicKey // target_certifier
+        )
+        .accounts({
+          ...noTokenAccounts,
+          priceFeed: null,
+          requester: owner1.publicKey,
+          authority: authorityPda,
+          request: requestPda,
+          certifierProfile: certifierProfilePda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([owner1])
+        .rpc();
+
+      const requestBefore = await program.account.certificationRequest.fetch(requestPda);
+      const feePaid = requestBefore.feePaid.toNumber();
+      const profileBefore = await program.account.certifierProfile.fetch(certifierProfilePda);
+
+      // Rejeter la demande (seul le certificateur assigné peut rejeter)
+      await program.methods
+        .rejectCertification({ insufficientDocumentation: {} }, "Authenticite non verifiable - photos insuffisantes")
+        .accounts({
+          ...noTokenAccounts,
+          operator: certifier.publicKey,
+          certifier: certifier.publicKey,
+          delegate: null,
+          authority: authorityPda,
+          request: requestPda,
+          certifierProfile: certifierProfilePda,
+          requester: owner1.publicKey,
+          certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
+          treasury: treasuryPda,
+        })
+        .signers([certifier])
+        .rpc();
+
+      // Verifier la demande mise a jour
+      const request = await program.account.certificationRequest.fetch(requestPda);
+      expect(request.status.rejected).to.exist;
+      expect(request.rejectionReason).to.include("Authenticite non verifiable");
+
+      // Verifier que le rejet est comptabilise separement des approbations
+      const profileAfter = await program.account.certifierProfile.fetch(certifierProfilePda);
+      expect(profileAfter.totalRejected.toNumber()).to.equal(profileBefore.totalRejected.toNumber() + 1);
+      expect(profileAfter.totalApproved.toNumber()).to.equal(profileBefore.totalApproved.toNumber());
+
+      // Verifier le remboursement
+      const requesterBalanceAfter = await provider.connection.getBalance(owner1.publicKey);
+      console.log("Demandeur balance avant:", requesterBalanceBefore);
+      console.log("Demandeur balance apres:", requesterBalanceAfter);
+      console.log("Frais rembourses:", feePaid, "lamports");
+
+      console.log("Demande rejetee et frais rembourses");
+    });
+
+    it("Approuver la demande restante", async () => {
+      // Approuver requestSerial2
+      const [requestPda] = getRequestPda(requestSerial2);
+      const [certificatePda] = getCertificatePda(requestSerial2);
+      const [ownerActivityPda] = getUserActivityPda(owner3.publicKey);
+      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+
+      await program.methods
+        .approveCertification()
+        .accounts({
+          ...noNftMintAccounts,
+          ...noTokenAccounts,
+          requester: null,
+          operator: certifier.publicKey,
+          certifier: certifier.publicKey,
+          delegate: null,
+          authority: authorityPda,
+          request: requestPda,
+          certificate: certificatePda,
+          ownerActivity: ownerActivityPda,
+          certifierProfile: certifierProfilePda,
+          certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
+          treasury: treasuryPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([certifier])
+        .rpc();
+
+      const certificate = await program.account.certificate.fetch(certificatePda);
+      expect(certificate.serialNumber).to.equal(requestSerial2);
+      console.log("Deuxieme demande approuvee");
+    });
+
+    it("Les gains sont verses a l'adresse de paiement configuree", async () => {
+      const [vaultPda] = getCertifierVaultPda(certifier.publicKey);
+      const payee = Keypair.generate();
+      await airdrop(payee.publicKey, 1);
+
+      const setPayoutAddress = (payoutAddress: PublicKey) =>
+        program.methods
+          .setPayoutAddress(payoutAddress)
+          .accounts({
+            certifier: certifier.publicKey,
+            certifierVault: vaultPda,
+          })
+          .signers([certifier])
+          .rpc();
+      const withdraw = (amount: anchor.BN, payout: PublicKey) =>
+        program.methods
+          .withdrawEarnings(amount)
+          .accounts({
+            certifier: certifier.publicKey,
+            certifierVault: vaultPda,
+            payout,
+          })
+          .signers([certifier])
+          .rpc();
+
+      // Ni la cle par defaut ni le coffre lui-meme ne peuvent recevoir les gains
+      for (const invalid of [PublicKey.default, vaultPda]) {
+        try {
+          await setPayoutAddress(invalid);
+          expect.fail("Devrait lever une erreur InvalidPayoutAddress");
+        } catch (err: any) {
+          expect(err.toString()).to.include("InvalidPayoutAddress");
+        }
+      }
+
+      await setPayoutAddress(payee.publicKey);
+      const vaultBefore = await program.account.certifierVault.fetch(vaultPda);
+      expect(vaultBefore.payoutAddress.toString()).to.equal(payee.publicKey.toString());
+      const amount = vaultBefore.lifetimeEarnings.sub(vaultBefore.totalWithdrawn).divn(2);
+
+      // L'ancienne adresse (le certificateur lui-meme) est refusee
+      try {
+        await withdraw(amount, certifier.publicKey);
+        expect.fail("Devrait lever une erreur InvalidPayoutAddress");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidPayoutAddress");
+      }
+
+      const payeeBalanceBefore = await provider.connection.getBalance(payee.publicKey);
+      await withdraw(amount, payee.publicKey);
+      const payeeBalanceAfter = await provider.connection.getBalance(payee.publicKey);
+      expect(payeeBalanceAfter - payeeBalanceBefore).to.equal(amount.toNumber());
+
+      const vaultAfter = await program.account.certifierVault.fetch(vaultPda);
+      expect(vaultAfter.totalWithdrawn.toString()).to.equal(vaultBefore.totalWithdrawn.add(amount).toString());
+
+      // Retour a l'adresse du certificateur pour la suite des tests
+      await setPayoutAddress(certifier.publicKey);
+      console.log("Gains verses a l'adresse de paiement:", amount.toString(), "lamports");
+    });
+
+    it("Certificateur retire ses gains vers son adresse de paiement", async () => {
+      const [vaultPda] = getCertifierVaultPda(certifier.publicKey);
+      const vaultBefore = await program.account.certifierVault.fetch(vaultPda);
+      const available = vaultBefore.lifetimeEarnings.sub(vaultBefore.totalWithdrawn);
+      const balanceBefore = await provider.connection.getBalance(certifier.publicKey);
+
+      await program.methods
+        .withdrawEarnings(available)
+        .accounts({
+          certifier: certifier.publicKey,
+          certifierVault: vaultPda,
+          payout: vaultBefore.payoutAddress,
+        })
+        .signers([certifier])
+        .rpc();
+
+      const vaultAfter = await program.account.certifierVault.fetch(vaultPda);
+      expect(vaultAfter.totalWithdrawn.toString()).to.equal(vaultAfter.lifetimeEarnings.toString());
+      const balanceAfter = await provider.connection.getBalance(certifier.publicKey);
+      expect(balanceAfter).to.be.greaterThan(balanceBefore);
+      console.log("Gains retires:", available.toString(), "lamports");
+    });
+
+    it("Un retrait est impossible quand l'admin bloque les paiements", async () => {
+      const [vaultPda] = getCertifierVaultPda(certifier.publicKey);
+
+      await program.methods
+        .setPayoutsHeld(certifier.publicKey, true)
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          proposal: null,
+          operatorRole: null,
+          certifierVault: vaultPda,
+        })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await program.methods
+          .withdrawEarnings(new anchor.BN(0))
+          .accounts({
+            certifier: certifier.publicKey,
+            certifierVault: vaultPda,
+            payout: certifier.publicKey,
+          })
+          .signers([certifier])
+          .rpc();
+        expect.fail("Devrait lever une erreur PayoutsHeld");
+      } catch (err: any) {
+        expect(err.toString()).to.include("PayoutsHeld");
+      }
+
+      await program.methods
+        .setPayoutsHeld(certifier.publicKey, false)
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          proposal: null,
+          operatorRole: null,
+          certifierVault: vaultPda,
+        })
+        .signers([admin])
+        .rpc();
+      console.log("Blocage des paiements verifie");
+    });
+
+  });
+  // ==================== TESTS TRESORERIE ====================
+  describe("Tests tresorerie", () => {
+    const payout = Keypair.generate().publicKey;
+
+    const setTreasury = (signer: Keypair, payoutAddress: PublicKey) =>
+      program.methods
+        .setTreasury(payoutAddress)
+        .accounts({
+          admin: signer.publicKey,
+          authority: authorityPda,
+          treasury: treasuryPda,
+          proposal: null,
+        })
+        .signers([signer])
+        .rpc();
+
+    it("L'admin designe l'adresse de paiement de la tresorerie", async () => {
+      expect((await program.account.treasuryVault.fetch(treasuryPda)).payoutAddress.toBase58())
+        .to.equal(admin.publicKey.toBase58());
+
+      try {
+        await setTreasury(unauthorized, unauthorized.publicKey);
+        expect.fail("Devrait lever une erreur UnauthorizedCertifier");
+      } catch (err: any) {
+        expect(err.toString()).to.include("UnauthorizedCertifier");
+      }
+
+      // Le coffre ne peut pas etre sa propre destination
+      try {
+        await setTreasury(admin, treasuryPda);
+        expect.fail("Devrait lever une erreur InvalidPayoutAddress");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidPayoutAddress");
+      }
+
+      await setTreasury(admin, payout);
+      expect((await program.account.treasuryVault.fetch(treasuryPda)).payoutAddress.toBase58())
+        .to.equal(payout.toBase58());
+    });
+
+    it("L'admin retire des fonds du coffre vers l'adresse de paiement", async () => {
+      const treasuryBefore = await program.account.treasuryVault.fetch(treasuryPda);
+      expect(treasuryBefore.totalCollected.toNumber()).to.be.greaterThan(0);
+
+      const amount = new anchor.BN(10_000_000);
+      const withdraw = (destination: PublicKey) =>
+        program.methods
+          .withdrawTreasury(amount)
+          .accounts({
+            admin: admin.publicKey,
+            authority: authorityPda,
+            proposal: null,
+            treasury: treasuryPda,
+            destination,
+          })
+          .signers([admin])
+          .rpc();
+
+      // Aucune autre destination que l'adresse de paiement
+      try {
+        await withdraw(admin.publicKey);
+        expect.fail("Devrait lever une erreur InvalidPayoutAddress");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidPayoutAddress");
+      }
+
+      await withdraw(payout);
+
+      const treasuryAfter = await program.account.treasuryVault.fetch(treasuryPda);
+      expect(treasuryAfter.totalWithdrawn.sub(treasuryBefore.totalWithdrawn).toString()).to.equal(amount.toString());
+      expect(await provider.connection.getBalance(payout)).to.equal(amount.toNumber());
+      console.log("Retrait tresorerie:", amount.toString(), "lamports");
+    });
+
+    it("Un non-admin ne peut pas retirer de la tresorerie", async () => {
+      try {
+        await program.methods
+          .withdrawTreasury(new anchor.BN(1))
+          .accounts({
+            admin: unauthorized.publicKey,
+            authority: authorityPda,
+            proposal: null,
+            treasury: treasuryPda,
+            destination: payout,
+          })
+          .signers([unauthorized])
+          .rpc();
+        expect.fail("Devrait lever une erreur UnauthorizedCertifier");
+      } catch (err: any) {
+        expect(err.toString()).to.include("UnauthorizedCertifier");
+      }
+    });
+  });
+
+  // ==================== TESTS CLES DELEGUEES ====================
+  describe("Tests cles deleguees", () => {
+    const delegatedSerial = "REQUEST-DELEGATE-001";
+    let staff: Keypair;
+    let owner4: Keypair;
+
+    before(async () => {
+      staff = Keypair.generate();
+      owner4 = Keypair.generate();
+      await airdrop(staff.publicKey, 1);
+      await airdrop(owner4.publicKey, 2);
+    });
+
+    it("Un certificateur enregistre un délégué", async () => {
+      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+      const [delegatePda] = getCertifierDelegatePda(certifier.publicKey, staff.publicKey);
+      const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+
+      await program.methods
+        .addDelegate(staff.publicKey, { approve: true, reject: false, issue: false, service: false }, new anchor.BN(expiresAt))
+        .accounts({
+          certifier: certifier.publicKey,
+          certifierProfile: certifierProfilePda,
+          delegateRecord: delegatePda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([certifier])
+        .rpc();
+
+      const record = await program.account.certifierDelegate.fetch(delegatePda);
+      expect(record.delegate.toString()).to.equal(staff.publicKey.toString());
+      expect(record.permissions.approve).to.be.true;
+      expect(record.permissions.reject).to.be.false;
+      console.log("Délégué enregistré:", staff.publicKey.toBase58());
+    });
+
+    it("Le délégué approuve une demande au nom du certificateur", async () => {
+      const [requestPda] = getRequestPda(delegatedSerial);
+      const [certificatePda] = getCertificatePda(delegatedSerial);
+      const [ownerActivityPda] = getUserActivityPda(owner4.publicKey);
+      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+      const [delegatePda] = getCertifierDelegatePda(certifier.publicKey, staff.publicKey);
+
+      await program.methods
+        .requestCertification(
+          delegatedSerial,
+          "Tudor",
+          "Black Bay",
+          { standard: {} },
+          new anchor.BN(3500),
+          "ipfs://QmTudorBlackBay",
+          sha256("ipfs://QmTudorBlackBay"),
+          { transferable: {} },
+          certifier.publicKey
         )
         .accounts({
-          requester: owner1.publicKey,
+          ...noTokenAccounts,
+          priceFeed: null,
+          requester: owner4.publicKey,
+          authority: authorityPda,
+          request: requestPda,
+          certifierProfile: certifierProfilePda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([owner4])
+        .rpc();
+
+      await program.methods
+        .approveCertification()
+        .accounts({
+          ...noNftMintAccounts,
+          ...noTokenAccounts,
+          requester: null,
+          operator: staff.publicKey,
+          certifier: certifier.publicKey,
+          delegate: delegatePda,
+          authority: authorityPda,
+          request: requestPda,
+          certificate: certificatePda,
+          ownerActivity: ownerActivityPda,
+          certifierProfile: certifierProfilePda,
+          certifierVault: getCertifierVaultPda(certifier.publicKey)[0],
+          treasury: treasuryPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([staff])
+        .rpc();
+
+      const certificate = await program.account.certificate.fetch(certificatePda);
+      expect(certificate.certifier.toString()).to.equal(certifier.publicKey.toString());
+      expect(certificate.actingDelegate.toString()).to.equal(staff.publicKey.toString());
+      console.log("Demande approuvée par le délégué");
+    });
+
+    it("Le délégué ne peut pas émettre sans la permission", async () => {
+      const serial = "DELEGATE-ISSUE-001";
+      const [certificatePda] = getCertificatePda(serial);
+      const [ownerActivityPda] = getUserActivityPda(owner4.publicKey);
+      const [delegatePda] = getCertifierDelegatePda(certifier.publicKey, staff.publicKey);
+
+      try {
+        await program.methods
+          .issueCertificate(serial, "Tudor", "Pelagos", { standard: {} }, new anchor.BN(4000), "ipfs://QmTudorPelagos", sha256("ipfs://QmTudorPelagos"), { transferable: {} })
+          .accounts({
+            ...noNftMintAccounts,
+            ...noTokenAccounts,
+            priceFeed: null,
+            operator: staff.publicKey,
+            certifier: certifier.publicKey,
+            delegate: delegatePda,
+            owner: owner4.publicKey,
+            authority: authorityPda,
+            certificate: certificatePda,
+            ownerActivity: ownerActivityPda,
+            certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+            treasury: treasuryPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([staff])
+          .rpc();
+
+        expect.fail("Devrait lever une erreur DelegateNotAuthorized");
+      } catch (err: any) {
+        expect(err.toString()).to.include("DelegateNotAuthorized");
+        console.log("Erreur attendue: permission d'émission absente");
+      }
+    });
+
+    it("Un certificateur revoque son délégué", async () => {
+      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+      const [delegatePda] = getCertifierDelegatePda(certifier.publicKey, staff.publicKey);
+      const profileBefore = await program.account.certifierProfile.fetch(certifierProfilePda);
+
+      await program.methods
+        .removeDelegate()
+        .accounts({
+          certifier: certifier.publicKey,
+          certifierProfile: certifierProfilePda,
+          delegateRecord: delegatePda,
+        })
+        .signers([certifier])
+        .rpc();
+
+      expect(await program.account.certifierDelegate.fetchNullable(delegatePda)).to.be.null;
+      const profileAfter = await program.account.certifierProfile.fetch(certifierProfilePda);
+      expect(profileAfter.delegateCount).to.equal(profileBefore.delegateCount - 1);
+      console.log("Délégué révoqué:", staff.publicKey.toBase58());
+    });
+  });
+
+  // ==================== TESTS ROLES OPERATEURS ====================
+  describe("Tests roles operateurs", () => {
+    const getOperatorRolePda = (operator: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("operator_role"), operator.toBuffer()],
+        program.programId
+      );
+
+    let ops: Keypair;
+
+    before(async () => {
+      ops = Keypair.generate();
+      await airdrop(ops.publicKey, 1);
+    });
+
+    it("L'admin attribue des roles a un operateur", async () => {
+      const [operatorRolePda] = getOperatorRolePda(ops.publicKey);
+      const roles = { certifierManager: false, feeManager: false, pauser: true, disputeResolver: true };
+
+      await program.methods
+        .setOperatorRoles(ops.publicKey, roles)
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          proposal: null,
+          operatorRole: operatorRolePda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const operatorRole = await program.account.operatorRole.fetch(operatorRolePda);
+      expect(operatorRole.roles).to.deep.equal(roles);
+    });
+
+    it("Le pauser suspend le protocole : les emissions sont bloquees", async () => {
+      const [operatorRolePda] = getOperatorRolePda(ops.publicKey);
+
+      await program.methods
+        .setPaused(true)
+        .accounts({
+          admin: ops.publicKey,
+          authority: authorityPda,
+          proposal: null,
+          operatorRole: operatorRolePda,
+        })
+        .signers([ops])
+        .rpc();
+
+      const serial = "PAUSED-CERT-001";
+      const [ownerActivityPda] = getUserActivityPda(owner3.publicKey);
+      try {
+        await program.methods
+          .issueCertificate(serial, "Brand", "Model", { standard: {} }, new anchor.BN(100), "uri", sha256("uri"), { transferable: {} })
+          .accounts({
+            ...noNftMintAccounts,
+            ...noTokenAccounts,
+            priceFeed: null,
+            operator: certifier.publicKey,
+            certifier: certifier.publicKey,
+            delegate: null,
+            owner: owner3.publicKey,
+            authority: authorityPda,
+            certificate: getCertificatePda(serial)[0],
+            ownerActivity: ownerActivityPda,
+            certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+            treasury: treasuryPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([certifier])
+          .rpc();
+        expect.fail("Devrait lever une erreur ProtocolPaused");
+      } catch (err: any) {
+        expect(err.toString()).to.include("ProtocolPaused");
+      }
+
+      await program.methods
+        .setPaused(false)
+        .accounts({
+          admin: ops.publicKey,
           authority: authorityPda,
-          request: requestPda,
-          certifierProfile: certifierProfilePda,
-          treasury: treasury.publicKey,
+          proposal: null,
+          operatorRole: operatorRolePda,
+        })
+        .signers([ops])
+        .rpc();
+
+      const authority = await program.account.certificationAuthority.fetch(authorityPda);
+      expect(authority.paused).to.be.false;
+    });
+
+    it("Le resolveur de litiges bloque les paiements sans la cle admin", async () => {
+      const [operatorRolePda] = getOperatorRolePda(ops.publicKey);
+      const [vaultPda] = getCertifierVaultPda(certifier.publicKey);
+
+      for (const held of [true, false]) {
+        await program.methods
+          .setPayoutsHeld(certifier.publicKey, held)
+          .accounts({
+            admin: ops.publicKey,
+            authority: authorityPda,
+            proposal: null,
+            operatorRole: operatorRolePda,
+            certifierVault: vaultPda,
+          })
+          .signers([ops])
+          .rpc();
+        const vault = await program.account.certifierVault.fetch(vaultPda);
+        expect(vault.payoutsHeld).to.equal(held);
+      }
+    });
+
+    it("Un operateur sans le role requis est refuse", async () => {
+      const [operatorRolePda] = getOperatorRolePda(ops.publicKey);
+      try {
+        await program.methods
+          .renewCertifier(certifier.publicKey)
+          .accounts({
+            admin: ops.publicKey,
+            authority: authorityPda,
+            proposal: null,
+            operatorRole: operatorRolePda,
+            certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          })
+          .signers([ops])
+          .rpc();
+        expect.fail("Devrait lever une erreur MissingRole");
+      } catch (err: any) {
+        expect(err.toString()).to.include("MissingRole");
+      }
+    });
+  });
+
+  // ==================== TESTS GOUVERNANCE ====================
+  describe("Tests gouvernance", () => {
+    const getCouncilPda = () =>
+      PublicKey.findProgramAddressSync([Buffer.from("admin_council")], program.programId);
+    const getProposalPda = (id: number) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("admin_proposal"), new anchor.BN(id).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+    const getOperatorRolePda = (operator: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("operator_role"), operator.toBuffer()],
+        program.programId
+      );
+
+    let member2: Keypair;
+    let member3: Keypair;
+    let certifierManager: Keypair;
+
+    before(async () => {
+      member2 = Keypair.generate();
+      member3 = Keypair.generate();
+      certifierManager = Keypair.generate();
+      await airdrop(member2.publicKey, 1);
+      await airdrop(member3.publicKey, 1);
+      await airdrop(certifierManager.publicKey, 1);
+
+      // Gestionnaire des certificateurs nommé avant l'activation du conseil
+      await program.methods
+        .setOperatorRoles(certifierManager.publicKey, {
+          certifierManager: true,
+          feeManager: false,
+          pauser: false,
+          disputeResolver: false,
+        })
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          proposal: null,
+          operatorRole: getOperatorRolePda(certifierManager.publicKey)[0],
           systemProgram: anchor.web3.SystemProgram.programId,
         })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("Une modification de configuration n'est applicable qu'apres le preavis", async () => {
+      const before = await program.account.certificationAuthority.fetch(authorityPda);
+      const config = { ...before.config, cooldownPeriod: new anchor.BN(2) };
+      const queuedChangePda = await queueChange({ updateConfig: { config } });
+
+      const queued = await program.account.queuedChange.fetch(queuedChangePda);
+      expect(queued.status).to.deep.equal({ queued: {} });
+      expect(queued.effectiveAt.sub(queued.queuedAt).toString()).to.equal(before.config.timelockDelay.toString());
+
+      try {
+        await program.methods
+          .updateConfig(config)
+          .accounts({
+            executor: owner1.publicKey,
+            authority: authorityPda,
+            queuedChange: queuedChangePda,
+          })
+          .signers([owner1])
+          .rpc();
+        expect.fail("Devrait lever une erreur TimelockNotElapsed");
+      } catch (err: any) {
+        expect(err.toString()).to.include("TimelockNotElapsed");
+      }
+
+      await new Promise(r => setTimeout(r, TIMELOCK_WAIT_MS));
+
+      // N'importe qui peut appliquer la modification une fois le préavis écoulé
+      await program.methods
+        .updateConfig(config)
+        .accounts({
+          executor: owner1.publicKey,
+          authority: authorityPda,
+          queuedChange: queuedChangePda,
+        })
         .signers([owner1])
         .rpc();
 
-      const requestBefore = await program.account.certificationRequest.fetch(requestPda);
-      const feePaid = requestBefore.feePaid.toNumber();
+      const after = await program.account.certificationAuthority.fetch(authorityPda);
+      expect(after.config.cooldownPeriod.toNumber()).to.equal(2);
+      const executed = await program.account.queuedChange.fetch(queuedChangePda);
+      expect(executed.status).to.deep.equal({ executed: {} });
 
-      // Rejeter la demande (seul le certificateur assigné peut rejeter)
+      // Restaurer la configuration initiale
+      const restorePda = await queueChange({ updateConfig: { config: before.config } });
+      await new Promise(r => setTimeout(r, TIMELOCK_WAIT_MS));
       await program.methods
-        .rejectCertification("Authenticite non verifiable - photos insuffisantes")
+        .updateConfig(before.config)
         .accounts({
-          certifier: certifier.publicKey,
+          executor: admin.publicKey,
           authority: authorityPda,
-          request: requestPda,
-          certifierProfile: certifierProfilePda,
-          requester: owner1.publicKey,
-          treasury: treasury.publicKey,
+          queuedChange: restorePda,
         })
-        .signers([certifier])
+        .signers([admin])
         .rpc();
+    });
 
-      // Verifier la demande mise a jour
-      const request = await program.account.certificationRequest.fetch(requestPda);
-      expect(request.status.rejected).to.exist;
-      expect(request.rejectionReason).to.include("Authenticite non verifiable");
+    it("Une modification annulee pendant le preavis ne peut plus etre appliquee", async () => {
+      const authority = await program.account.certificationAuthority.fetch(authorityPda);
+      const config = { ...authority.config, standardFee: new anchor.BN(1) };
+      const queuedChangePda = await queueChange({ updateConfig: { config } });
+      const queued = await program.account.queuedChange.fetch(queuedChangePda);
 
-      // Verifier le remboursement
-      const requesterBalanceAfter = await provider.connection.getBalance(owner1.publicKey);
-      console.log("Demandeur balance avant:", requesterBalanceBefore);
-      console.log("Demandeur balance apres:", requesterBalanceAfter);
-      console.log("Frais rembourses:", feePaid, "lamports");
+      await program.methods
+        .cancelChange(queued.id)
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          proposal: null,
+          queuedChange: queuedChangePda,
+        })
+        .signers([admin])
+        .rpc();
 
-      console.log("Demande rejetee et frais rembourses");
+      await new Promise(r => setTimeout(r, TIMELOCK_WAIT_MS));
+      try {
+        await program.methods
+          .updateConfig(config)
+          .accounts({
+            executor: admin.publicKey,
+            authority: authorityPda,
+            queuedChange: queuedChangePda,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("Devrait lever une erreur ChangeNotQueued");
+      } catch (err: any) {
+        expect(err.toString()).to.include("ChangeNotQueued");
+      }
     });
 
-    it("Approuver la demande restante", async () => {
-      // Approuver requestSerial2
-      const [requestPda] = getRequestPda(requestSerial2);
-      const [certificatePda] = getCertificatePda(requestSerial2);
-      const [ownerActivityPda] = getUserActivityPda(owner3.publicKey);
+    it("Une modification ne peut plus etre annulee une fois le preavis ecoule", async () => {
+      const authority = await program.account.certificationAuthority.fetch(authorityPda);
+      const queuedChangePda = await queueChange({ updateConfig: { config: authority.config } });
+      const queued = await program.account.queuedChange.fetch(queuedChangePda);
+
+      await new Promise(r => setTimeout(r, TIMELOCK_WAIT_MS));
+      try {
+        await program.methods
+          .cancelChange(queued.id)
+          .accounts({
+            admin: admin.publicKey,
+            authority: authorityPda,
+            proposal: null,
+            queuedChange: queuedChangePda,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("Devrait lever une erreur TimelockElapsed");
+      } catch (err: any) {
+        expect(err.toString()).to.include("TimelockElapsed");
+      }
+      expect((await program.account.queuedChange.fetch(queuedChangePda)).status).to.deep.equal({ queued: {} });
+
+      // La modification annoncée reste applicable
+      await program.methods
+        .updateConfig(authority.config)
+        .accounts({
+          executor: admin.publicKey,
+          authority: authorityPda,
+          queuedChange: queuedChangePda,
+        })
+        .signers([admin])
+        .rpc();
+      expect((await program.account.queuedChange.fetch(queuedChangePda)).status).to.deep.equal({ executed: {} });
+    });
+
+    it("Une part certificateur superieure a 100% est refusee", async () => {
+      const authority = await program.account.certificationAuthority.fetch(authorityPda);
+      try {
+        await queueChange({
+          updateConfig: { config: { ...authority.config, certifierSharePercent: 101 } },
+        });
+        expect.fail("Devrait lever une erreur InvalidConfig");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidConfig");
+      }
+    });
+
+    it("Active un conseil 2-sur-3 : l'admin seul ne suffit plus", async () => {
+      const [councilPda] = getCouncilPda();
+
+      await program.methods
+        .createCouncil([admin.publicKey, member2.publicKey, member3.publicKey], 2)
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          council: councilPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const authority = await program.account.certificationAuthority.fetch(authorityPda);
+      expect(authority.councilEnabled).to.be.true;
+
+      try {
+        await program.methods
+          .renewCertifier(certifier.publicKey)
+          .accounts({
+            admin: admin.publicKey,
+            authority: authorityPda,
+            proposal: null,
+            operatorRole: null,
+            certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("Devrait lever une erreur ProposalRequired");
+      } catch (err: any) {
+        expect(err.toString()).to.include("ProposalRequired");
+      }
+    });
+
+    it("Un role operateur ne contourne pas le conseil", async () => {
+      try {
+        await program.methods
+          .renewCertifier(certifier.publicKey)
+          .accounts({
+            admin: certifierManager.publicKey,
+            authority: authorityPda,
+            proposal: null,
+            operatorRole: getOperatorRolePda(certifierManager.publicKey)[0],
+            certifierProfile: getCertifierProfilePda(certifier.publicKey)[0],
+          })
+          .signers([certifierManager])
+          .rpc();
+        expect.fail("Devrait lever une erreur ProposalRequired");
+      } catch (err: any) {
+        expect(err.toString()).to.include("ProposalRequired");
+      }
+    });
+
+    it("Execute une operation approuvee par le conseil", async () => {
+      const [councilPda] = getCouncilPda();
+      const council = await program.account.adminCouncil.fetch(councilPda);
+      const [proposalPda] = getProposalPda(council.proposalCount.toNumber());
       const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+      const action = { renewCertifier: { certifier: certifier.publicKey } };
 
       await program.methods
-        .approveCertification()
+        .proposeAdminAction(action)
         .accounts({
-          certifier: certifier.publicKey,
+          proposer: member2.publicKey,
           authority: authorityPda,
-          request: requestPda,
-          certificate: certificatePda,
-          ownerActivity: ownerActivityPda,
-          certifierProfile: certifierProfilePda,
-          treasury: treasury.publicKey,
+          council: councilPda,
+          proposal: proposalPda,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
-        .signers([certifier])
+        .signers([member2])
         .rpc();
 
-      const certificate = await program.account.certificate.fetch(certificatePda);
-      expect(certificate.serialNumber).to.equal(requestSerial2);
-      console.log("Deuxieme demande approuvee");
+      // Une seule approbation : l'execution est refusee
+      try {
+        await program.methods
+          .renewCertifier(certifier.publicKey)
+          .accounts({
+            admin: member2.publicKey,
+            authority: authorityPda,
+            proposal: proposalPda,
+            operatorRole: null,
+            certifierProfile: certifierProfilePda,
+          })
+          .signers([member2])
+          .rpc();
+        expect.fail("Devrait lever une erreur ProposalNotApproved");
+      } catch (err: any) {
+        expect(err.toString()).to.include("ProposalNotApproved");
+      }
+
+      await program.methods
+        .approveAdminAction()
+        .accounts({
+          member: member3.publicKey,
+          authority: authorityPda,
+          council: councilPda,
+          proposal: proposalPda,
+        })
+        .signers([member3])
+        .rpc();
+
+      const profileBefore = await program.account.certifierProfile.fetch(certifierProfilePda);
+      await program.methods
+        .renewCertifier(certifier.publicKey)
+        .accounts({
+          admin: member3.publicKey,
+          authority: authorityPda,
+          proposal: proposalPda,
+          operatorRole: null,
+          certifierProfile: certifierProfilePda,
+        })
+        .signers([member3])
+        .rpc();
+
+      const profileAfter = await program.account.certifierProfile.fetch(certifierProfilePda);
+      expect(profileAfter.accreditedUntil.toNumber()).to.be.greaterThan(profileBefore.accreditedUntil.toNumber());
+      const proposal = await program.account.adminProposal.fetch(proposalPda);
+      expect(proposal.executed).to.be.true;
+
+      // Une proposition ne peut etre consommee qu'une fois
+      try {
+        await program.methods
+          .renewCertifier(certifier.publicKey)
+          .accounts({
+            admin: member3.publicKey,
+            authority: authorityPda,
+            proposal: proposalPda,
+            operatorRole: null,
+            certifierProfile: certifierProfilePda,
+          })
+          .signers([member3])
+          .rpc();
+        expect.fail("Devrait lever une erreur ProposalAlreadyExecuted");
+      } catch (err: any) {
+        expect(err.toString()).to.include("ProposalAlreadyExecuted");
+      }
+      console.log("Operation executee apres 2 approbations sur 3");
     });
 
+    it("Une mise a jour du conseil invalide les propositions en cours", async () => {
+      const [councilPda] = getCouncilPda();
+      const [certifierProfilePda] = getCertifierProfilePda(certifier.publicKey);
+
+      const propose = async (proposer: Keypair, action: any) => {
+        const council = await program.account.adminCouncil.fetch(councilPda);
+        const [proposalPda] = getProposalPda(council.proposalCount.toNumber());
+        await program.methods
+          .proposeAdminAction(action)
+          .accounts({
+            proposer: proposer.publicKey,
+            authority: authorityPda,
+            council: councilPda,
+            proposal: proposalPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([proposer])
+          .rpc();
+        return proposalPda;
+      };
+      const approve = (member: Keypair, proposalPda: PublicKey) =>
+        program.methods
+          .approveAdminAction()
+          .accounts({
+            member: member.publicKey,
+            authority: authorityPda,
+            council: councilPda,
+            proposal: proposalPda,
+          })
+          .signers([member])
+          .rpc();
+      const renew = (proposalPda: PublicKey) =>
+        program.methods
+          .renewCertifier(certifier.publicKey)
+          .accounts({
+            admin: member2.publicKey,
+            authority: authorityPda,
+            proposal: proposalPda,
+            operatorRole: null,
+            certifierProfile: certifierProfilePda,
+          })
+          .signers([member2])
+          .rpc();
+
+      // Proposition approuvée par member2 et member3, pas encore exécutée
+      const staleProposal = await propose(member2, { renewCertifier: { certifier: certifier.publicKey } });
+      await approve(member3, staleProposal);
+      // Proposition en attente, approuvée seulement par member3
+      const pendingProposal = await propose(member3, { renewCertifier: { certifier: certifier.publicKey } });
+
+      // Le conseil retire member3
+      const members = [admin.publicKey, member2.publicKey];
+      const updateProposal = await propose(admin, { updateCouncil: { members, threshold: 2 } });
+      await approve(member2, updateProposal);
+      await program.methods
+        .updateCouncil(members, 2)
+        .accounts({
+          admin: admin.publicKey,
+          authority: authorityPda,
+          council: councilPda,
+          proposal: updateProposal,
+        })
+        .signers([admin])
+        .rpc();
+
+      const council = await program.account.adminCouncil.fetch(councilPda);
+      expect(council.members.map((m) => m.toBase58())).to.deep.equal(members.map((m) => m.toBase58()));
+      const authority = await program.account.certificationAuthority.fetch(authorityPda);
+      expect(authority.councilEpoch.toNumber()).to.equal(1);
+
+      // Les approbations recueillies sous l'ancien conseil ne valent plus
+      try {
+        await renew(staleProposal);
+        expect.fail("Devrait lever une erreur ProposalStale");
+      } catch (err: any) {
+        expect(err.toString()).to.include("ProposalStale");
+      }
+      try {
+        await approve(member2, pendingProposal);
+        expect.fail("Devrait lever une erreur ProposalStale");
+      } catch (err: any) {
+        expect(err.toString()).to.include("ProposalStale");
+      }
+
+      // Un ancien membre ne peut plus proposer ; le nouveau conseil doit reproposer
+      try {
+        await propose(member3, { renewCertifier: { certifier: certifier.publicKey } });
+        expect.fail("Devrait lever une erreur NotCouncilMember");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NotCouncilMember");
+      }
+      const freshProposal = await propose(member2, { renewCertifier: { certifier: certifier.publicKey } });
+      await approve(admin, freshProposal);
+      await renew(freshProposal);
+      expect((await program.account.adminProposal.fetch(freshProposal)).executed).to.be.true;
+    });
   });
+
   // ==================== RÉSUMÉ ====================
   describe("Résumé final", () => {
     it("Affiche les statistiques finales", async () => {
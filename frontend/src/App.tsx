@@ -15,7 +15,7 @@ type TabType = 'verify' | 'my-watches' | 'certify';
 
 function App() {
     const { connected, publicKey } = useWallet();
-    const { program, getAuthorityPda } = useSolCertify();
+    const { program, getAuthorityPda, getCertifierProfilePda } = useSolCertify();
     const [activeTab, setActiveTab] = useState<TabType>('verify');
     const [isCertifier, setIsCertifier] = useState(false);
 
@@ -28,20 +28,18 @@ function App() {
             try {
                 const [authorityPda] = getAuthorityPda();
                 const authority = await (program.account as any).certificationAuthority.fetch(authorityPda);
-
-                console.log("[DEBUG] Objet Authority COMPLET:", authority);
-
-                const certifiersRaw = authority.approvedCertifiers || authority.certifiers || [];
-                const certifiers = certifiersRaw as PublicKey[];
                 const admin = authority.admin as PublicKey;
 
-                console.log("[DEBUG] Certificateurs autorisés:", certifiers.map(c => c.toString()));
+                // The certifier profile (existence + isActive) is the source of truth for accreditation
+                const [profilePda] = getCertifierProfilePda(publicKey);
+                const profile = await (program.account as any).certifierProfile.fetchNullable(profilePda);
+                const isActiveCertifier = !!profile && profile.isActive;
+
                 console.log("[DEBUG] Admin:", admin.toString());
                 console.log("[DEBUG] Ton Wallet:", publicKey.toString());
 
                 // Allow if user is an approved certifier OR the global admin
-                const isAuth = certifiers.some(c => c.toString() === publicKey.toString()) ||
-                    admin.toString() === publicKey.toString();
+                const isAuth = isActiveCertifier || admin.toString() === publicKey.toString();
 
                 console.log("[DEBUG] Accès Dashboard autorisé ?", isAuth);
 
@@ -24,7 +24,7 @@ interface CertificationRequest {
         certType: any;
         estimatedValue: BN;
         metadataUri: string;
-        status: { pending?: {}, approved?: {}, rejected?: {} };
+        status: { pending?: {}, approved?: {}, rejected?: {}, awaitingQuote?: {}, quoted?: {} };
         assignedCertifier: PublicKey | null;
         rejectionReason: string;
         createdAt: BN;
@@ -86,7 +86,7 @@ const MetadataImage = ({ uri }: { uri: string }) => {
 };
 
 export const CertifierDashboard = () => {
-    const { program, getAuthorityPda, getUserActivityPda, getCertificatePda, getCertifierProfilePda } = useSolCertify();
+    const { program, getAuthorityPda, getUserActivityPda, getCertificatePda, getCertifierProfilePda, getCertifierVaultPda } = useSolCertify();
     const { publicKey } = useWallet();
 
     const [requests, setRequests] = useState<CertificationRequest[]>([]);
@@ -97,6 +97,7 @@ export const CertifierDashboard = () => {
     // Approval/Rejection Modal State
     const [selectedRequest, setSelectedRequest] = useState<CertificationRequest | null>(null);
     const [rejectReason, setRejectReason] = useState('');
+    const [rejectionCode, setRejectionCode] = useState('counterfeit');
     const [actionType, setActionType] = useState<'approve' | 'reject' | null>(null);
 
     // Filter logic update
@@ -177,13 +178,28 @@ export const CertifierDashboard = () => {
             const tx = await (program.methods as any)
                 .approveCertification()
                 .accounts({
+                    operator: publicKey,
                     certifier: publicKey,
+                    delegate: null,
                     certifierProfile: certifierProfilePda, // Added in V2
+                    certifierVault: getCertifierVaultPda(publicKey)[0],
                     request: request.publicKey,
                     authority: authorityPda,
                     certificate: certificatePda,
                     ownerActivity: ownerActivityPda,
                     treasury: treasuryPubkey,
+                    // Frais en SOL : comptes de jeton SPL absents
+                    requester: null,
+                    paymentMint: null,
+                    escrowTokenAccount: null,
+                    certifierTokenAccount: null,
+                    treasuryTokenAccount: null,
+                    tokenProgram: null,
+                    // Certificat émis sans jeton NFT
+                    nftMint: null,
+                    ownerNftAccount: null,
+                    nftTokenProgram: null,
+                    associatedTokenProgram: null,
                     systemProgram: SystemProgram.programId // Use explicitly imported/resolved SystemProgram if available, or rely on Anchor default
                 })
                 .rpc();
@@ -213,15 +229,27 @@ export const CertifierDashboard = () => {
 
             const [certifierProfilePda] = getCertifierProfilePda(publicKey);
 
+            // The inspection fee is split between the certifier vault and the treasury
+            const authority = await (program.account as any).certificationAuthority.fetch(authorityPda);
+
             const tx = await (program.methods as any)
-                .rejectCertification(rejectReason)
+                .rejectCertification({ [rejectionCode]: {} }, rejectReason)
                 .accounts({
+                    operator: publicKey,
                     certifier: publicKey,
+                    delegate: null,
                     certifierProfile: certifierProfilePda, // Added in V2
                     request: request.publicKey,
                     requester: request.account.requester,
                     authority: authorityPda,
-                    systemProgram: SystemProgram.programId
+                    certifierVault: getCertifierVaultPda(publicKey)[0],
+                    treasury: authority.treasury,
+                    paymentMint: null,
+                    escrowTokenAccount: null,
+                    requesterTokenAccount: null,
+                    certifierTokenAccount: null,
+                    treasuryTokenAccount: null,
+                    tokenProgram: null,
                 })
                 .rpc();
 
@@ -249,6 +277,8 @@ export const CertifierDashboard = () => {
         if (status.pending) return <span className="px-2 py-1 bg-yellow-500/20 text-yellow-500 rounded text-xs">En Attente</span>;
         if (status.approved) return <span className="px-2 py-1 bg-green-500/20 text-green-500 rounded text-xs">Approuve</span>;
         if (status.rejected) return <span className="px-2 py-1 bg-red-500/20 text-red-500 rounded text-xs">Rejete</span>;
+        if (status.awaitingQuote) return <span className="px-2 py-1 bg-blue-500/20 text-blue-500 rounded text-xs">Devis a etablir</span>;
+        if (status.quoted) return <span className="px-2 py-1 bg-blue-500/20 text-blue-500 rounded text-xs">Devis envoye</span>;
         return null;
     };
 
@@ -373,7 +403,19 @@ export const CertifierDashboard = () => {
                                                 </div>
                                             ) : (
                                                 <div className="space-y-3">
-                                                    <p className="text-sm text-white">Motif du rejet (remboursement automatique):</p>
+                                                    <p className="text-sm text-white">Motif du rejet (remboursement automatique, frais d'inspection retenus après inspection):</p>
+                                                    <select
+                                                        value={rejectionCode}
+                                                        onChange={(e) => setRejectionCode(e.target.value)}
+                                                        className="w-full bg-black/20 border border-white/10 rounded-lg p-2 text-sm text-white"
+                                                    >
+                                                        <option value="counterfeit">Contrefaçon (frais d'inspection retenus)</option>
+                                                        <option value="nonOriginalParts">Pièces non d'origine (frais d'inspection retenus)</option>
+                                                        <option value="detailsMismatch">Non conforme à la demande (frais d'inspection retenus)</option>
+                                                        <option value="insufficientDocumentation">Documentation insuffisante</option>
+                                                        <option value="watchNotReceived">Montre non reçue</option>
+                                                        <option value="other">Autre</option>
+                                                    </select>
                                                     <input
                                                         type="text"
                                                         value={rejectReason}
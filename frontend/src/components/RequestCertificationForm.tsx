@@ -18,7 +18,7 @@ import {
     UserCheck
 } from 'lucide-react';
 import { clsx } from 'clsx';
-import { uploadImage, createMetadata } from '../services/ipfs';
+import { uploadImage, createMetadata, contentHashToBytes } from '../services/ipfs';
 
 type CertificationType = 'standard' | 'premium' | 'luxury' | 'exceptional';
 
@@ -61,6 +61,23 @@ const CERT_TYPES: Record<CertificationType, CertTypeInfo> = {
     },
 };
 
+type TransferPolicy = 'transferable' | 'soulbound' | 'certifierApproval';
+
+const TRANSFER_POLICIES: Record<TransferPolicy, { label: string; description: string }> = {
+    transferable: {
+        label: 'Transferable',
+        description: 'Transferts et ventes libres',
+    },
+    soulbound: {
+        label: 'Lie au proprietaire',
+        description: 'Aucun transfert possible',
+    },
+    certifierApproval: {
+        label: 'Sur approbation',
+        description: 'Chaque transfert approuve par le certificateur',
+    },
+};
+
 interface CertifierInfo {
     publicKey: PublicKey;
     displayName: string;
@@ -70,7 +87,7 @@ interface CertifierInfo {
 }
 
 export const RequestCertificationForm = () => {
-    const { program, getAuthorityPda, getRequestPda, getCertifierProfilePda } = useSolCertify();
+    const { program, getAuthorityPda, getRequestPda, getCertifierProfilePda, fetchCertifiers } = useSolCertify();
     const { publicKey } = useWallet();
     const fileInputRef = useRef<HTMLInputElement>(null);
 
@@ -79,6 +96,7 @@ export const RequestCertificationForm = () => {
         brand: '',
         model: '',
         certType: 'standard' as CertificationType,
+        transferPolicy: 'transferable' as TransferPolicy,
         estimatedValue: '',
         targetCertifier: '', // User selection
     });
@@ -98,40 +116,19 @@ export const RequestCertificationForm = () => {
 
     // Fetch certifiers on mount
     useEffect(() => {
-        const fetchCertifiers = async () => {
+        const loadCertifiers = async () => {
             if (!program) return;
             try {
-                const [authorityPda] = getAuthorityPda();
-                const authority = await (program.account as any).certificationAuthority.fetch(authorityPda);
-
-                // Get list of authorized pubkeys
-                const approvedKeys = authority.approvedCertifiers as PublicKey[];
-
-                // Fetch profiles for each
-                const profiles: CertifierInfo[] = [];
-                for (const key of approvedKeys) {
-                    const [profilePda] = getCertifierProfilePda(key);
-                    try {
-                        const profile = await (program.account as any).certifierProfile.fetch(profilePda);
-                        profiles.push({
-                            publicKey: key,
-                            displayName: profile.displayName,
-                            physicalAddress: profile.physicalAddress,
-                            currentLoad: profile.currentLoad,
-                            totalProcessed: profile.totalProcessed.toNumber() // u64 to number
-                        });
-                    } catch (e) {
-                        console.warn(`Profile not found for ${key.toString()}`, e);
-                        // Fallback if profile missing (should not happen in prod)
-                        profiles.push({
-                            publicKey: key,
-                            displayName: `Certif. ${key.toString().slice(0, 4)}`,
-                            physicalAddress: "N/A",
-                            currentLoad: 0,
-                            totalProcessed: 0
-                        });
-                    }
-                }
+                // Enumerate active certifier profiles (source of truth for accreditation)
+                const activeProfiles = await fetchCertifiers();
+
+                const profiles: CertifierInfo[] = activeProfiles.map((p: any) => ({
+                    publicKey: p.account.certifier,
+                    displayName: p.account.displayName,
+                    physicalAddress: p.account.physicalAddress,
+                    currentLoad: p.account.currentLoad,
+                    totalProcessed: p.account.totalProcessed.toNumber() // u64 to number
+                }));
                 setCertifiers(profiles);
                 // Pre-select the first one (or the one with lowest load later)
                 if (profiles.length > 0) {
@@ -144,8 +141,8 @@ export const RequestCertificationForm = () => {
             }
         };
 
-        fetchCertifiers();
-    }, [program, getAuthorityPda, getCertifierProfilePda]);
+        loadCertifiers();
+    }, [program]);
 
     const handleChange = (e: React.ChangeEvent<HTMLInputElement | HTMLSelectElement>) => {
         const { name, value } = e.target;
@@ -215,6 +212,7 @@ export const RequestCertificationForm = () => {
             });
 
             metadataUri = metadataResult.metadataUri;
+            const metadataHash = contentHashToBytes(metadataResult.contentHash);
 
             // Step 3: Request certification on blockchain
             setStep('blockchain');
@@ -223,9 +221,14 @@ export const RequestCertificationForm = () => {
             const [certifierProfilePda] = getCertifierProfilePda(targetPubkey);
 
             const authority = await (program.account as any).certificationAuthority.fetch(authorityPda);
-            const treasuryPubkey = authority.treasury;
+
+            // Frais en euros : convertis au cours SOL/EUR publié par l'oracle
+            const priceFeedPda = authority.config.feeCurrency?.eurCents
+                ? PublicKey.findProgramAddressSync([Buffer.from("price_feed")], program.programId)[0]
+                : null;
 
             const certTypeArg = { [formData.certType]: {} };
+            const transferPolicyArg = { [formData.transferPolicy]: {} };
 
             // NEW: includes targetCertifier arg and certifierProfile account
             const tx = await (program.methods as any)
@@ -236,6 +239,8 @@ export const RequestCertificationForm = () => {
                     certTypeArg,
                     new BN(parseInt(formData.estimatedValue)),
                     metadataUri,
+                    metadataHash,
+                    transferPolicyArg,
                     targetPubkey // Argument added in V2 logic
                 )
                 .accounts({
@@ -243,7 +248,13 @@ export const RequestCertificationForm = () => {
                     authority: authorityPda,
                     request: requestPda,
                     certifierProfile: certifierProfilePda, // Added account
-                    treasury: treasuryPubkey,
+                    // Frais en SOL : comptes de jeton SPL absents
+                    feeMint: null,
+                    paymentMint: null,
+                    payerTokenAccount: null,
+                    escrowTokenAccount: null,
+                    tokenProgram: null,
+                    priceFeed: priceFeedPda,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -383,6 +394,26 @@ export const RequestCertificationForm = () => {
                     ))}
                 </div>
 
+                {/* Transfer Policy Selection */}
+                <div className="grid grid-cols-3 gap-3">
+                    {(Object.entries(TRANSFER_POLICIES) as [TransferPolicy, { label: string; description: string }][]).map(([key, info]) => (
+                        <button
+                            key={key}
+                            type="button"
+                            onClick={() => setFormData(prev => ({ ...prev, transferPolicy: key }))}
+                            className={clsx(
+                                "p-3 rounded-xl border-2 transition-all text-center",
+                                formData.transferPolicy === key
+                                    ? "border-blue-500 bg-blue-500/10"
+                                    : "border-white/10 bg-white/5 hover:border-white/20"
+                            )}
+                        >
+                            <div className="text-sm font-semibold text-white">{info.label}</div>
+                            <div className="text-[10px] text-slate-500">{info.description}</div>
+                        </button>
+                    ))}
+                </div>
+
                 {/* Image Upload */}
                 <div className="space-y-2">
                     <label className="text-xs text-slate-400 uppercase tracking-wider flex items-center gap-2">
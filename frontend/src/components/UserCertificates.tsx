@@ -66,6 +66,8 @@ export const UserCertificates = () => {
         if (status.pending) return <span className="flex items-center gap-1 text-yellow-500 bg-yellow-500/10 px-2 py-1 rounded text-xs"><Clock size={12} /> En Attente</span>;
         if (status.approved) return <span className="flex items-center gap-1 text-green-500 bg-green-500/10 px-2 py-1 rounded text-xs"><CheckCircle size={12} /> Approuvé</span>;
         if (status.rejected) return <span className="flex items-center gap-1 text-red-500 bg-red-500/10 px-2 py-1 rounded text-xs"><AlertCircle size={12} /> Rejeté</span>;
+        if (status.awaitingQuote) return <span className="flex items-center gap-1 text-blue-500 bg-blue-500/10 px-2 py-1 rounded text-xs"><Clock size={12} /> Devis demandé</span>;
+        if (status.quoted) return <span className="flex items-center gap-1 text-blue-500 bg-blue-500/10 px-2 py-1 rounded text-xs"><Clock size={12} /> Devis reçu</span>;
         return null;
     };
 
@@ -121,7 +123,12 @@ export const UserCertificates = () => {
                     <div className="grid grid-cols-1 md:grid-cols-2 gap-6">
                         {certificates.map((cert) => {
                             const data = cert.account;
-                            const isLocked = new Date().getTime() / 1000 < data.lockedUntil.toNumber();
+                            const now = new Date().getTime() / 1000;
+                            const isLocked = now < data.lockedUntil.toNumber();
+                            // Absent when the bundled IDL predates certificate validity: treat as no expiry
+                            const validUntil = data.validUntil?.toNumber() ?? 0;
+                            const isExpired = validUntil !== 0 && now >= validUntil;
+                            const isSoulbound = !!data.transferPolicy && 'soulbound' in data.transferPolicy;
 
                             return (
                                 <div key={cert.publicKey.toString()} className="luxury-card group overflow-hidden border-white/5 bg-[#1a1a1e]">
@@ -157,7 +164,22 @@ export const UserCertificates = () => {
                                                 "text-sm font-medium",
                                                 isLocked ? "text-red-400" : "text-green-400"
                                             )}>
-                                                {isLocked ? "Verrouillé" : "Transférable"}
+                                                {isSoulbound ? "Lié au propriétaire" : isLocked ? "Verrouillé" : "Transférable"}
+                                            </div>
+                                        </div>
+                                        <div className="space-y-1">
+                                            <div className="text-[10px] text-slate-500 uppercase flex items-center gap-1">
+                                                <Timer size={10} /> Valide jusqu'au
+                                            </div>
+                                            <div className={clsx(
+                                                "text-sm font-medium",
+                                                isExpired ? "text-red-400" : "text-slate-200"
+                                            )}>
+                                                {validUntil === 0
+                                                    ? "Sans expiration"
+                                                    : isExpired
+                                                        ? "Expiré, à recertifier"
+                                                        : new Date(validUntil * 1000).toLocaleDateString()}
                                             </div>
                                         </div>
                                     </div>
@@ -171,7 +193,7 @@ export const UserCertificates = () => {
                                         </button>
                                     </div>
 
-                                    {!isLocked && (
+                                    {!isLocked && !isSoulbound && (
                                         <button className="w-full mt-2 luxury-button !py-2 !text-sm">
                                             Transférer la Propriété
                                         </button>
@@ -1,11 +1,14 @@
 import { useMemo } from 'react';
 import { PublicKey } from '@solana/web3.js';
-import { AnchorProvider, Program, Idl } from '@coral-xyz/anchor';
+import { AnchorProvider, BN, Program, Idl } from '@coral-xyz/anchor';
 import { useAnchorWallet, useConnection } from '@solana/wallet-adapter-react';
 import idl from '../idl/solcertify.json';
 
 export const PROGRAM_ID = new PublicKey(idl.address);
 
+// Nombre d'entrees par page de provenance (ProvenancePage::PAGE_SIZE)
+const PROVENANCE_PAGE_SIZE = 10;
+
 export function useSolCertify() {
     const { connection } = useConnection();
     const wallet = useAnchorWallet();
@@ -55,6 +58,57 @@ export function useSolCertify() {
         );
     };
 
+    const getCertifierVaultPda = (certifier: PublicKey) => {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("certifier_vault"), certifier.toBuffer()],
+            PROGRAM_ID
+        );
+    };
+
+    const getProvenancePagePda = (certificate: PublicKey, page: number) => {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("provenance"), certificate.toBuffer(), new BN(page).toArrayLike(Buffer, "le", 4)],
+            PROGRAM_ID
+        );
+    };
+
+    // Reconstitue l'historique complet de provenance (pages de 10 transferts, ordre chronologique)
+    const fetchProvenance = async (certificate: PublicKey) => {
+        if (!program) return [];
+        const { transferCount } = await (program.account as any).certificate.fetch(certificate);
+        const entries: any[] = [];
+        for (let page = 0; page * PROVENANCE_PAGE_SIZE < transferCount; page++) {
+            const [pagePda] = getProvenancePagePda(certificate, page);
+            const account = await (program.account as any).provenancePage.fetch(pagePda);
+            entries.push(...account.entries);
+        }
+        return entries;
+    };
+
+    const getServiceRecordPda = (certificate: PublicKey, index: number) => {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("service_record"), certificate.toBuffer(), new BN(index).toArrayLike(Buffer, "le", 4)],
+            PROGRAM_ID
+        );
+    };
+
+    // Lit le carnet d'entretien complet d'un certificat (ordre d'enregistrement)
+    const fetchServiceRecords = async (certificate: PublicKey) => {
+        if (!program) return [];
+        const { serviceCount } = await (program.account as any).certificate.fetch(certificate);
+        const pdas = Array.from({ length: serviceCount }, (_, i) => getServiceRecordPda(certificate, i)[0]);
+        return (program.account as any).serviceRecord.fetchMultiple(pdas);
+    };
+
+    // Enumere les certificateurs via leurs profils (le profil actif fait foi pour l'agrement)
+    const fetchCertifiers = async (activeOnly: boolean = true) => {
+        if (!program) return [];
+        const profiles = await (program.account as any).certifierProfile.all();
+        return activeOnly
+            ? profiles.filter((p: any) => p.account.isActive)
+            : profiles;
+    };
+
     return {
         program,
         PROGRAM_ID,
@@ -63,6 +117,12 @@ export function useSolCertify() {
         getUserActivityPda,
         getRequestPda,
         getCertifierProfilePda,
+        getCertifierVaultPda,
+        getProvenancePagePda,
+        getServiceRecordPda,
+        fetchCertifiers,
+        fetchProvenance,
+        fetchServiceRecords,
         wallet,
         connection
     };
@@ -22,6 +22,7 @@ export interface CreateMetadataResponse {
     metadataUri: string;
     url: string;
     gatewayUrl?: string;
+    contentHash: string;
     metadata: any;
     simulated?: boolean;
 }
@@ -38,10 +39,18 @@ export interface CertificateFullResponse {
         hash: string;
         uri: string;
         url: string;
+        contentHash: string;
         content: any;
     };
 }
 
+/**
+ * Convertit l'empreinte SHA-256 hexadecimale en argument [u8; 32] du programme
+ */
+export function contentHashToBytes(contentHash: string): number[] {
+    return Array.from({ length: 32 }, (_, i) => parseInt(contentHash.slice(i * 2, i * 2 + 2), 16));
+}
+
 /**
  * Verifie que le service IPFS est disponible
  */
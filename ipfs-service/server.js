@@ -13,6 +13,7 @@ const cors = require('cors');
 const multer = require('multer');
 const { PinataSDK } = require('pinata-web3');
 const { Readable } = require('stream');
+const crypto = require('crypto');
 
 const app = express();
 const PORT = process.env.PORT || 3001;
@@ -118,6 +119,14 @@ app.post('/api/upload/image', upload.single('file'), async (req, res) => {
     }
 });
 
+/**
+ * Empreinte SHA-256 (hex) du JSON de metadonnees tel qu'il est epingle sur IPFS
+ * Elle est enregistree on-chain avec l'URI pour verifier le contenu
+ */
+function metadataContentHash(metadata) {
+    return crypto.createHash('sha256').update(JSON.stringify(metadata)).digest('hex');
+}
+
 /**
  * POST /api/metadata/create
  * Crée et upload les métadonnées JSON d'un certificat
@@ -188,6 +197,7 @@ app.post('/api/metadata/create', async (req, res) => {
                 hash: fakeHash,
                 metadataUri: `ipfs://${fakeHash}`,
                 url: `https://ipfs.io/ipfs/${fakeHash}`,
+                contentHash: metadataContentHash(metadata),
                 metadata
             });
         }
@@ -203,6 +213,7 @@ app.post('/api/metadata/create', async (req, res) => {
             metadataUri: `ipfs://${result.IpfsHash}`,
             url: `https://ipfs.io/ipfs/${result.IpfsHash}`,
             gatewayUrl: `https://${process.env.PINATA_GATEWAY || 'gateway.pinata.cloud'}/ipfs/${result.IpfsHash}`,
+            contentHash: metadataContentHash(metadata),
             metadata
         });
 
@@ -310,6 +321,7 @@ app.post('/api/certificate/full', upload.single('image'), async (req, res) => {
                 hash: metadataHash,
                 uri: `ipfs://${metadataHash}`,
                 url: `https://ipfs.io/ipfs/${metadataHash}`,
+                contentHash: metadataContentHash(metadata),
                 content: metadata
             }
         });